@@ -1,9 +1,32 @@
 use bevy::prelude::*;
-use crate::player::{Player, PlayerType, AIDifficulty};
+use crate::player::{Player, PlayerType, AIDifficulty, AIPlayer};
+use crate::game_state::GameState;
+use crate::game_state::GameData;
 use crate::betting::{PlayerAction, BettingRound};
-use crate::poker_rules::{HandEvaluation, evaluate_hand};
+use crate::poker_rules::{HandEvalCache, HandEvaluation};
 use crate::cards::Card;
-use rand::Rng;
+use crate::odds::{count_outs, estimate_equity, estimate_equity_vs_range, outs_hit_probability, HandRange};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// Run-outs sampled when an Advanced AI weighs a call against a betting range
+const ADVANCED_EQUITY_ITERATIONS: usize = 200;
+
+// Run-outs an Expert AI simulates per decision unless its personality says otherwise
+const EXPERT_EQUITY_ITERATIONS: usize = 500;
+
+// Equity an Expert wants before it bets or raises rather than checks or calls
+const EXPERT_VALUE_EQUITY: f32 = 0.65;
+
+// Share of a draw's chance to get there that counts towards the hand score
+const DRAW_SCORE_WEIGHT: f32 = 0.25;
+
+// Score taken off hands below a full house once the board pairs
+const PAIRED_BOARD_SCORE_PENALTY: f32 = 0.15;
+
+// (hand score, equity) points the AI's equity guess is interpolated between, one per old
+// strength tier plus the ends, so hands either side of a tier boundary play alike
+const SCORE_EQUITY_CURVE: [(f32, f32); 6] = [(0.0, 0.05), (0.15, 0.15), (0.3, 0.35), (0.45, 0.65), (0.6, 0.85), (1.0, 0.98)];
 
 /// AI personality traits that affect decision making
 #[derive(Debug, Clone)]
@@ -13,6 +36,7 @@ pub struct AIPersonality {
     pub tightness: f32,       // 0.0 = loose, 1.0 = very tight
     pub bluff_frequency: f32, // 0.0 = never bluff, 1.0 = bluff often
     pub position_awareness: f32, // 0.0 = ignore position, 1.0 = highly position-aware
+    pub equity_iterations: Option<usize>, // Expert only: run-outs simulated per decision; fewer is faster but noisier
 }
 
 impl Default for AIPersonality {
@@ -23,6 +47,7 @@ impl Default for AIPersonality {
             tightness: 0.5,
             bluff_frequency: 0.1,
             position_awareness: 0.2,
+            equity_iterations: None,
         }
     }
 }
@@ -35,6 +60,7 @@ impl AIPersonality {
             tightness: 0.7,
             bluff_frequency: 0.05,
             position_awareness: 0.1,
+            ..Self::default()
         }
     }
     
@@ -45,13 +71,45 @@ impl AIPersonality {
             tightness: 0.5,
             bluff_frequency: 0.15,
             position_awareness: 0.6,
+            ..Self::default()
+        }
+    }
+    
+    pub fn advanced() -> Self {
+        Self {
+            difficulty: AIDifficulty::Advanced,
+            aggression: 0.6,
+            tightness: 0.4,
+            bluff_frequency: 0.2,
+            position_awareness: 0.9,
+            ..Self::default()
+        }
+    }
+    
+    pub fn expert() -> Self {
+        Self {
+            difficulty: AIDifficulty::Expert,
+            aggression: 0.6,
+            tightness: 0.5,
+            bluff_frequency: 0.1,
+            position_awareness: 1.0,
+            equity_iterations: Some(EXPERT_EQUITY_ITERATIONS),
+        }
+    }
+    
+    pub fn for_difficulty(difficulty: AIDifficulty) -> Self {
+        match difficulty {
+            AIDifficulty::Beginner => Self::beginner(),
+            AIDifficulty::Intermediate => Self::intermediate(),
+            AIDifficulty::Advanced => Self::advanced(),
+            AIDifficulty::Expert => Self::expert(),
         }
     }
 }
 
 /// Hand strength categories for AI decision making
 #[derive(Debug, PartialEq, PartialOrd)]
-enum HandStrength {
+pub enum HandStrength {
     Weak,      // High card, low pairs
     Medium,    // Decent pairs, two pair
     Strong,    // Three of a kind, straights, flushes
@@ -59,16 +117,31 @@ enum HandStrength {
 }
 
 /// Advanced AI decision making system
-pub fn make_advanced_ai_decision(
+pub fn make_advanced_ai_decision<R: Rng>(
     player: &Player,
     betting_round: &BettingRound,
-    community_cards: &[Card],
+    game_data: &GameData,
     personality: &AIPersonality,
     players_in_hand: usize,
     position: usize, // 0 = early, higher = later
+    rng: &mut R,
 ) -> PlayerAction {
-    // Evaluate current hand strength
-    let hand_strength = evaluate_hand_strength(&player.hole_cards, community_cards);
+    let community_cards = &game_data.community_cards;
+    let eval_cache = &game_data.eval_cache;
+    
+    // Evaluate current hand strength, both measures from a single cached evaluation
+    let (hand_strength, score) = if community_cards.len() < 3 {
+        (evaluate_preflop_strength(&player.hole_cards), preflop_score(&player.hole_cards))
+    } else {
+        let evaluation = eval_cache.evaluate(&player.hole_cards, community_cards);
+        (strength_from_evaluation(&evaluation), hand_score(&evaluation, &player.hole_cards, community_cards))
+    };
+    let (hand_strength, score) = match personality.difficulty {
+        AIDifficulty::Beginner => (hand_strength, score),
+        AIDifficulty::Intermediate | AIDifficulty::Advanced | AIDifficulty::Expert => {
+            adjust_for_paired_board(hand_strength, score, community_cards)
+        },
+    };
     
     // Calculate pot odds
     let pot_odds = calculate_pot_odds(betting_round, player);
@@ -76,11 +149,44 @@ pub fn make_advanced_ai_decision(
     // Get base action based on difficulty and hand strength
     let base_action = match personality.difficulty {
         AIDifficulty::Beginner => beginner_decision(player, betting_round, &hand_strength),
-        AIDifficulty::Intermediate => intermediate_decision(
+        AIDifficulty::Expert => expert_decision(player, betting_round, community_cards, personality, players_in_hand, rng),
+        // Advanced reads a bettor's range as tighter than random before continuing post-flop,
+        // and a re-raiser's as mostly premiums
+        AIDifficulty::Advanced if pot_odds > 0.0 && community_cards.len() >= 3 && hand_strength != HandStrength::VeryStrong => {
+            let raises = betting_round
+                .action_history
+                .iter()
+                .filter(|(_, action)| matches!(action, PlayerAction::Raise(_)))
+                .count();
+            let range = if raises >= 2 { HandRange::premiums() } else { HandRange::betting_range() };
+            let equity = estimate_equity_vs_range(
+                &player.hole_cards,
+                community_cards,
+                &range,
+                players_in_hand.saturating_sub(1).max(1),
+                ADVANCED_EQUITY_ITERATIONS,
+                rng,
+            );
+            if equity < pot_odds {
+                PlayerAction::Fold
+            } else {
+                intermediate_decision(
+                    player,
+                    betting_round,
+                    &hand_strength,
+                    score,
+                    personality,
+                    players_in_hand,
+                    position
+                )
+            }
+        },
+        // Advanced shares the intermediate logic but with a sharper, more aggressive personality
+        AIDifficulty::Intermediate | AIDifficulty::Advanced => intermediate_decision(
             player, 
             betting_round, 
             &hand_strength, 
-            pot_odds, 
+            score, 
             personality, 
             players_in_hand,
             position
@@ -88,19 +194,78 @@ pub fn make_advanced_ai_decision(
     };
     
     // Add randomness and personality adjustments
-    apply_personality_adjustments(base_action, personality, &hand_strength, betting_round)
+    apply_personality_adjustments(base_action, personality, &hand_strength, betting_round, rng)
+}
+
+/// A beginner-readable tell: a fixed habit an AI shows only while holding a strong hand
+pub fn ai_tell(hole_cards: &[Card], community_cards: &[Card], eval_cache: &HandEvalCache) -> Option<&'static str> {
+    if hole_cards.is_empty() || evaluate_hand_strength(hole_cards, community_cards, eval_cache) < HandStrength::Strong {
+        return None;
+    }
+    Some("🤲 Stacking chips")
 }
 
 /// Evaluate the strength of a poker hand
-fn evaluate_hand_strength(hole_cards: &[Card], community_cards: &[Card]) -> HandStrength {
+pub fn evaluate_hand_strength(hole_cards: &[Card], community_cards: &[Card], eval_cache: &HandEvalCache) -> HandStrength {
     // If we don't have enough cards yet, evaluate based on hole cards only
     if community_cards.len() < 3 {
         return evaluate_preflop_strength(hole_cards);
     }
     
     // Evaluate best 5-card hand
-    let evaluation = evaluate_hand(hole_cards, community_cards);
+    strength_from_evaluation(&eval_cache.evaluate(hole_cards, community_cards))
+}
+
+/// Continuous post-flop hand strength from 0.0 to 1.0: the made hand plus a share of its draw potential
+pub fn hand_score(evaluation: &HandEvaluation, hole_cards: &[Card], community_cards: &[Card]) -> f32 {
+    let made = made_hand_score(evaluation);
+    let outs = count_outs(hole_cards, community_cards).len();
+    let draw_chance = outs_hit_probability(outs, community_cards.len());
+    (made + (1.0 - made) * draw_chance * DRAW_SCORE_WEIGHT).min(1.0)
+}
+
+/// A paired board makes full houses possible, so any hand short of one drops a tier and
+/// loses about a tier's worth of score
+fn adjust_for_paired_board(hand_strength: HandStrength, score: f32, community_cards: &[Card]) -> (HandStrength, f32) {
+    let board_paired = community_cards
+        .iter()
+        .enumerate()
+        .any(|(i, card)| community_cards[i + 1..].iter().any(|other| other.rank == card.rank));
+    if !board_paired || hand_strength == HandStrength::VeryStrong {
+        return (hand_strength, score);
+    }
     
+    let wary_strength = match hand_strength {
+        HandStrength::Strong => HandStrength::Medium,
+        _ => HandStrength::Weak,
+    };
+    (wary_strength, (score - PAIRED_BOARD_SCORE_PENALTY).max(0.0))
+}
+
+// Each hand rank gets a tenth of the scale; the hand's top card places it within that band
+fn made_hand_score(evaluation: &HandEvaluation) -> f32 {
+    let rank_band = (evaluation.rank.clone() as u8 - 1) as f32;
+    let within_band = evaluation.primary_value.saturating_sub(2) as f32 / 13.0;
+    (rank_band + within_band) / 10.0
+}
+
+// Pre-flop score on the same scale as the tiers: 22 scores like a weak pair, AA near the top of Strong
+fn preflop_score(hole_cards: &[Card]) -> f32 {
+    let [card1, card2] = hole_cards else {
+        return 0.0;
+    };
+    let high = (card1.rank as u8).max(card2.rank as u8);
+    let low = (card1.rank as u8).min(card2.rank as u8);
+    
+    if high == low {
+        return 0.1 + 0.3 * (high - 2) as f32 / 12.0;
+    }
+    let suited_bonus = if card1.suit == card2.suit { 0.02 } else { 0.0 };
+    0.02 + 0.2 * (high + low - 5) as f32 / 22.0 + suited_bonus
+}
+
+/// Map a made-hand evaluation onto a strength category
+fn strength_from_evaluation(evaluation: &HandEvaluation) -> HandStrength {
     match evaluation.rank {
         crate::poker_rules::HandRank::HighCard => {
             if evaluation.primary_value >= 12 { // Queen high or better
@@ -235,12 +400,13 @@ fn intermediate_decision(
     player: &Player,
     betting_round: &BettingRound,
     hand_strength: &HandStrength,
-    pot_odds: f32,
+    score: f32,
     personality: &AIPersonality,
     players_in_hand: usize,
     position: usize,
 ) -> PlayerAction {
     let call_amount = betting_round.current_bet.saturating_sub(player.current_bet);
+    let pot_odds = calculate_pot_odds(betting_round, player);
     
     // Can't afford to call
     if call_amount > player.chips {
@@ -269,7 +435,7 @@ fn intermediate_decision(
     
     // Pot odds decision making
     let required_equity = pot_odds;
-    let estimated_equity = estimate_hand_equity(hand_strength, players_in_hand) * position_factor * player_factor;
+    let estimated_equity = estimate_hand_equity(score, players_in_hand) * position_factor * player_factor;
     
     match hand_strength {
         HandStrength::VeryStrong => {
@@ -315,14 +481,16 @@ fn intermediate_decision(
     }
 }
 
-/// Estimate hand equity (probability of winning) based on hand strength
-fn estimate_hand_equity(hand_strength: &HandStrength, players_in_hand: usize) -> f32 {
-    let base_equity = match hand_strength {
-        HandStrength::Weak => 0.15,
-        HandStrength::Medium => 0.35,
-        HandStrength::Strong => 0.65,
-        HandStrength::VeryStrong => 0.85,
-    };
+/// Estimate hand equity (probability of winning) from the hand score
+fn estimate_hand_equity(score: f32, players_in_hand: usize) -> f32 {
+    let score = score.clamp(0.0, 1.0);
+    let base_equity = SCORE_EQUITY_CURVE
+        .windows(2)
+        .find(|points| score <= points[1].0)
+        .map_or(SCORE_EQUITY_CURVE[SCORE_EQUITY_CURVE.len() - 1].1, |points| {
+            let ((score_a, equity_a), (score_b, equity_b)) = (points[0], points[1]);
+            equity_a + (equity_b - equity_a) * (score - score_a) / (score_b - score_a)
+        });
     
     // Adjust for number of opponents
     let opponent_factor = match players_in_hand {
@@ -335,15 +503,51 @@ fn estimate_hand_equity(hand_strength: &HandStrength, players_in_hand: usize) ->
     base_equity * opponent_factor
 }
 
+/// Expert play: simulate the rest of the board against random hands and weigh the win chance against the pot odds
+fn expert_decision<R: Rng>(
+    player: &Player,
+    betting_round: &BettingRound,
+    community_cards: &[Card],
+    personality: &AIPersonality,
+    players_in_hand: usize,
+    rng: &mut R,
+) -> PlayerAction {
+    let equity = estimate_equity(
+        &player.hole_cards,
+        community_cards,
+        players_in_hand.saturating_sub(1).max(1),
+        personality.equity_iterations.unwrap_or(EXPERT_EQUITY_ITERATIONS),
+        rng,
+    );
+    let call_amount = betting_round.current_bet.saturating_sub(player.current_bet);
+    let value_raise = (betting_round.pot / 2).max(betting_round.min_full_raise());
+    
+    if call_amount == 0 {
+        return if equity >= EXPERT_VALUE_EQUITY && value_raise <= player.chips {
+            PlayerAction::Raise(value_raise)
+        } else {
+            PlayerAction::Check
+        };
+    }
+    
+    // Continue only when the win chance beats the price of calling; raise when well ahead
+    if equity < calculate_pot_odds(betting_round, player) {
+        PlayerAction::Fold
+    } else if equity >= EXPERT_VALUE_EQUITY && call_amount + value_raise <= player.chips {
+        PlayerAction::Raise(value_raise)
+    } else {
+        PlayerAction::Call
+    }
+}
+
 /// Apply personality adjustments to the base decision
-fn apply_personality_adjustments(
+fn apply_personality_adjustments<R: Rng>(
     base_action: PlayerAction,
     personality: &AIPersonality,
     hand_strength: &HandStrength,
     betting_round: &BettingRound,
+    rng: &mut R,
 ) -> PlayerAction {
-    let mut rng = rand::thread_rng();
-    
     // Add some randomness (5-15% chance to deviate)
     if rng.gen::<f32>() < 0.1 {
         match base_action {
@@ -379,6 +583,16 @@ pub struct AIPlayerComponent {
     pub personality: AIPersonality,
 }
 
+/// A seat's own random source, so two AIs with the same hand and personality still vary independently
+#[derive(Component, Debug, Clone)]
+pub struct AIRng(pub StdRng);
+
+impl AIRng {
+    pub fn for_seat(base_seed: u64, seat_id: u32) -> Self {
+        Self(StdRng::seed_from_u64(base_seed.wrapping_add(seat_id as u64)))
+    }
+}
+
 impl Default for AIPlayerComponent {
     fn default() -> Self {
         Self {
@@ -386,3 +600,214 @@ impl Default for AIPlayerComponent {
         }
     }
 }
+
+// Resource for difficulty cycling: presses are queued and applied at the next hand boundary
+#[derive(Resource, Default)]
+pub struct AIDifficultyCycle {
+    pub pending_steps: u32,
+}
+
+// System to queue an AI difficulty bump with D
+pub fn handle_difficulty_cycle_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut difficulty_cycle: ResMut<AIDifficultyCycle>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyD) {
+        difficulty_cycle.pending_steps += 1;
+        info!("🤖 AI difficulty will advance at the start of the next hand");
+    }
+}
+
+// System to swap AI personalities when a new hand starts
+pub fn apply_difficulty_cycle(
+    current_state: Res<State<GameState>>,
+    mut difficulty_cycle: ResMut<AIDifficultyCycle>,
+    mut ai_players: Query<(&Player, &mut AIPlayer, &mut AIPlayerComponent)>,
+) {
+    if !current_state.is_changed() || *current_state.get() != GameState::Setup {
+        return;
+    }
+    if difficulty_cycle.pending_steps == 0 {
+        return;
+    }
+    
+    for (player, mut ai_player, mut ai_component) in ai_players.iter_mut() {
+        let mut difficulty = ai_component.personality.difficulty;
+        for _ in 0..difficulty_cycle.pending_steps {
+            difficulty = difficulty.next();
+        }
+        
+        ai_player.difficulty = difficulty;
+        ai_component.personality = AIPersonality::for_difficulty(difficulty);
+        info!("🤖 AI Player {} is now {:?}", player.id, difficulty);
+    }
+    
+    difficulty_cycle.pending_steps = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::PlayerType;
+    use crate::poker_rules::evaluate_hand;
+    
+    #[test]
+    fn test_hand_score_orders_set_over_top_pair_over_weak_draw() {
+        use crate::cards::{Rank, Suit};
+        
+        let flop = [
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Spades, Rank::Ten),
+            Card::new(Suit::Diamonds, Rank::Three),
+        ];
+        let set = [Card::new(Suit::Clubs, Rank::Three), Card::new(Suit::Spades, Rank::Three)];
+        let top_pair = [Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Diamonds, Rank::Jack)];
+        let gutshot = [Card::new(Suit::Clubs, Rank::Nine), Card::new(Suit::Diamonds, Rank::Seven)];
+        
+        let score = |hole: &[Card]| hand_score(&evaluate_hand(hole, &flop), hole, &flop);
+        let set_score = score(&set);
+        let top_pair_score = score(&top_pair);
+        let gutshot_score = score(&gutshot);
+        assert!(set_score > top_pair_score, "set {} vs top pair {}", set_score, top_pair_score);
+        assert!(top_pair_score > gutshot_score, "top pair {} vs gutshot {}", top_pair_score, gutshot_score);
+        assert!((0.0..=1.0).contains(&gutshot_score) && set_score <= 1.0);
+        
+        // The draw itself is worth something over the same high card with no draw
+        let no_draw = [Card::new(Suit::Clubs, Rank::Four), Card::new(Suit::Diamonds, Rank::Two)];
+        assert!(gutshot_score > score(&no_draw));
+        
+        // Equity rises smoothly with the score instead of jumping at tier boundaries
+        assert!(estimate_hand_equity(set_score, 2) > estimate_hand_equity(top_pair_score, 2));
+        assert!((estimate_hand_equity(0.29, 2) - estimate_hand_equity(0.31, 2)).abs() < 0.05);
+    }
+    
+    #[test]
+    fn test_nut_straight_slows_down_once_the_board_pairs() {
+        use crate::cards::{Rank, Suit};
+        
+        let mut player = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        player.hole_cards = vec![Card::new(Suit::Hearts, Rank::Nine), Card::new(Suit::Hearts, Rank::Eight)];
+        let mut board = vec![
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Diamonds, Rank::Six),
+            Card::new(Suit::Spades, Rank::Five),
+        ];
+        let betting_round = BettingRound { current_bet: 0, pot: 200, ..default() };
+        let personality = AIPersonality::intermediate();
+        let decide = |board: &[Card]| {
+            let evaluation = evaluate_hand(&player.hole_cards, board);
+            let score = hand_score(&evaluation, &player.hole_cards, board);
+            let (strength, score) = adjust_for_paired_board(strength_from_evaluation(&evaluation), score, board);
+            (intermediate_decision(&player, &betting_round, &strength, score, &personality, 2, 1), score)
+        };
+        
+        // The nut straight on the flop bets for value
+        let (flop_action, flop_score) = decide(&board);
+        assert!(matches!(flop_action, PlayerAction::Raise(_)), "{:?}", flop_action);
+        
+        // The turn pairs the board: the straight is unchanged, but full houses are now possible
+        board.push(Card::new(Suit::Hearts, Rank::Five));
+        let (turn_action, turn_score) = decide(&board);
+        assert_eq!(turn_action, PlayerAction::Check);
+        assert!(turn_score < flop_score);
+        
+        // A full house of its own has nothing to fear
+        let boat = [Card::new(Suit::Hearts, Rank::Seven), Card::new(Suit::Spades, Rank::Seven)];
+        let (strength, _) = adjust_for_paired_board(evaluate_hand_strength(&boat, &board, &HandEvalCache::default()), 0.7, &board);
+        assert_eq!(strength, HandStrength::VeryStrong);
+    }
+    
+    #[test]
+    fn test_identical_seats_draw_independent_randomness() {
+        use crate::cards::{Rank, Suit};
+        
+        let seat = |id: u32, hole_cards: [Card; 2]| {
+            let mut player = Player::new(id, PlayerType::AI, 1000, Vec3::ZERO);
+            player.hole_cards = hole_cards.to_vec();
+            player
+        };
+        let seats = [
+            seat(1, [Card::new(Suit::Clubs, Rank::Seven), Card::new(Suit::Diamonds, Rank::Two)]),
+            seat(2, [Card::new(Suit::Diamonds, Rank::Seven), Card::new(Suit::Clubs, Rank::Two)]),
+        ];
+        let betting_round = BettingRound { current_bet: 0, pot: 30, ..default() };
+        let game_data = GameData::default();
+        let personality = AIPersonality { bluff_frequency: 0.5, ..AIPersonality::intermediate() };
+        
+        // Each seat's choices over several unopened pots, from its own RNG
+        let play = |player: &Player| {
+            let mut rng = AIRng::for_seat(42, player.id);
+            (0..20)
+                .map(|_| make_advanced_ai_decision(player, &betting_round, &game_data, &personality, 3, 1, &mut rng.0))
+                .collect::<Vec<_>>()
+        };
+        
+        let (first, second) = (play(&seats[0]), play(&seats[1]));
+        assert_ne!(first, second, "equal hands in equal seats should sometimes act differently");
+        assert_eq!(first, play(&seats[0]), "a seat's choices are reproducible from its seed");
+    }
+    
+    #[test]
+    fn test_expert_folds_seven_deuce_to_a_big_bet_far_more_than_aces() {
+        use crate::cards::{Rank, Suit};
+        use rand::rngs::StdRng;
+        
+        // Heads-up pre-flop, facing a pot-sized raise
+        let betting_round = BettingRound { current_bet: 300, pot: 330, ..default() };
+        let game_data = GameData::default();
+        let personality = AIPersonality { equity_iterations: Some(200), ..AIPersonality::expert() };
+        let mut rng = StdRng::seed_from_u64(8);
+        let mut folds = |hole_cards: [Card; 2]| {
+            let mut player = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+            player.hole_cards = hole_cards.to_vec();
+            player.current_bet = 20;
+            (0..40)
+                .filter(|_| {
+                    make_advanced_ai_decision(&player, &betting_round, &game_data, &personality, 2, 1, &mut rng) == PlayerAction::Fold
+                })
+                .count()
+        };
+        
+        let seven_deuce = folds([Card::new(Suit::Clubs, Rank::Seven), Card::new(Suit::Diamonds, Rank::Two)]);
+        let aces = folds([Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::Ace)]);
+        assert!(seven_deuce >= 30, "7-2 offsuit folded {} of 40", seven_deuce);
+        assert_eq!(aces, 0);
+        assert_eq!(AIDifficulty::Advanced.next(), AIDifficulty::Expert);
+    }
+    
+    #[test]
+    fn test_cycle_key_advances_ai_difficulty_on_next_hand() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<AIDifficultyCycle>()
+            .add_systems(Update, (handle_difficulty_cycle_input, apply_difficulty_cycle).chain());
+        
+        let ai = app.world_mut().spawn((
+            Player::new(1, PlayerType::AI, 1000, Vec3::ZERO),
+            AIPlayer { difficulty: AIDifficulty::Beginner },
+            AIPlayerComponent { personality: AIPersonality::beginner() },
+        )).id();
+        
+        // Move into the middle of a hand, then press the cycle key
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::PreFlop);
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyD);
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+        
+        // Nothing changes until the following hand
+        let difficulty = app.world().get::<AIPlayerComponent>(ai).unwrap().personality.difficulty;
+        assert_eq!(difficulty, AIDifficulty::Beginner);
+        
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Setup);
+        app.update();
+        
+        let personality = &app.world().get::<AIPlayerComponent>(ai).unwrap().personality;
+        assert_eq!(personality.difficulty, AIDifficulty::Intermediate);
+        assert_eq!(personality.position_awareness, AIPersonality::intermediate().position_awareness);
+        assert_eq!(app.world().get::<AIPlayer>(ai).unwrap().difficulty, AIDifficulty::Intermediate);
+        assert_eq!(app.world().resource::<AIDifficultyCycle>().pending_steps, 0);
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use rand::Rng;
+use crate::game_state::{GameData, GameState};
+use crate::player::Player;
 
 // Animation types
 #[derive(Component)]
@@ -27,15 +29,31 @@ pub struct ChipAnimation {
     pub duration: f32,
 }
 
+// A chip stack sprite sliding from the pot to a winner
+#[derive(Component)]
+pub struct PotChip;
+
+// Accessibility setting: with reduced motion on, cards and chips snap to where they're going
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct ReducedMotion {
+    pub enabled: bool,
+}
+
+// Visual chip stacks the pot is drawn as when it's pushed to the winners
+const POT_CHIP_STACKS: usize = 6;
+const POT_POSITION: Vec3 = Vec3::new(0.0, 70.0, 2.0);
+const POT_CHIP_COLOR: Color = Color::srgb(0.9, 0.75, 0.2);
+
 // Plugin for animations
 pub struct AnimationPlugin;
 
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (
+        app.init_resource::<ReducedMotion>().add_systems(Update, (
             animate_cards,
             animate_chips,
             cleanup_finished_animations,
+            animate_pot_awards,
         ));
     }
 }
@@ -43,10 +61,12 @@ impl Plugin for AnimationPlugin {
 // System to animate card movements
 fn animate_cards(
     time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
     mut query: Query<(&mut Transform, &mut CardAnimation)>,
 ) {
     for (mut transform, mut animation) in query.iter_mut() {
-        animation.progress += time.delta_seconds() / animation.duration;
+        // Reduced motion finishes anything already in flight straight away
+        animation.progress = if reduced_motion.enabled { 1.0 } else { animation.progress + time.delta_seconds() / animation.duration };
         
         if animation.progress <= 1.0 {
             // Smooth easing function (ease-out)
@@ -71,10 +91,11 @@ fn animate_cards(
 // System to animate chip movements
 fn animate_chips(
     time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
     mut query: Query<(&mut Transform, &mut ChipAnimation)>,
 ) {
     for (mut transform, mut animation) in query.iter_mut() {
-        animation.progress += time.delta_seconds() / animation.duration;
+        animation.progress = if reduced_motion.enabled { 1.0 } else { animation.progress + time.delta_seconds() / animation.duration };
         
         if animation.progress <= 1.0 {
             // Smooth easing with slight arc for chip movement
@@ -130,13 +151,19 @@ pub fn animate_card_deal(
     });
 }
 
+// With reduced motion the chips are placed at the end instead
 pub fn animate_chip_movement(
     commands: &mut Commands,
     entity: Entity,
     start_pos: Vec3,
     end_pos: Vec3,
     duration: f32,
+    reduced_motion: &ReducedMotion,
 ) {
+    if reduced_motion.enabled {
+        commands.entity(entity).insert(Transform::from_translation(end_pos));
+        return;
+    }
     commands.entity(entity).insert(ChipAnimation {
         start_pos,
         end_pos,
@@ -145,6 +172,72 @@ pub fn animate_chip_movement(
     });
 }
 
+/// Share out the pot's chip stacks between winners in proportion to what each won,
+/// giving every winner at least one stack
+pub fn chip_stacks_per_winner(awards: &[(u32, u32)], total_stacks: usize) -> Vec<usize> {
+    let total: u32 = awards.iter().map(|(_, amount)| amount).sum();
+    if total == 0 {
+        return vec![1; awards.len()];
+    }
+    
+    awards
+        .iter()
+        .map(|(_, amount)| ((total_stacks as f32 * *amount as f32 / total as f32).round() as usize).max(1))
+        .collect()
+}
+
+// System to push the pot's chips to the winner(s) once the hand is decided; a split pot fans out
+fn animate_pot_awards(
+    mut commands: Commands,
+    game_state: Res<State<GameState>>,
+    game_data: Res<GameData>,
+    reduced_motion: Res<ReducedMotion>,
+    players: Query<&Player>,
+    pot_chips: Query<Entity, With<PotChip>>,
+) {
+    if !game_state.is_changed() {
+        return;
+    }
+    
+    // Chips from the last hand are cleared when a new one starts
+    if *game_state.get() == GameState::Setup {
+        for entity in pot_chips.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+    if *game_state.get() != GameState::GameOver {
+        return;
+    }
+    
+    let stacks = chip_stacks_per_winner(&game_data.pot_awards, POT_CHIP_STACKS);
+    for (&(winner_id, _), stack_count) in game_data.pot_awards.iter().zip(stacks) {
+        let Some(winner) = players.iter().find(|p| p.id == winner_id) else {
+            continue;
+        };
+        
+        for i in 0..stack_count {
+            // Stagger the stacks slightly so they don't overlap in flight
+            let offset = Vec3::new(i as f32 * 6.0 - (stack_count as f32 - 1.0) * 3.0, 0.0, 0.0);
+            let entity = commands
+                .spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: POT_CHIP_COLOR,
+                            custom_size: Some(Vec2::new(16.0, 8.0)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(POT_POSITION + offset),
+                        ..default()
+                    },
+                    PotChip,
+                ))
+                .id();
+            animate_chip_movement(&mut commands, entity, POT_POSITION + offset, winner.position + offset, 0.8, &reduced_motion);
+        }
+    }
+}
+
 // Particle effect for celebrations
 #[derive(Component)]
 pub struct ParticleEffect {
@@ -214,3 +307,60 @@ fn update_particles(
         effect.particles.retain(|p| p.lifetime > 0.0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::player::PlayerType;
+    
+    #[test]
+    fn test_split_pot_fans_chips_out_to_both_winners() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<GameData>()
+            .init_resource::<ReducedMotion>()
+            .add_systems(Update, animate_pot_awards);
+        
+        let left = Vec3::new(-300.0, 150.0, 0.0);
+        let right = Vec3::new(300.0, 150.0, 0.0);
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, left));
+        app.world_mut().spawn(Player::new(2, PlayerType::AI, 1000, right));
+        app.world_mut().resource_mut::<GameData>().pot_awards = vec![(1, 50), (2, 50)];
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::GameOver);
+        app.update();
+        
+        let mut chips = app.world_mut().query_filtered::<&ChipAnimation, With<PotChip>>();
+        let targets: Vec<f32> = chips.iter(app.world()).map(|animation| animation.end_pos.x).collect();
+        
+        // Each winner gets half of the stacks, all heading to their seat
+        assert_eq!(targets.len(), POT_CHIP_STACKS);
+        assert_eq!(targets.iter().filter(|x| (*x - left.x).abs() < 20.0).count(), POT_CHIP_STACKS / 2);
+        assert_eq!(targets.iter().filter(|x| (*x - right.x).abs() < 20.0).count(), POT_CHIP_STACKS / 2);
+    }
+    
+    #[test]
+    fn test_reduced_motion_places_pot_chips_without_animating() {
+        // The pot push is the only motion the game runs; nothing calls the card deal helper yet
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<GameData>()
+            .insert_resource(ReducedMotion { enabled: true })
+            .add_systems(Update, animate_pot_awards);
+        
+        // The pot's chips land on the winner without a flight
+        let winner_seat = Vec3::new(300.0, 150.0, 0.0);
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, winner_seat));
+        app.world_mut().resource_mut::<GameData>().pot_awards = vec![(1, 100)];
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::GameOver);
+        app.update();
+        
+        let mut chips = app.world_mut().query_filtered::<&Transform, With<PotChip>>();
+        assert_eq!(chips.iter(app.world()).count(), POT_CHIP_STACKS);
+        assert!(chips.iter(app.world()).all(|transform| (transform.translation.x - winner_seat.x).abs() < 20.0));
+        let mut animations = app.world_mut().query::<&ChipAnimation>();
+        assert_eq!(animations.iter(app.world()).count(), 0);
+    }
+}
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
 use crate::player::{Player, PlayerType, AIPlayer};
-use crate::game_state::GameState;
-use crate::ai_player::{make_advanced_ai_decision, AIPlayerComponent};
+use crate::game_state::{GameEvent, GameState};
+use crate::ai_player::{make_advanced_ai_decision, AIPlayerComponent, AIRng};
 use crate::cards::Card;
 use crate::betting_ui::HumanPlayerInput;
+use crate::teaching::TeachingState;
 
 // Player betting actions
 #[derive(Debug, Clone, PartialEq)]
@@ -19,9 +20,12 @@ pub enum PlayerAction {
 pub struct BettingRound {
     pub current_bet: u32,
     pub min_raise: u32,
-    pub players_to_act: Vec<u32>,
+    pub big_blind: u32, // Smallest opening bet once the street starts unbet
+    pub players_to_act: Vec<u32>, // Queue in acting order; the front player acts next
     pub betting_complete: bool,
     pub pot: u32,
+    pub action_history: Vec<(u32, PlayerAction)>, // Actions taken this street, in order
+    pub raise_closed: Vec<u32>, // Already acted before an incomplete all-in raise: may only call or fold
 }
 
 impl Default for BettingRound {
@@ -29,9 +33,12 @@ impl Default for BettingRound {
         Self {
             current_bet: 0,
             min_raise: 10, // Minimum raise amount
+            big_blind: 20,
             players_to_act: Vec::new(),
             betting_complete: false,
             pot: 0,
+            action_history: Vec::new(),
+            raise_closed: Vec::new(),
         }
     }
 }
@@ -41,29 +48,74 @@ impl BettingRound {
         Self {
             current_bet: small_blind * 2, // Big blind
             min_raise: small_blind * 2,
+            big_blind: small_blind * 2,
             players_to_act: player_ids,
             betting_complete: false,
             pot: 0,
+            action_history: Vec::new(),
+            raise_closed: Vec::new(),
         }
     }
     
     pub fn reset_for_new_round(&mut self, player_ids: Vec<u32>) {
         self.current_bet = 0;
+        self.min_raise = self.big_blind;
         self.players_to_act = player_ids;
         self.betting_complete = false;
+        self.action_history.clear();
+        self.raise_closed.clear();
         info!("Betting round reset - players to act: {:?}", self.players_to_act);
     }
     
+    /// Re-open the action after a raise so everyone still able to act gets to respond,
+    /// in seat order starting with the player left of the raiser
+    pub fn reopen_action(&mut self, raiser_id: u32, seat_order: &[u32]) {
+        let (before, after): (Vec<u32>, Vec<u32>) = seat_order
+            .iter()
+            .filter(|&&id| id != raiser_id)
+            .partition(|&&id| id < raiser_id);
+        
+        let mut order = after;
+        order.extend(before);
+        
+        self.players_to_act = order;
+        self.betting_complete = false;
+        info!("Action re-opened by Player {} - players to act: {:?}", raiser_id, self.players_to_act);
+    }
+    
+    /// Check a `Raise(amount)` against the table minimums. With nothing bet yet it is an
+    /// opening bet and must be at least the big blind; otherwise it must match the last raise.
+    pub fn validate_raise(&self, amount: u32) -> Result<(), InvalidRaise> {
+        if self.current_bet == 0 {
+            if amount < self.big_blind {
+                return Err(InvalidRaise::BetBelowBigBlind { amount, minimum: self.big_blind });
+            }
+        } else if amount < self.min_raise {
+            return Err(InvalidRaise::RaiseBelowMinimum { amount, minimum: self.min_raise });
+        }
+        Ok(())
+    }
+    
+    // Smallest raise that counts as a full raise and re-opens the betting
+    pub fn min_full_raise(&self) -> u32 {
+        if self.current_bet == 0 { self.big_blind } else { self.min_raise }
+    }
+    
+    pub fn can_raise(&self, player_id: u32) -> bool {
+        !self.raise_closed.contains(&player_id)
+    }
+    
     pub fn is_complete(&self) -> bool {
         self.betting_complete || self.players_to_act.is_empty()
     }
     
     pub fn peek_next_player(&self) -> Option<u32> {
-        self.players_to_act.last().copied()
+        self.players_to_act.first().copied()
     }
     
     pub fn next_player(&mut self) -> Option<u32> {
-        if let Some(player_id) = self.players_to_act.pop() {
+        if !self.players_to_act.is_empty() {
+            let player_id = self.players_to_act.remove(0);
             info!("Next player to act: {}, remaining: {:?}", player_id, self.players_to_act);
             Some(player_id)
         } else {
@@ -74,6 +126,80 @@ impl BettingRound {
     }
 }
 
+// One layer of the pot: the main pot, or a side pot above a shorter stack's all-in
+#[derive(Debug, Clone, PartialEq)]
+pub struct SidePot {
+    pub amount: u32,
+    pub eligible: Vec<u32>, // Live players who paid into the whole layer
+    pub contributors: usize, // Everyone who put chips into it, folded players included
+}
+
+impl SidePot {
+    // A layer only its bettor paid into was never called, so it goes straight back
+    pub fn is_uncalled(&self) -> bool {
+        self.contributors == 1 && self.eligible.len() == 1
+    }
+}
+
+/// Split the pot into layers from each player's (id, chips put in this hand, still live) entry:
+/// every live player's total caps a layer, and each layer can only be won by live players who
+/// matched it. Chips folded players put in above the top cap join the top layer.
+pub fn build_side_pots(contributions: &[(u32, u32, bool)]) -> Vec<SidePot> {
+    let mut caps: Vec<u32> = contributions
+        .iter()
+        .filter(|(_, amount, live)| *live && *amount > 0)
+        .map(|(_, amount, _)| *amount)
+        .collect();
+    caps.sort();
+    caps.dedup();
+    
+    let mut pots: Vec<SidePot> = Vec::new();
+    let mut floor = 0;
+    for cap in caps {
+        let layer: Vec<(u32, u32, bool)> = contributions
+            .iter()
+            .map(|&(id, amount, live)| (id, amount.clamp(floor, cap) - floor, live))
+            .filter(|(_, in_layer, _)| *in_layer > 0)
+            .collect();
+        pots.push(SidePot {
+            amount: layer.iter().map(|(_, in_layer, _)| in_layer).sum(),
+            eligible: layer.iter().filter(|(_, in_layer, live)| *live && *in_layer == cap - floor).map(|(id, _, _)| *id).collect(),
+            contributors: layer.len(),
+        });
+        floor = cap;
+    }
+    
+    let dead_money: u32 = contributions.iter().map(|(_, amount, _)| amount.saturating_sub(floor)).sum();
+    if let Some(top) = pots.last_mut() {
+        top.amount += dead_money;
+    }
+    pots
+}
+
+// A bet or raise smaller than the table allows
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvalidRaise {
+    BetBelowBigBlind { amount: u32, minimum: u32 },
+    RaiseBelowMinimum { amount: u32, minimum: u32 },
+    ActionNotReopened, // Only an incomplete all-in raise came in since the player acted
+}
+
+impl std::fmt::Display for InvalidRaise {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InvalidRaise::BetBelowBigBlind { amount, minimum } => {
+                write!(f, "an opening bet of ${} is below the ${} big blind", amount, minimum)
+            },
+            InvalidRaise::RaiseBelowMinimum { amount, minimum } => {
+                write!(f, "a raise of ${} is below the ${} minimum raise", amount, minimum)
+            },
+            InvalidRaise::ActionNotReopened => {
+                write!(f, "an all-in for less than a full raise doesn't re-open the betting")
+            },
+        }
+    }
+}
+
 // Simple AI decision making
 fn make_ai_decision(player: &Player, betting_round: &BettingRound) -> PlayerAction {
     let call_amount = betting_round.current_bet.saturating_sub(player.current_bet);
@@ -94,12 +220,19 @@ fn make_ai_decision(player: &Player, betting_round: &BettingRound) -> PlayerActi
 
 // System to handle AI player decisions
 pub fn ai_player_system(
-    mut players: Query<(&mut Player, Option<&AIPlayerComponent>)>,
+    mut players: Query<(&mut Player, Option<&AIPlayerComponent>, Option<&mut AIRng>)>,
     mut betting_round: ResMut<BettingRound>,
     game_state: Res<State<GameState>>,
     game_data: Res<crate::game_state::GameData>,
     mut human_input: ResMut<HumanPlayerInput>,
+    teaching_state: Option<Res<TeachingState>>,
+    mut game_events: EventWriter<GameEvent>,
 ) {
+    // Nobody acts while a tutorial checkpoint is on screen
+    if teaching_state.is_some_and(|teaching_state| teaching_state.at_checkpoint()) {
+        return;
+    }
+    
     // Only process AI actions during betting phases
     match game_state.get() {
         GameState::PreFlop | GameState::Flop | GameState::Turn | GameState::River => {},
@@ -114,13 +247,13 @@ pub fn ai_player_system(
     if let Some(current_player_id) = betting_round.peek_next_player() {
         // First pass: count active players and find current player
         let active_players = players.iter()
-            .filter(|(p, _)| !p.has_folded)
+            .filter(|(p, _, _)| !p.has_folded)
             .count();
         
         let mut current_player_data: Option<(Player, Option<AIPlayerComponent>)> = None;
         
         // Find the current player and clone their data
-        for (player, ai_component) in players.iter() {
+        for (player, ai_component, _) in players.iter() {
             if player.id == current_player_id && !player.has_folded {
                 current_player_data = Some((
                     player.clone(),
@@ -130,6 +263,12 @@ pub fn ai_player_system(
             }
         }
         
+        // Drop a queued player who has since folded so the next player isn't left waiting
+        if current_player_data.is_none() {
+            betting_round.next_player();
+            return;
+        }
+        
         if let Some((player_data, ai_comp)) = current_player_data {
             let action = match player_data.player_type {
                 PlayerType::AI => {
@@ -138,14 +277,24 @@ pub fn ai_player_system(
                     
                     // Use advanced AI if component is present, otherwise use simple AI
                     if let Some(ai_component) = ai_comp {
-                        make_advanced_ai_decision(
+                        // Each seat draws from its own seeded RNG when it has one
+                        let seat_rng = players
+                            .iter_mut()
+                            .find(|(p, _, _)| p.id == player_data.id)
+                            .and_then(|(_, _, seat_rng)| seat_rng);
+                        let decide = |mut rng: &mut dyn rand::RngCore| make_advanced_ai_decision(
                             &player_data,
                             &betting_round,
-                            &game_data.community_cards,
+                            &game_data,
                             &ai_component.personality,
                             active_players,
                             position,
-                        )
+                            &mut rng,
+                        );
+                        match seat_rng {
+                            Some(mut seat_rng) => decide(&mut seat_rng.0),
+                            None => decide(&mut rand::thread_rng()),
+                        }
                     } else {
                         make_ai_decision(&player_data, &betting_round)
                     }
@@ -161,29 +310,97 @@ pub fn ai_player_system(
                 },
             };
             
+            // Undersized bets and raises are rejected unless they put the player all-in
+            let action = match action {
+                PlayerAction::Raise(amount) => {
+                    let call_amount = betting_round.current_bet.saturating_sub(player_data.current_bet);
+                    let all_in = call_amount + amount >= player_data.chips;
+                    let validation = match betting_round.validate_raise(amount) {
+                        _ if !betting_round.can_raise(player_data.id) => Err(InvalidRaise::ActionNotReopened),
+                        Err(_) if all_in => Ok(()),
+                        result => result,
+                    };
+                    match validation {
+                        Err(error) => {
+                            warn!("Player {} raise rejected: {}", player_data.id, error);
+                            if matches!(player_data.player_type, PlayerType::Human) {
+                                return; // Human keeps the turn and can pick again
+                            }
+                            if call_amount == 0 { PlayerAction::Check } else { PlayerAction::Call }
+                        },
+                        _ => PlayerAction::Raise(amount),
+                    }
+                },
+                other => other,
+            };
+            
             // Only remove the player from the queue after they've made a decision
             betting_round.next_player(); // This pops the player from the queue
             
             // Second pass: apply the action to the actual player
-            for (mut player, _) in players.iter_mut() {
+            let bet_before_action = betting_round.current_bet;
+            let min_full_raise = betting_round.min_full_raise();
+            let already_acted: Vec<u32> = players
+                .iter()
+                .map(|(p, _, _)| p.id)
+                .filter(|id| *id != current_player_id && !betting_round.players_to_act.contains(id))
+                .collect();
+            betting_round.raise_closed.retain(|&id| id != current_player_id);
+            for (mut player, _, _) in players.iter_mut() {
                 if player.id == current_player_id {
-                    process_player_action(&mut player, action, &mut betting_round);
+                    game_events.send(GameEvent::Action { player: current_player_id, action: action.clone() });
+                    if let Err(error) = process_player_action(&mut player, action, &mut betting_round) {
+                        warn!("Player {} action rejected: {}", player.id, error);
+                    }
                     break;
                 }
             }
+            
+            // Everyone else still in the hand must respond to a raise. Only a full raise re-opens
+            // the betting, though: after a short all-in, players who already acted may just call or fold.
+            if betting_round.current_bet > bet_before_action {
+                let mut seat_order: Vec<u32> = players
+                    .iter()
+                    .filter(|(p, _, _)| p.can_act())
+                    .map(|(p, _, _)| p.id)
+                    .collect();
+                seat_order.sort();
+                betting_round.reopen_action(current_player_id, &seat_order);
+                
+                if betting_round.current_bet - bet_before_action >= min_full_raise {
+                    betting_round.raise_closed.clear();
+                } else {
+                    info!("Player {} made an incomplete raise - betting is not re-opened", current_player_id);
+                    for id in already_acted {
+                        if !betting_round.raise_closed.contains(&id) {
+                            betting_round.raise_closed.push(id);
+                        }
+                    }
+                }
+            }
         }
     }
 }
 
-// Process a player's action
+// Process a player's action; an undersized raise is rejected unless it puts the player all-in
 fn process_player_action(
     player: &mut Player,
     action: PlayerAction,
     betting_round: &mut BettingRound,
-) {
+) -> Result<(), InvalidRaise> {
+    if let PlayerAction::Raise(amount) = action {
+        let call_amount = betting_round.current_bet.saturating_sub(player.current_bet);
+        if call_amount + amount < player.chips {
+            betting_round.validate_raise(amount)?;
+        }
+    }
+    betting_round.action_history.push((player.id, action.clone()));
+    
     match action {
         PlayerAction::Fold => {
             player.has_folded = true;
+            // A folded player never acts again this hand, even if they were still queued
+            betting_round.players_to_act.retain(|&id| id != player.id);
             info!("Player {} folded", player.id);
         }
         PlayerAction::Check => {
@@ -207,24 +424,53 @@ fn process_player_action(
         }
         PlayerAction::Raise(amount) => {
             let total_bet = betting_round.current_bet + amount;
-            if player.chips >= total_bet {
-                let bet_amount = total_bet - player.current_bet;
+            let bet_amount = total_bet.saturating_sub(player.current_bet);
+            if player.chips >= bet_amount {
                 player.chips -= bet_amount;
                 player.current_bet = total_bet;
                 betting_round.pot += bet_amount;
+                // An all-in short of a full raise doesn't change the minimum
+                if amount >= betting_round.min_full_raise() {
+                    betting_round.min_raise = amount;
+                }
                 betting_round.current_bet = total_bet;
-                betting_round.min_raise = amount;
                 info!("Player {} raised to ${}", player.id, total_bet);
             } else {
-                // Convert to all-in
+                // Convert to all-in; it still raises the bet if it goes past the call
                 let all_in_amount = player.chips;
                 player.current_bet += all_in_amount;
                 betting_round.pot += all_in_amount;
                 player.chips = 0;
+                if player.current_bet > betting_round.current_bet {
+                    let raise_size = player.current_bet - betting_round.current_bet;
+                    if raise_size >= betting_round.min_full_raise() {
+                        betting_round.min_raise = raise_size;
+                    }
+                    betting_round.current_bet = player.current_bet;
+                }
                 info!("Player {} went all-in with ${}", player.id, all_in_amount);
             }
         }
     }
+    Ok(())
+}
+
+// System to keep GameData's current player pointed at whoever acts next, for the UI and teaching
+pub fn sync_current_player(
+    game_state: Res<State<GameState>>,
+    betting_round: Res<BettingRound>,
+    mut game_data: ResMut<crate::game_state::GameData>,
+) {
+    if game_state.get().street_index().is_none() {
+        return;
+    }
+    
+    if let Some(next_actor) = betting_round.peek_next_player() {
+        // Only write on a change so GameData change detection stays meaningful
+        if game_data.current_player != next_actor {
+            game_data.current_player = next_actor;
+        }
+    }
 }
 
 // System to check if betting round is complete
@@ -259,3 +505,189 @@ pub fn check_betting_round_complete(
         info!("Betting round complete - {} players remain", active_players.len());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::game_state::GameData;
+    
+    #[test]
+    fn test_postflop_opening_bet_needs_big_blind() {
+        // A big preflop raise shouldn't carry over as the post-flop minimum
+        let mut betting_round = BettingRound::new(vec![0, 1], 10);
+        betting_round.min_raise = 60;
+        betting_round.reset_for_new_round(vec![0, 1]);
+        
+        assert_eq!(betting_round.min_raise, 20);
+        assert_eq!(betting_round.min_full_raise(), 20);
+        assert_eq!(
+            betting_round.validate_raise(10),
+            Err(InvalidRaise::BetBelowBigBlind { amount: 10, minimum: 20 })
+        );
+        assert_eq!(betting_round.validate_raise(20), Ok(()));
+        
+        // Once there's a bet, raises are measured against the last raise
+        betting_round.current_bet = 40;
+        betting_round.min_raise = 40;
+        assert!(matches!(betting_round.validate_raise(20), Err(InvalidRaise::RaiseBelowMinimum { .. })));
+    }
+    
+    #[test]
+    fn test_three_all_ins_make_a_main_pot_a_side_pot_and_a_return() {
+        let pots = build_side_pots(&[(0, 50, true), (1, 150, true), (2, 400, true)]);
+        assert_eq!(pots.len(), 3);
+        assert_eq!((pots[0].amount, pots[0].eligible.clone()), (150, vec![0, 1, 2]));
+        assert_eq!((pots[1].amount, pots[1].eligible.clone()), (200, vec![1, 2]));
+        assert_eq!((pots[2].amount, pots[2].eligible.clone()), (250, vec![2]));
+        assert!(!pots[0].is_uncalled() && !pots[1].is_uncalled());
+        assert!(pots[2].is_uncalled());
+        
+        // A folded player's chips play in every layer they reached, but they can't win any
+        let pots = build_side_pots(&[(0, 50, true), (1, 100, false), (2, 150, true)]);
+        assert_eq!((pots[0].amount, pots[0].eligible.clone()), (150, vec![0, 2]));
+        assert_eq!((pots[1].amount, pots[1].eligible.clone()), (150, vec![2]));
+        assert!(!pots[1].is_uncalled(), "player 1's fold left chips in that layer");
+    }
+    
+    #[test]
+    fn test_current_player_follows_next_actor() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Flop)
+            .init_resource::<GameData>()
+            .insert_resource(BettingRound { players_to_act: vec![2, 0], ..default() })
+            .add_systems(Update, sync_current_player);
+        
+        app.update();
+        assert_eq!(app.world().resource::<GameData>().current_player, 2);
+        
+        app.world_mut().resource_mut::<BettingRound>().next_player();
+        app.update();
+        assert_eq!(app.world().resource::<GameData>().current_player, 0);
+    }
+    
+    #[test]
+    fn test_human_bet_below_big_blind_keeps_the_turn() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Flop)
+            .init_resource::<GameData>()
+            .init_resource::<HumanPlayerInput>()
+            .add_event::<GameEvent>()
+            .insert_resource(BettingRound::new(vec![0, 1], 10))
+            .add_systems(Update, ai_player_system);
+        app.world_mut().resource_mut::<BettingRound>().reset_for_new_round(vec![0, 1]);
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        
+        app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = Some(PlayerAction::Raise(10));
+        app.update();
+        let betting_round = app.world().resource::<BettingRound>();
+        assert_eq!(betting_round.peek_next_player(), Some(0));
+        assert_eq!(betting_round.current_bet, 0);
+        
+        app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = Some(PlayerAction::Raise(20));
+        app.update();
+        let betting_round = app.world().resource::<BettingRound>();
+        assert_eq!(betting_round.current_bet, 20);
+        assert_eq!(betting_round.action_history[0], (0, PlayerAction::Raise(20)));
+    }
+    
+    #[test]
+    fn test_short_all_in_raise_does_not_reopen_betting() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Flop)
+            .init_resource::<GameData>()
+            .init_resource::<HumanPlayerInput>()
+            .add_event::<GameEvent>()
+            .insert_resource(BettingRound::new(vec![0, 1, 2], 10))
+            .add_systems(Update, ai_player_system);
+        app.world_mut().resource_mut::<BettingRound>().reset_for_new_round(vec![0, 1, 2]);
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(2, PlayerType::Human, 150, Vec3::ZERO));
+        
+        // Bet 100 and a call, then a $150 all-in: a raise of only 50 against a 100 minimum
+        for action in [PlayerAction::Raise(100), PlayerAction::Call, PlayerAction::Raise(50)] {
+            app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = Some(action);
+            app.update();
+        }
+        let betting_round = app.world().resource::<BettingRound>();
+        assert_eq!(betting_round.current_bet, 150);
+        assert_eq!(betting_round.min_raise, 100);
+        assert_eq!(betting_round.players_to_act, vec![0, 1]);
+        assert!(!betting_round.can_raise(0));
+        
+        // The original bettor must call the extra 50 but can't re-raise
+        app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = Some(PlayerAction::Raise(200));
+        app.update();
+        let betting_round = app.world().resource::<BettingRound>();
+        assert_eq!(betting_round.peek_next_player(), Some(0));
+        assert_eq!(betting_round.current_bet, 150);
+        
+        app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = Some(PlayerAction::Call);
+        app.update();
+        let betting_round = app.world().resource::<BettingRound>();
+        assert_eq!(betting_round.players_to_act, vec![1]);
+        assert_eq!(betting_round.current_bet, 150);
+    }
+    
+    #[test]
+    fn test_process_player_action_enforces_the_minimum_raise() {
+        let mut betting_round = BettingRound { current_bet: 40, min_raise: 20, pot: 60, ..default() };
+        
+        // Raising by 1 chip is refused and changes nothing
+        let mut player = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        assert_eq!(
+            process_player_action(&mut player, PlayerAction::Raise(1), &mut betting_round),
+            Err(InvalidRaise::RaiseBelowMinimum { amount: 1, minimum: 20 })
+        );
+        assert_eq!((player.chips, player.current_bet), (1000, 0));
+        assert_eq!((betting_round.current_bet, betting_round.pot), (40, 60));
+        assert!(betting_round.action_history.is_empty());
+        
+        // Exactly the minimum goes through
+        assert!(process_player_action(&mut player, PlayerAction::Raise(20), &mut betting_round).is_ok());
+        assert_eq!((player.chips, player.current_bet), (940, 60));
+        assert_eq!(betting_round.current_bet, 60);
+        
+        // All-in for less than a full raise is still legal
+        let mut short_stack = Player::new(2, PlayerType::AI, 70, Vec3::ZERO);
+        assert!(process_player_action(&mut short_stack, PlayerAction::Raise(10), &mut betting_round).is_ok());
+        assert_eq!((short_stack.chips, short_stack.current_bet), (0, 70));
+        assert_eq!((betting_round.current_bet, betting_round.min_raise), (70, 20));
+    }
+    
+    #[test]
+    fn test_folding_removes_queued_player() {
+        let mut betting_round = BettingRound { players_to_act: vec![1, 2, 0], ..default() };
+        let mut player = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        
+        assert!(process_player_action(&mut player, PlayerAction::Fold, &mut betting_round).is_ok());
+        
+        assert!(player.has_folded);
+        assert_eq!(betting_round.players_to_act, vec![2, 0]);
+        assert_eq!(betting_round.peek_next_player(), Some(2));
+    }
+    
+    #[test]
+    fn test_folded_player_at_front_of_queue_is_skipped() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Flop)
+            .init_resource::<GameData>()
+            .init_resource::<HumanPlayerInput>()
+            .add_event::<GameEvent>()
+            .insert_resource(BettingRound { players_to_act: vec![1, 0], ..default() })
+            .add_systems(Update, ai_player_system);
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        let mut folded = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        folded.has_folded = true;
+        app.world_mut().spawn(folded);
+        
+        app.update();
+        assert_eq!(app.world().resource::<BettingRound>().peek_next_player(), Some(0));
+    }
+}
@@ -2,7 +2,10 @@ use bevy::prelude::*;
 use crate::player::{Player, PlayerType};
 use crate::betting::{PlayerAction, BettingRound};
 use crate::game_state::GameState;
+use crate::game_config::GameConfig;
+use crate::theme::Palette;
 use crate::audio::AudioEvent;
+use bevy::ui::RelativeCursorPosition;
 
 // Betting UI Components
 #[derive(Component)]
@@ -13,6 +16,14 @@ pub struct BettingButton {
     pub action: BettingButtonAction,
 }
 
+// FOLD while checking is free: greyed out, since folding then gives up the hand for nothing
+#[derive(Component)]
+pub struct Deprioritized;
+
+// The action the learner should reach for first (CHECK while it's free)
+#[derive(Component)]
+pub struct PrimaryAction;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum BettingButtonAction {
     Fold,
@@ -24,13 +35,70 @@ pub enum BettingButtonAction {
 #[derive(Component)]
 pub struct RaiseSlider;
 
+#[derive(Component)]
+pub struct RaiseSliderFill;
+
 #[derive(Component)]
 pub struct RaiseAmountDisplay;
 
+// Panel of pre-action toggles, shown while the human waits for their turn
+#[derive(Component)]
+pub struct PreActionUI;
+
+#[derive(Component)]
+pub struct PreActionButton {
+    pub kind: PreActionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreActionKind {
+    CheckFold,
+    CallAny,
+    Call,
+}
+
+// An action chosen ahead of the human's turn, applied as soon as the turn arrives
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreAction {
+    CheckFold, // Check if free, otherwise fold
+    CallAny,   // Call whatever the bet is
+    Call(u32), // Call only if the price is still this amount
+}
+
+impl PreAction {
+    pub fn arm(kind: PreActionKind, call_amount: u32) -> Self {
+        match kind {
+            PreActionKind::CheckFold => PreAction::CheckFold,
+            PreActionKind::CallAny => PreAction::CallAny,
+            PreActionKind::Call => PreAction::Call(call_amount),
+        }
+    }
+    
+    pub fn kind(&self) -> PreActionKind {
+        match self {
+            PreAction::CheckFold => PreActionKind::CheckFold,
+            PreAction::CallAny => PreActionKind::CallAny,
+            PreAction::Call(_) => PreActionKind::Call,
+        }
+    }
+    
+    /// The action to take now, or None when the situation changed and the human should decide
+    pub fn resolve(&self, call_amount: u32) -> Option<PlayerAction> {
+        match self {
+            _ if call_amount == 0 => Some(PlayerAction::Check),
+            PreAction::CheckFold => Some(PlayerAction::Fold),
+            PreAction::CallAny => Some(PlayerAction::Call),
+            PreAction::Call(armed_amount) if *armed_amount == call_amount => Some(PlayerAction::Call),
+            PreAction::Call(_) => None, // Someone raised since it was armed
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct HumanPlayerInput {
     pub pending_action: Option<PlayerAction>,
     pub raise_amount: u32,
+    pub pre_action: Option<PreAction>,
 }
 
 impl Default for HumanPlayerInput {
@@ -38,19 +106,12 @@ impl Default for HumanPlayerInput {
         Self {
             pending_action: None,
             raise_amount: 20, // Default raise amount
+            pre_action: None,
         }
     }
 }
 
-// Button colors
-const BUTTON_NORMAL: Color = Color::srgb(0.3, 0.3, 0.3);
-const BUTTON_HOVER: Color = Color::srgb(0.4, 0.4, 0.4);
-const BUTTON_PRESSED: Color = Color::srgb(0.2, 0.2, 0.2);
-const FOLD_BUTTON_COLOR: Color = Color::srgb(0.7, 0.2, 0.2);
-const CALL_BUTTON_COLOR: Color = Color::srgb(0.2, 0.6, 0.2);
-const RAISE_BUTTON_COLOR: Color = Color::srgb(0.2, 0.4, 0.7);
-
-pub fn setup_betting_ui(mut commands: Commands) {
+pub fn setup_betting_ui(mut commands: Commands, palette: Res<Palette>) {
     // Betting panel for human player (bottom center, moved higher to avoid cards)
     commands
         .spawn(NodeBundle {
@@ -66,7 +127,7 @@ pub fn setup_betting_ui(mut commands: Commands) {
                 flex_direction: FlexDirection::Row,
                 ..default()
             },
-            background_color: Color::srgba(0.1, 0.1, 0.1, 0.9).into(),
+            background_color: palette.panel_background.into(),
             visibility: Visibility::Hidden, // Hidden by default
             ..default()
         })
@@ -76,7 +137,7 @@ pub fn setup_betting_ui(mut commands: Commands) {
                 parent, 
                 "FOLD", 
                 BettingButtonAction::Fold, 
-                FOLD_BUTTON_COLOR
+                &palette
             );
             
             // Check/Call Button (text will be updated dynamically)
@@ -84,7 +145,7 @@ pub fn setup_betting_ui(mut commands: Commands) {
                 parent, 
                 "CHECK", 
                 BettingButtonAction::Check, 
-                CALL_BUTTON_COLOR
+                &palette
             );
             
             // Raise Section
@@ -113,7 +174,7 @@ pub fn setup_betting_ui(mut commands: Commands) {
                         ))
                         .insert(RaiseAmountDisplay);
                     
-                    // Raise Slider (simplified as buttons for now)
+                    // Raise stepper (fine adjustment)
                     raise_parent
                         .spawn(NodeBundle {
                             style: Style {
@@ -125,10 +186,37 @@ pub fn setup_betting_ui(mut commands: Commands) {
                         })
                         .with_children(|slider_parent| {
                             // Decrease button
-                            create_small_button(slider_parent, "-", "decrease");
+                            create_small_button(slider_parent, "-", "decrease", &palette);
                             
                             // Increase button  
-                            create_small_button(slider_parent, "+", "increase");
+                            create_small_button(slider_parent, "+", "increase", &palette);
+                        });
+                    
+                    // Draggable slider between the minimum raise and the player's stack
+                    raise_parent
+                        .spawn(ButtonBundle {
+                            style: Style {
+                                width: Val::Px(130.0),
+                                height: Val::Px(10.0),
+                                margin: UiRect::bottom(Val::Px(4.0)),
+                                ..default()
+                            },
+                            background_color: palette.button_normal.into(),
+                            ..default()
+                        })
+                        .insert((RaiseSlider, RelativeCursorPosition::default()))
+                        .with_children(|track| {
+                            track
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        width: Val::Percent(0.0),
+                                        height: Val::Percent(100.0),
+                                        ..default()
+                                    },
+                                    background_color: palette.raise_button.into(),
+                                    ..default()
+                                })
+                                .insert(RaiseSliderFill);
                         });
                     
                     // Raise Button
@@ -136,18 +224,68 @@ pub fn setup_betting_ui(mut commands: Commands) {
                         raise_parent, 
                         "RAISE", 
                         BettingButtonAction::Raise, 
-                        RAISE_BUTTON_COLOR
+                        &palette
                     );
                 });
         })
         .insert(BettingUI);
+    
+    // Pre-action toggles (same spot as the betting panel, shown only while waiting)
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(30.0),
+                bottom: Val::Px(200.0),
+                width: Val::Percent(40.0),
+                justify_content: JustifyContent::SpaceEvenly,
+                flex_direction: FlexDirection::Row,
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            ..default()
+        })
+        .with_children(|parent| {
+            for (label, kind) in [
+                ("Check/Fold", PreActionKind::CheckFold),
+                ("Call Any", PreActionKind::CallAny),
+                ("Call", PreActionKind::Call),
+            ] {
+                parent
+                    .spawn(ButtonBundle {
+                        style: Style {
+                            width: Val::Px(90.0),
+                            height: Val::Px(28.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        background_color: palette.button_normal.into(),
+                        border_color: palette.button_border.into(),
+                        ..default()
+                    })
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_section(
+                            label,
+                            TextStyle {
+                                font_size: 12.0,
+                                color: palette.text,
+                                ..default()
+                            },
+                        ));
+                    })
+                    .insert(PreActionButton { kind });
+            }
+        })
+        .insert(PreActionUI);
 }
 
 fn create_betting_button(
     parent: &mut ChildBuilder,
     text: &str,
     action: BettingButtonAction,
-    color: Color,
+    palette: &Palette,
 ) {
     parent
         .spawn(ButtonBundle {
@@ -157,9 +295,11 @@ fn create_betting_button(
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 margin: UiRect::all(Val::Px(5.0)),
+                border: UiRect::all(Val::Px(2.0)),
                 ..default()
             },
-            background_color: color.into(),
+            background_color: palette.button_color(&action).into(),
+            border_color: palette.button_border.into(),
             ..default()
         })
         .with_children(|button| {
@@ -167,7 +307,7 @@ fn create_betting_button(
                 text,
                 TextStyle {
                     font_size: 14.0,
-                    color: Color::WHITE,
+                    color: palette.text,
                     ..default()
                 },
             ));
@@ -175,7 +315,7 @@ fn create_betting_button(
         .insert(BettingButton { action });
 }
 
-fn create_small_button(parent: &mut ChildBuilder, text: &str, id: &str) {
+fn create_small_button(parent: &mut ChildBuilder, text: &str, id: &str, palette: &Palette) {
     parent
         .spawn(ButtonBundle {
             style: Style {
@@ -184,9 +324,11 @@ fn create_small_button(parent: &mut ChildBuilder, text: &str, id: &str) {
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 margin: UiRect::all(Val::Px(2.0)),
+                border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
-            background_color: BUTTON_NORMAL.into(),
+            background_color: palette.button_normal.into(),
+            border_color: palette.button_border.into(),
             ..default()
         })
         .with_children(|button| {
@@ -194,7 +336,7 @@ fn create_small_button(parent: &mut ChildBuilder, text: &str, id: &str) {
                 text,
                 TextStyle {
                     font_size: 12.0,
-                    color: Color::WHITE,
+                    color: palette.text,
                     ..default()
                 },
             ));
@@ -238,13 +380,14 @@ pub fn manage_betting_ui_visibility(
 // System to handle betting button interactions
 pub fn handle_betting_buttons(
     mut interaction_query: Query<
-        (&Interaction, &BettingButton, &mut BackgroundColor),
+        (&Interaction, &BettingButton, &mut BackgroundColor, Has<Deprioritized>),
         (Changed<Interaction>, With<Button>),
     >,
     mut human_input: ResMut<HumanPlayerInput>,
     betting_round: Res<BettingRound>,
     players: Query<&Player>,
     mut audio_events: EventWriter<AudioEvent>,
+    palette: Res<Palette>,
 ) {
     // Find current human player using betting round
     let current_human_player = if let Some(current_id) = betting_round.peek_next_player() {
@@ -259,10 +402,10 @@ pub fn handle_betting_buttons(
         return;
     };
     
-    for (interaction, betting_button, mut color) in &mut interaction_query {
+    for (interaction, betting_button, mut color, deprioritized) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
-                *color = BUTTON_PRESSED.into();
+                *color = palette.button_pressed.into();
                 
                 // Trigger button click sound
                 audio_events.send(AudioEvent::ButtonClick);
@@ -300,16 +443,15 @@ pub fn handle_betting_buttons(
                 }
             }
             Interaction::Hovered => {
-                *color = BUTTON_HOVER.into();
+                *color = palette.button_hover.into();
             }
             Interaction::None => {
                 // Reset to appropriate color based on button type
-                let base_color = match betting_button.action {
-                    BettingButtonAction::Fold => FOLD_BUTTON_COLOR,
-                    BettingButtonAction::Check | BettingButtonAction::Call => CALL_BUTTON_COLOR,
-                    BettingButtonAction::Raise => RAISE_BUTTON_COLOR,
+                *color = if deprioritized {
+                    palette.button_normal.into()
+                } else {
+                    palette.button_color(&betting_button.action).into()
                 };
-                *color = base_color.into();
             }
         }
     }
@@ -321,15 +463,24 @@ pub fn handle_raise_adjustment(
     mut human_input: ResMut<HumanPlayerInput>,
     mut amount_display_query: Query<&mut Text, With<RaiseAmountDisplay>>,
     betting_round: Res<BettingRound>,
+    game_config: Res<GameConfig>,
+    players: Query<&Player>,
 ) {
+    let step = game_config.raise_increment(betting_round.min_raise);
+    
+    // Like the slider, the stepper stops at everything left after calling
+    let max_raise = players
+        .iter()
+        .find(|p| matches!(p.player_type, PlayerType::Human))
+        .map_or(u32::MAX, |human| human.chips.saturating_sub(betting_round.current_bet.saturating_sub(human.current_bet)));
     for (interaction, name) in &mut interaction_query {
         if matches!(*interaction, Interaction::Pressed) {
             match name.as_str() {
                 "increase" => {
-                    human_input.raise_amount = (human_input.raise_amount + betting_round.min_raise).min(1000);
+                    human_input.raise_amount = (human_input.raise_amount + step).min(max_raise);
                 }
                 "decrease" => {
-                    human_input.raise_amount = human_input.raise_amount.saturating_sub(betting_round.min_raise).max(betting_round.min_raise);
+                    human_input.raise_amount = human_input.raise_amount.saturating_sub(step).max(betting_round.min_raise);
                 }
                 _ => continue,
             }
@@ -342,13 +493,70 @@ pub fn handle_raise_adjustment(
     }
 }
 
+// Map a slider position (0.0 - 1.0) to a raise amount in the legal range
+pub fn raise_amount_from_slider(fraction: f32, min_raise: u32, max_raise: u32) -> u32 {
+    if max_raise <= min_raise {
+        return max_raise;
+    }
+    let fraction = fraction.clamp(0.0, 1.0);
+    min_raise + ((max_raise - min_raise) as f32 * fraction).round() as u32
+}
+
+// System to handle dragging the raise slider
+pub fn handle_raise_slider(
+    slider_query: Query<(&Interaction, &RelativeCursorPosition), With<RaiseSlider>>,
+    mut fill_query: Query<&mut Style, With<RaiseSliderFill>>,
+    mut human_input: ResMut<HumanPlayerInput>,
+    mut amount_display_query: Query<&mut Text, With<RaiseAmountDisplay>>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+) {
+    let Ok((interaction, cursor)) = slider_query.get_single() else {
+        return;
+    };
+    
+    // Update continuously while the slider is held so the display follows the drag
+    if !matches!(*interaction, Interaction::Pressed) {
+        return;
+    }
+    let Some(position) = cursor.normalized else {
+        return;
+    };
+    
+    let Some(human_player) = betting_round
+        .peek_next_player()
+        .and_then(|id| players.iter().find(|p| p.id == id && matches!(p.player_type, PlayerType::Human)))
+    else {
+        return;
+    };
+    
+    // The largest raise is everything left after calling
+    let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+    let max_raise = human_player.chips.saturating_sub(call_amount);
+    let fraction = position.x.clamp(0.0, 1.0);
+    
+    human_input.raise_amount = raise_amount_from_slider(fraction, betting_round.min_raise, max_raise);
+    
+    if let Ok(mut fill_style) = fill_query.get_single_mut() {
+        fill_style.width = Val::Percent(fraction * 100.0);
+    }
+    if let Ok(mut text) = amount_display_query.get_single_mut() {
+        text.sections[0].value = format!("Raise: ${}", human_input.raise_amount);
+    }
+}
+
+// A betting button with its label, its fill and whether it's currently greyed out
+type BettingButtonParts<'a> = (Entity, &'a BettingButton, &'a Children, Option<&'a mut BackgroundColor>, Has<Deprioritized>);
+
 // System to update betting button text based on current situation
 pub fn update_betting_button_text(
-    mut button_query: Query<(&BettingButton, &Children)>,
+    mut commands: Commands,
+    mut button_query: Query<BettingButtonParts>,
     mut text_query: Query<&mut Text>,
     betting_round: Res<BettingRound>,
     players: Query<&Player>,
     game_data: Res<crate::game_state::GameData>,
+    palette: Res<Palette>,
 ) {
     // Find current human player
     let current_human_player = players
@@ -360,14 +568,39 @@ pub fn update_betting_button_text(
     };
     
     let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+    let check_is_free = call_amount == 0;
     
-    for (betting_button, children) in &mut button_query {
+    for (entity, betting_button, children, background, deprioritized) in &mut button_query {
+        match betting_button.action {
+            // Grey out FOLD while checking is free, restoring it once there's a bet to face
+            BettingButtonAction::Fold if check_is_free != deprioritized => {
+                if check_is_free {
+                    commands.entity(entity).insert(Deprioritized);
+                } else {
+                    commands.entity(entity).remove::<Deprioritized>();
+                }
+                if let Some(mut background) = background {
+                    *background = if check_is_free { palette.button_normal } else { palette.fold_button }.into();
+                }
+            },
+            BettingButtonAction::Check if check_is_free => {
+                commands.entity(entity).insert(PrimaryAction);
+            },
+            BettingButtonAction::Check => {
+                commands.entity(entity).remove::<PrimaryAction>();
+            },
+            _ => {},
+        }
+        
         if let BettingButtonAction::Check = betting_button.action {
             // Update Check/Call button text
             for &child in children.iter() {
                 if let Ok(mut text) = text_query.get_mut(child) {
                     if call_amount == 0 {
                         text.sections[0].value = "CHECK".to_string();
+                    } else if call_amount >= human_player.chips {
+                        // Calling would put the whole stack in, so say so
+                        text.sections[0].value = format!("ALL-IN ${}", human_player.chips);
                     } else {
                         text.sections[0].value = format!("CALL ${}", call_amount);
                     }
@@ -377,15 +610,378 @@ pub fn update_betting_button_text(
     }
 }
 
+// System to show the pre-action toggles while the human is in the hand but not yet to act
+pub fn manage_pre_action_ui(
+    mut pre_action_ui_query: Query<&mut Visibility, With<PreActionUI>>,
+    players: Query<&Player>,
+    game_state: Res<State<GameState>>,
+    betting_round: Res<BettingRound>,
+) {
+    let Ok(mut visibility) = pre_action_ui_query.get_single_mut() else {
+        return;
+    };
+    
+    let waiting = game_state.get().street_index().is_some()
+        && !betting_round.betting_complete
+        && players.iter().any(|p| {
+            matches!(p.player_type, PlayerType::Human)
+                && !p.has_folded
+                && betting_round.players_to_act.contains(&p.id)
+                && betting_round.peek_next_player() != Some(p.id)
+        });
+    *visibility = if waiting { Visibility::Visible } else { Visibility::Hidden };
+}
+
+// System to arm or disarm a pre-action and highlight the armed toggle
+pub fn handle_pre_action_buttons(
+    interaction_query: Query<(&Interaction, &PreActionButton), Changed<Interaction>>,
+    mut button_colors: Query<(&PreActionButton, &mut BackgroundColor)>,
+    mut human_input: ResMut<HumanPlayerInput>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+    palette: Res<Palette>,
+) {
+    let Some(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)) else {
+        return;
+    };
+    
+    for (interaction, button) in interaction_query.iter() {
+        if matches!(*interaction, Interaction::Pressed) {
+            let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+            human_input.pre_action = match human_input.pre_action {
+                Some(armed) if armed.kind() == button.kind => None,
+                _ => Some(PreAction::arm(button.kind, call_amount)),
+            };
+            info!("Pre-action: {:?}", human_input.pre_action);
+        }
+    }
+    
+    if human_input.is_changed() || palette.is_changed() {
+        let armed = human_input.pre_action.map(|pre_action| pre_action.kind());
+        for (button, mut color) in button_colors.iter_mut() {
+            *color = if armed == Some(button.kind) { palette.raise_button } else { palette.button_normal }.into();
+        }
+    }
+}
+
+// System to play an armed pre-action as soon as it's the human's turn
+pub fn apply_pre_action(
+    mut human_input: ResMut<HumanPlayerInput>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+) {
+    if human_input.pre_action.is_none() || human_input.pending_action.is_some() {
+        return;
+    }
+    
+    let Some(human_player) = betting_round
+        .peek_next_player()
+        .and_then(|id| players.iter().find(|p| p.id == id && matches!(p.player_type, PlayerType::Human)))
+    else {
+        return;
+    };
+    
+    // A pre-action is used once, whether or not it still fits the situation
+    let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+    if let Some(action) = human_input.pre_action.take().and_then(|pre_action| pre_action.resolve(call_amount)) {
+        info!("Playing pre-selected action: {:?}", action);
+        human_input.pending_action = Some(action);
+    }
+}
+
 // System to reset raise amount when a new hand starts
 pub fn reset_raise_amount_on_new_hand(
     mut human_input: ResMut<HumanPlayerInput>,
     current_state: Res<State<GameState>>,
+    players: Query<&Player>,
 ) {
+    // Nothing to reset when no human is seated
+    if !players.iter().any(|p| matches!(p.player_type, PlayerType::Human)) {
+        return;
+    }
+    
     // Reset raise amount when dealing starts (new hand)
     if current_state.is_changed() && *current_state == GameState::Dealing {
         human_input.raise_amount = 20; // Reset to default
         human_input.pending_action = None; // Clear any pending action
+        human_input.pre_action = None;
         info!("🔄 Reset raise amount to default ($20) for new hand");
     }
 }
+
+// Default raise: about two thirds of the pot, kept within the legal range
+pub fn pot_relative_raise(pot: u32, min_raise: u32, max_raise: u32) -> u32 {
+    (pot * 2 / 3).max(min_raise).min(max_raise)
+}
+
+// System to size the default raise from the pot each time the human's turn starts
+pub fn size_default_raise(
+    mut human_input: ResMut<HumanPlayerInput>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+    mut human_was_to_act: Local<bool>,
+) {
+    let human_player = betting_round
+        .peek_next_player()
+        .and_then(|id| players.iter().find(|p| p.id == id && matches!(p.player_type, PlayerType::Human)));
+    let turn_started = human_player.is_some() && !*human_was_to_act;
+    *human_was_to_act = human_player.is_some();
+    
+    // Only on the first frame of the turn, so the human's own adjustments stick
+    let Some(human_player) = human_player.filter(|_| turn_started) else {
+        return;
+    };
+    
+    let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+    let max_raise = human_player.chips.saturating_sub(call_amount);
+    human_input.raise_amount = pot_relative_raise(betting_round.pot, betting_round.min_full_raise(), max_raise);
+}
+
+// System to keep the raise display in step with the raise amount; besides any change to the
+// input, it refreshes whenever the human's turn starts or the pot moves
+pub fn update_raise_amount_display(
+    human_input: Res<HumanPlayerInput>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+    mut amount_display_query: Query<&mut Text, With<RaiseAmountDisplay>>,
+    mut last_seen: Local<Option<(bool, u32)>>,
+) {
+    let human_to_act = betting_round
+        .peek_next_player()
+        .is_some_and(|id| players.iter().any(|p| p.id == id && matches!(p.player_type, PlayerType::Human)));
+    let seen = (human_to_act, betting_round.pot);
+    if !human_input.is_changed() && *last_seen == Some(seen) {
+        return;
+    }
+    *last_seen = Some(seen);
+    
+    if let Ok(mut text) = amount_display_query.get_single_mut() {
+        text.sections[0].value = format!("Raise: ${}", human_input.raise_amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_dragging_slider_to_max_raises_full_stack() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HumanPlayerInput>()
+            .insert_resource(BettingRound {
+                current_bet: 0,
+                players_to_act: vec![0],
+                ..default()
+            })
+            .add_systems(Update, handle_raise_slider);
+        
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 750, Vec3::ZERO));
+        app.world_mut().spawn((
+            RaiseSlider,
+            Interaction::Pressed,
+            RelativeCursorPosition {
+                normalized: Some(Vec2::new(1.0, 0.5)),
+                ..default()
+            },
+        ));
+        let display = app.world_mut().spawn((Text::from_section("Raise: $20", TextStyle::default()), RaiseAmountDisplay)).id();
+        
+        app.update();
+        
+        assert_eq!(app.world().resource::<HumanPlayerInput>().raise_amount, 750);
+        assert_eq!(app.world().get::<Text>(display).unwrap().sections[0].value, "Raise: $750");
+    }
+    
+    #[test]
+    fn test_call_bigger_than_stack_is_labelled_all_in() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<crate::game_state::GameData>()
+            .init_resource::<Palette>()
+            .insert_resource(BettingRound {
+                current_bet: 500,
+                players_to_act: vec![0],
+                ..default()
+            })
+            .add_systems(Update, update_betting_button_text);
+        
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 180, Vec3::ZERO));
+        let label = app.world_mut().spawn(Text::from_section("CHECK", TextStyle::default())).id();
+        app.world_mut()
+            .spawn(BettingButton { action: BettingButtonAction::Check })
+            .add_child(label);
+        
+        app.update();
+        assert_eq!(app.world().get::<Text>(label).unwrap().sections[0].value, "ALL-IN $180");
+        
+        // A call the stack covers keeps the normal label
+        app.world_mut().resource_mut::<BettingRound>().current_bet = 100;
+        app.update();
+        assert_eq!(app.world().get::<Text>(label).unwrap().sections[0].value, "CALL $100");
+    }
+    
+    #[test]
+    fn test_free_check_greys_out_fold_and_makes_check_primary() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<crate::game_state::GameData>()
+            .init_resource::<Palette>()
+            .insert_resource(BettingRound {
+                current_bet: 0,
+                players_to_act: vec![0],
+                ..default()
+            })
+            .add_systems(Update, update_betting_button_text);
+        
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        let fold_label = app.world_mut().spawn(Text::from_section("FOLD", TextStyle::default())).id();
+        let fold = app.world_mut()
+            .spawn((BettingButton { action: BettingButtonAction::Fold }, BackgroundColor(Palette::standard().fold_button)))
+            .add_child(fold_label)
+            .id();
+        let check_label = app.world_mut().spawn(Text::from_section("CHECK", TextStyle::default())).id();
+        let check = app.world_mut()
+            .spawn(BettingButton { action: BettingButtonAction::Check })
+            .add_child(check_label)
+            .id();
+        
+        app.update();
+        assert!(app.world().get::<Deprioritized>(fold).is_some());
+        assert_eq!(app.world().get::<BackgroundColor>(fold).unwrap().0, Palette::standard().button_normal);
+        assert!(app.world().get::<PrimaryAction>(check).is_some());
+        assert_eq!(app.world().get::<Text>(check_label).unwrap().sections[0].value, "CHECK");
+        
+        // Facing a bet, folding is a real option again
+        app.world_mut().resource_mut::<BettingRound>().current_bet = 50;
+        app.update();
+        assert!(app.world().get::<Deprioritized>(fold).is_none());
+        assert_eq!(app.world().get::<BackgroundColor>(fold).unwrap().0, Palette::standard().fold_button);
+        assert!(app.world().get::<PrimaryAction>(check).is_none());
+    }
+    
+    #[test]
+    fn test_raise_step_sets_increase_button_increment() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HumanPlayerInput>()
+            .init_resource::<BettingRound>()
+            .insert_resource(GameConfig { raise_step: 25, ..default() })
+            .add_systems(Update, handle_raise_adjustment);
+        app.world_mut().spawn((Button, Interaction::Pressed, Name::new("increase")));
+        
+        app.update();
+        
+        assert_eq!(app.world().resource::<HumanPlayerInput>().raise_amount, 45);
+    }
+    
+    #[test]
+    fn test_increase_button_stops_at_the_human_stack() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(HumanPlayerInput { raise_amount: 990, ..default() })
+            .insert_resource(BettingRound { current_bet: 20, ..default() })
+            .insert_resource(GameConfig { raise_step: 25, ..default() })
+            .add_systems(Update, handle_raise_adjustment);
+        let human = app.world_mut().spawn(Player::new(0, PlayerType::Human, 1500, Vec3::ZERO)).id();
+        let button = app.world_mut().spawn((Button, Interaction::Pressed, Name::new("increase"))).id();
+        
+        // A stack above $1000 can raise past it
+        app.update();
+        assert_eq!(app.world().resource::<HumanPlayerInput>().raise_amount, 1015);
+        
+        // A $1000 stack facing a $20 bet tops out at $980
+        app.world_mut().get_mut::<Player>(human).unwrap().chips = 1000;
+        app.world_mut().resource_mut::<HumanPlayerInput>().raise_amount = 970;
+        app.world_mut().entity_mut(button).insert(Interaction::Pressed);
+        app.update();
+        assert_eq!(app.world().resource::<HumanPlayerInput>().raise_amount, 980);
+    }
+    
+    #[test]
+    fn test_default_raise_is_two_thirds_of_the_pot_on_human_turn() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HumanPlayerInput>()
+            .insert_resource(BettingRound { pot: 90, players_to_act: vec![1, 0], ..default() })
+            .add_systems(Update, size_default_raise);
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        
+        // Not the human's turn yet
+        app.update();
+        assert_eq!(app.world().resource::<HumanPlayerInput>().raise_amount, 20);
+        
+        app.world_mut().resource_mut::<BettingRound>().next_player();
+        app.update();
+        assert_eq!(app.world().resource::<HumanPlayerInput>().raise_amount, 60);
+        
+        // An adjustment during the turn isn't overwritten
+        app.world_mut().resource_mut::<HumanPlayerInput>().raise_amount = 100;
+        app.update();
+        assert_eq!(app.world().resource::<HumanPlayerInput>().raise_amount, 100);
+    }
+    
+    #[test]
+    fn test_raise_display_follows_default_size_on_a_new_street() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HumanPlayerInput>()
+            .insert_resource(BettingRound { pot: 90, players_to_act: vec![0], ..default() })
+            .add_systems(Update, (size_default_raise, update_raise_amount_display).chain());
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        let display = app.world_mut().spawn((Text::from_section("Raise: $20", TextStyle::default()), RaiseAmountDisplay)).id();
+        let shown = |app: &App| app.world().get::<Text>(display).unwrap().sections[0].value.clone();
+        
+        app.update();
+        assert_eq!(shown(&app), "Raise: $60");
+        
+        // The human acts, the street ends with a bigger pot, and the next street starts on them
+        app.world_mut().resource_mut::<BettingRound>().next_player();
+        app.update();
+        {
+            let mut betting_round = app.world_mut().resource_mut::<BettingRound>();
+            betting_round.pot = 300;
+            betting_round.reset_for_new_round(vec![0, 1]);
+        }
+        app.update();
+        assert_eq!(shown(&app), "Raise: $200");
+    }
+    
+    #[test]
+    fn test_check_fold_pre_action_folds_facing_a_bet() {
+        use bevy::state::app::StatesPlugin;
+        
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Flop)
+            .init_resource::<crate::game_state::GameData>()
+            .init_resource::<HumanPlayerInput>()
+            .insert_resource(BettingRound { current_bet: 40, players_to_act: vec![1, 0], ..default() })
+            .add_event::<crate::game_state::GameEvent>()
+            .add_systems(Update, (apply_pre_action, crate::betting::ai_player_system).chain());
+        let human = app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO)).id();
+        let mut bettor = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        bettor.current_bet = 40;
+        app.world_mut().spawn(bettor);
+        app.world_mut().resource_mut::<HumanPlayerInput>().pre_action = Some(PreAction::CheckFold);
+        
+        // The AI acts first; the pre-action waits for the human's turn
+        app.update();
+        assert!(!app.world().get::<Player>(human).unwrap().has_folded);
+        assert_eq!(app.world().resource::<BettingRound>().peek_next_player(), Some(0));
+        
+        app.update();
+        assert!(app.world().get::<Player>(human).unwrap().has_folded);
+        assert!(app.world().resource::<HumanPlayerInput>().pre_action.is_none());
+    }
+    
+    #[test]
+    fn test_call_pre_action_is_dropped_after_a_raise() {
+        assert_eq!(PreAction::Call(20).resolve(20), Some(PlayerAction::Call));
+        assert_eq!(PreAction::Call(20).resolve(60), None);
+        assert_eq!(PreAction::CheckFold.resolve(0), Some(PlayerAction::Check));
+        assert_eq!(PreAction::CallAny.resolve(60), Some(PlayerAction::Call));
+    }
+}
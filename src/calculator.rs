@@ -0,0 +1,326 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use crate::cards::{Card, Deck};
+use crate::odds::{count_outs, estimate_equity, outs_hit_probability};
+use crate::poker_rules::{evaluate_hand, hand_rank_name, HandEvaluation};
+use crate::rendering::{rank_symbol, suit_symbol};
+use crate::theme::Palette;
+
+// Run-outs for the calculator's equity figure; it only runs when a card is picked
+const CALCULATOR_EQUITY_ITERATIONS: usize = 2000;
+
+// Resource for the standalone odds calculator: cards picked so far, for study away from a live hand
+#[derive(Resource, Debug, Default)]
+pub struct OddsCalculator {
+    pub visible: bool,
+    pub hole_cards: Vec<Card>,
+    pub board: Vec<Card>,
+}
+
+// What the calculator reports for the picked cards
+#[derive(Debug, Clone)]
+pub struct CalculatorResult {
+    pub evaluation: HandEvaluation,
+    pub outs: usize,
+    pub outs_chance: f32, // Chance of hitting one of the outs by the river
+    pub equity: f32,      // Against a single random hand
+}
+
+impl CalculatorResult {
+    pub fn summary(&self) -> String {
+        format!(
+            "Made hand: {}\n{} outs ≈ {:.0}% by river\nEquity vs a random hand: ~{:.0}%",
+            hand_rank_name(&self.evaluation.rank),
+            self.outs,
+            self.outs_chance * 100.0,
+            self.equity * 100.0,
+        )
+    }
+}
+
+impl OddsCalculator {
+    /// Pick or unpick a card: the first two picks are the hole cards, the next five the board
+    pub fn toggle_card(&mut self, card: Card) {
+        if self.hole_cards.contains(&card) {
+            self.hole_cards.retain(|&picked| picked != card);
+        } else if self.board.contains(&card) {
+            self.board.retain(|&picked| picked != card);
+        } else if self.hole_cards.len() < 2 {
+            self.hole_cards.push(card);
+        } else if self.board.len() < 5 {
+            self.board.push(card);
+        }
+    }
+    
+    pub fn is_picked(&self, card: Card) -> bool {
+        self.hole_cards.contains(&card) || self.board.contains(&card)
+    }
+    
+    pub fn clear(&mut self) {
+        self.hole_cards.clear();
+        self.board.clear();
+    }
+    
+    // Results need both hole cards and a complete street (no board, flop, turn or river)
+    pub fn calculate<R: Rng>(&self, rng: &mut R) -> Option<CalculatorResult> {
+        if self.hole_cards.len() != 2 || matches!(self.board.len(), 1 | 2) {
+            return None;
+        }
+        
+        let outs = count_outs(&self.hole_cards, &self.board).len();
+        Some(CalculatorResult {
+            evaluation: evaluate_hand(&self.hole_cards, &self.board),
+            outs,
+            outs_chance: outs_hit_probability(outs, self.board.len()),
+            equity: estimate_equity(&self.hole_cards, &self.board, 1, CALCULATOR_EQUITY_ITERATIONS, rng),
+        })
+    }
+    
+    // What to pick next, or the results once there's enough to work with
+    pub fn status<R: Rng>(&self, rng: &mut R) -> String {
+        let picked = format!("Hole: {}   Board: {}", card_labels(&self.hole_cards), card_labels(&self.board));
+        let next = match self.calculate(rng) {
+            Some(result) => result.summary(),
+            None if self.hole_cards.len() < 2 => "Pick your two hole cards".to_string(),
+            None => format!("Pick {} more board card(s) to complete the flop", 3 - self.board.len()),
+        };
+        format!("{}\n{}", picked, next)
+    }
+}
+
+// Short labels for a list of cards, e.g. "A♠ K♥"
+pub fn card_labels(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        return "-".to_string();
+    }
+    cards
+        .iter()
+        .map(|card| format!("{}{}", rank_symbol(card.rank), suit_symbol(card.suit)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Marker for the calculator panel
+#[derive(Component)]
+pub struct CalculatorPanel;
+
+// Marker for the calculator's picked cards and results text
+#[derive(Component)]
+pub struct CalculatorDisplay;
+
+// A card in the calculator's pick grid
+#[derive(Component)]
+pub struct CalculatorCardButton {
+    pub card: Card,
+}
+
+// Clears every picked card
+#[derive(Component)]
+pub struct CalculatorClearButton;
+
+// Plugin for the standalone odds calculator
+pub struct CalculatorPlugin;
+
+impl Plugin for CalculatorPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<OddsCalculator>()
+            .add_systems(Startup, setup_calculator)
+            .add_systems(Update, (handle_calculator_input, handle_calculator_buttons, update_calculator_display).chain());
+    }
+}
+
+fn setup_calculator(mut commands: Commands, palette: Res<Palette>) {
+    // Calculator panel (center), hidden until opened
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(20.0),
+                top: Val::Percent(15.0),
+                width: Val::Percent(60.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                display: Display::None,
+                ..default()
+            },
+            background_color: palette.panel_background.into(),
+            z_index: ZIndex::Global(10),
+            ..default()
+        })
+        .with_children(|panel| {
+            panel.spawn(TextBundle::from_section(
+                "🧮 Odds Calculator (K to close)",
+                TextStyle {
+                    font_size: 18.0,
+                    color: palette.text,
+                    ..default()
+                },
+            ));
+            
+            // One row of cards per suit
+            for suit_cards in Deck::default().cards.chunks(13) {
+                panel
+                    .spawn(NodeBundle {
+                        style: Style {
+                            column_gap: Val::Px(3.0),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        for &card in suit_cards {
+                            row.spawn((
+                                ButtonBundle {
+                                    style: Style {
+                                        width: Val::Px(34.0),
+                                        height: Val::Px(26.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    background_color: palette.button_normal.into(),
+                                    ..default()
+                                },
+                                CalculatorCardButton { card },
+                            ))
+                            .with_children(|button| {
+                                button.spawn(TextBundle::from_section(
+                                    card_labels(&[card]),
+                                    TextStyle {
+                                        font_size: 13.0,
+                                        color: palette.text,
+                                        ..default()
+                                    },
+                                ));
+                            });
+                        }
+                    });
+            }
+            
+            panel
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                            ..default()
+                        },
+                        background_color: palette.button_normal.into(),
+                        ..default()
+                    },
+                    CalculatorClearButton,
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        "CLEAR",
+                        TextStyle {
+                            font_size: 14.0,
+                            color: palette.text,
+                            ..default()
+                        },
+                    ));
+                });
+            
+            panel.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 15.0,
+                        color: palette.text,
+                        ..default()
+                    },
+                ),
+                CalculatorDisplay,
+            ));
+        })
+        .insert(CalculatorPanel);
+}
+
+// System to open and close the calculator with K
+fn handle_calculator_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut calculator: ResMut<OddsCalculator>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyK) {
+        calculator.visible = !calculator.visible;
+        info!("🧮 Odds calculator: {}", if calculator.visible { "OPEN" } else { "CLOSED" });
+    }
+}
+
+// System to pick cards and clear the calculator from its buttons
+fn handle_calculator_buttons(
+    card_buttons: Query<(&Interaction, &CalculatorCardButton), Changed<Interaction>>,
+    clear_buttons: Query<&Interaction, (Changed<Interaction>, With<CalculatorClearButton>)>,
+    mut calculator: ResMut<OddsCalculator>,
+) {
+    for (interaction, button) in card_buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            calculator.toggle_card(button.card);
+        }
+    }
+    if clear_buttons.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        calculator.clear();
+    }
+}
+
+// System to show or hide the panel, highlight picked cards and refresh the results
+fn update_calculator_display(
+    calculator: Res<OddsCalculator>,
+    palette: Res<Palette>,
+    mut panel_query: Query<&mut Style, With<CalculatorPanel>>,
+    mut card_buttons: Query<(&CalculatorCardButton, &mut BackgroundColor)>,
+    mut display_query: Query<&mut Text, With<CalculatorDisplay>>,
+) {
+    if !calculator.is_changed() && !palette.is_changed() {
+        return;
+    }
+    
+    if let Ok(mut style) = panel_query.get_single_mut() {
+        style.display = if calculator.visible { Display::Flex } else { Display::None };
+    }
+    if !calculator.visible {
+        return;
+    }
+    
+    for (button, mut background) in card_buttons.iter_mut() {
+        *background = if calculator.is_picked(button.card) { palette.button_pressed } else { palette.button_normal }.into();
+    }
+    if let Ok(mut text) = display_query.get_single_mut() {
+        text.sections[0].value = calculator.status(&mut thread_rng());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use crate::cards::{Rank, Suit};
+    use crate::poker_rules::HandRank;
+    
+    #[test]
+    fn test_pocket_aces_on_dry_board() {
+        let mut calculator = OddsCalculator::default();
+        for card in [
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::King),
+            Card::new(Suit::Clubs, Rank::Seven),
+        ] {
+            calculator.toggle_card(card);
+        }
+        assert_eq!(calculator.board.len(), 2);
+        assert!(calculator.calculate(&mut StdRng::seed_from_u64(5)).is_none(), "a two-card board isn't a street");
+        
+        calculator.toggle_card(Card::new(Suit::Spades, Rank::Two));
+        let result = calculator.calculate(&mut StdRng::seed_from_u64(5)).unwrap();
+        assert_eq!(result.evaluation.rank, HandRank::OnePair);
+        assert!((0.85..=0.97).contains(&result.equity), "AA on K72 rainbow was {}", result.equity);
+        assert!(result.summary().starts_with("Made hand: One Pair"), "{}", result.summary());
+        
+        // Unpicking a hole card frees its slot without touching the board
+        calculator.toggle_card(Card::new(Suit::Hearts, Rank::Ace));
+        assert_eq!((calculator.hole_cards.len(), calculator.board.len()), (1, 3));
+    }
+}
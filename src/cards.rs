@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use rand::prelude::*;
+use rand::rngs::StdRng;
+use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 pub enum Suit {
     Hearts,
     Diamonds,
@@ -9,7 +11,7 @@ pub enum Suit {
     Spades,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
 pub enum Rank {
     Two = 2,
     Three = 3,
@@ -26,7 +28,7 @@ pub enum Rank {
     Ace = 14,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Deserialize)]
 pub struct Card {
     pub suit: Suit,
     pub rank: Rank,
@@ -38,6 +40,21 @@ impl Card {
     }
 }
 
+// A card found in more than one place after dealing
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateCard {
+    pub card: Card,
+}
+
+impl std::fmt::Display for DuplicateCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?} of {:?} was dealt more than once", self.card.rank, self.card.suit)
+    }
+}
+
+// Cards in a complete single deck
+pub const FULL_DECK_SIZE: usize = 52;
+
 #[derive(Resource)]
 pub struct Deck {
     pub cards: Vec<Card>,
@@ -62,11 +79,22 @@ impl Default for Deck {
 }
 
 impl Deck {
+    /// A full deck in the order `seed` always shuffles it to, for replays and tests
+    pub fn from_seed(seed: u64) -> Self {
+        let mut deck = Deck::default();
+        deck.shuffle_seeded(seed);
+        deck
+    }
+    
     pub fn shuffle(&mut self) {
         let mut rng = thread_rng();
         self.cards.shuffle(&mut rng);
     }
     
+    pub fn shuffle_seeded(&mut self, seed: u64) {
+        self.cards.shuffle(&mut StdRng::seed_from_u64(seed));
+    }
+    
     pub fn deal(&mut self) -> Option<Card> {
         self.cards.pop()
     }
@@ -75,4 +103,85 @@ impl Deck {
         *self = Deck::default();
         self.shuffle();
     }
+    
+    /// Check the single-deck invariant: no card is in the deck twice, dealt twice,
+    /// or both dealt and still in the deck
+    pub fn check_invariant(&self, dealt: &[Card]) -> Result<(), DuplicateCard> {
+        let mut seen = std::collections::HashSet::new();
+        for &card in self.cards.iter().chain(dealt) {
+            if !seen.insert(card) {
+                return Err(DuplicateCard { card });
+            }
+        }
+        Ok(())
+    }
+    
+    /// Make sure the deck holds all 52 distinct cards before a hand is dealt, rebuilding and
+    /// reshuffling it if any went missing; returns false if it had to recover
+    pub fn ensure_full(&mut self) -> bool {
+        if self.cards.len() == FULL_DECK_SIZE && self.check_invariant(&[]).is_ok() {
+            return true;
+        }
+        self.reset();
+        false
+    }
+    
+    // Debug builds stop on a broken invariant so dealing bugs surface immediately
+    pub fn assert_invariant(&self, dealt: &[Card]) {
+        if cfg!(debug_assertions) {
+            if let Err(duplicate) = self.check_invariant(dealt) {
+                panic!("Deck invariant broken: {}", duplicate);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_deck_invariant_catches_duplicate_card() {
+        let mut deck = Deck::default();
+        let dealt = vec![deck.deal().unwrap(), deck.deal().unwrap()];
+        assert_eq!(deck.check_invariant(&dealt), Ok(()));
+        deck.assert_invariant(&dealt);
+        
+        // A card dealt and still in the deck
+        deck.cards.push(dealt[0]);
+        assert_eq!(deck.check_invariant(&dealt), Err(DuplicateCard { card: dealt[0] }));
+        
+        // A card in the deck twice
+        let mut deck = Deck::default();
+        let first = deck.cards[0];
+        deck.cards.push(first);
+        assert_eq!(deck.check_invariant(&[]), Err(DuplicateCard { card: first }));
+    }
+    
+    #[test]
+    fn test_same_seed_gives_the_same_deck_order() {
+        let deck = Deck::from_seed(2024);
+        assert_eq!(deck.cards, Deck::from_seed(2024).cards);
+        assert_ne!(deck.cards, Deck::from_seed(2025).cards);
+        assert_eq!(deck.check_invariant(&[]), Ok(()));
+        
+        let mut reshuffled = Deck::default();
+        reshuffled.shuffle_seeded(2024);
+        assert_eq!(reshuffled.cards, deck.cards);
+    }
+    
+    #[test]
+    fn test_short_or_duplicated_deck_is_rebuilt() {
+        let mut deck = Deck::default();
+        assert!(deck.ensure_full());
+        
+        deck.deal();
+        assert!(!deck.ensure_full());
+        assert_eq!(deck.cards.len(), FULL_DECK_SIZE);
+        
+        // Right size, but one card replaced by a copy of another
+        deck.cards[0] = deck.cards[1];
+        assert!(!deck.ensure_full());
+        assert_eq!(deck.check_invariant(&[]), Ok(()));
+    }
 }
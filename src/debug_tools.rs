@@ -0,0 +1,276 @@
+use bevy::prelude::*;
+use bevy::diagnostic::{DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
+use serde::Serialize;
+use crate::betting::BettingRound;
+use crate::calculator::card_labels;
+use crate::cards::Card;
+use crate::game_state::{GameData, GameState};
+use crate::player::Player;
+use crate::poker_rules::EvalStats;
+
+// Resource for developer-only debugging aids
+#[derive(Resource, Default)]
+pub struct DebugTools {
+    pub show_perf: bool, // FPS and entity-count overlay for profiling
+}
+
+// Marker for the perf overlay text
+#[derive(Component)]
+pub struct PerfOverlay;
+
+// JSON snapshot of the table, for bug reports and external tools
+#[derive(Serialize)]
+struct GameSnapshot {
+    state: GameState,
+    pot: u32,
+    current_bet: u32,
+    community_cards: Vec<String>,
+    players: Vec<PlayerSnapshot>,
+    betting_round: BettingRoundSnapshot,
+}
+
+#[derive(Serialize)]
+struct PlayerSnapshot {
+    id: u32,
+    player_type: String,
+    chips: u32,
+    current_bet: u32,
+    committed: u32,
+    hole_cards: Vec<String>,
+    has_folded: bool,
+}
+
+#[derive(Serialize)]
+struct BettingRoundSnapshot {
+    current_bet: u32,
+    min_raise: u32,
+    pot: u32,
+    players_to_act: Vec<u32>,
+    betting_complete: bool,
+}
+
+// One label per card, e.g. ["A♠", "K♥"]
+fn card_list(cards: &[Card]) -> Vec<String> {
+    cards.iter().map(|card| card_labels(&[*card])).collect()
+}
+
+/// Pretty-printed JSON of the current state, pot, board, betting round and every player
+pub fn serialize_game_state(world: &mut World) -> String {
+    let mut players: Vec<PlayerSnapshot> = world
+        .query::<&Player>()
+        .iter(world)
+        .map(|player| PlayerSnapshot {
+            id: player.id,
+            player_type: format!("{:?}", player.player_type),
+            chips: player.chips,
+            current_bet: player.current_bet,
+            committed: player.committed,
+            hole_cards: card_list(&player.hole_cards),
+            has_folded: player.has_folded,
+        })
+        .collect();
+    players.sort_by_key(|player| player.id);
+    
+    let game_data = world.resource::<GameData>();
+    let betting_round = world.resource::<BettingRound>();
+    let snapshot = GameSnapshot {
+        state: *world.resource::<State<GameState>>().get(),
+        pot: game_data.pot,
+        current_bet: game_data.current_bet,
+        community_cards: card_list(&game_data.community_cards),
+        players,
+        betting_round: BettingRoundSnapshot {
+            current_bet: betting_round.current_bet,
+            min_raise: betting_round.min_raise,
+            pot: betting_round.pot,
+            players_to_act: betting_round.players_to_act.clone(),
+            betting_complete: betting_round.betting_complete,
+        },
+    };
+    serde_json::to_string_pretty(&snapshot).expect("the snapshot holds only plain values")
+}
+
+// Plugin for debug tooling
+pub struct DebugToolsPlugin;
+
+impl Plugin for DebugToolsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+        
+        app
+            .init_resource::<DebugTools>()
+            .init_resource::<EvalStats>()
+            .add_systems(Update, (
+                handle_debug_input,
+                refresh_eval_stats,
+                manage_perf_overlay,
+                update_perf_overlay,
+            ).chain())
+            .add_systems(Update, dump_game_state);
+    }
+}
+
+// System to toggle the perf overlay with F3
+fn handle_debug_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut debug_tools: ResMut<DebugTools>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F3) {
+        debug_tools.show_perf = !debug_tools.show_perf;
+        info!("🛠️ Perf overlay: {}", if debug_tools.show_perf { "SHOWN" } else { "HIDDEN" });
+    }
+}
+
+// System to log a JSON snapshot of the table with F4
+fn dump_game_state(world: &mut World) {
+    let dump_requested = world
+        .get_resource::<ButtonInput<KeyCode>>()
+        .is_some_and(|keyboard_input| keyboard_input.just_pressed(KeyCode::F4));
+    if dump_requested {
+        info!("🛠️ Game state:\n{}", serialize_game_state(world));
+    }
+}
+
+// System to copy the current hand's evaluation counters out of the eval cache
+fn refresh_eval_stats(game_data: Option<Res<GameData>>, mut eval_stats: ResMut<EvalStats>) {
+    let Some(game_data) = game_data else {
+        return;
+    };
+    
+    let stats = game_data.eval_cache.stats();
+    if *eval_stats != stats {
+        *eval_stats = stats;
+    }
+}
+
+// System to spawn or despawn the overlay when the flag changes
+fn manage_perf_overlay(
+    mut commands: Commands,
+    debug_tools: Res<DebugTools>,
+    overlay_query: Query<Entity, With<PerfOverlay>>,
+) {
+    if !debug_tools.is_changed() {
+        return;
+    }
+    
+    if debug_tools.show_perf {
+        if overlay_query.is_empty() {
+            commands.spawn((
+                TextBundle::from_section(
+                    "FPS: --\nEntities: --\nHand evals: --",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::srgb(0.2, 1.0, 0.2),
+                        ..default()
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(10.0),
+                    top: Val::Px(10.0),
+                    ..default()
+                }),
+                PerfOverlay,
+            ));
+        }
+    } else {
+        for entity in overlay_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+// System to refresh the overlay from Bevy's diagnostics
+fn update_perf_overlay(
+    diagnostics: Res<DiagnosticsStore>,
+    eval_stats: Res<EvalStats>,
+    mut overlay_query: Query<&mut Text, With<PerfOverlay>>,
+) {
+    let Ok(mut text) = overlay_query.get_single_mut() else {
+        return;
+    };
+    
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|d| d.smoothed());
+    let entity_count = diagnostics
+        .get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT)
+        .and_then(|d| d.value());
+    
+    text.sections[0].value = format!(
+        "FPS: {}\nEntities: {}\nHand evals: {} ({} five-card)",
+        fps.map_or("--".to_string(), |v| format!("{:.0}", v)),
+        entity_count.map_or("--".to_string(), |v| format!("{:.0}", v)),
+        eval_stats.hand_evaluations,
+        eval_stats.five_card_evaluations,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_enabling_show_perf_spawns_overlay() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, DebugToolsPlugin))
+            .init_resource::<ButtonInput<KeyCode>>();
+        app.update();
+        
+        let mut overlays = app.world_mut().query::<&PerfOverlay>();
+        assert_eq!(overlays.iter(app.world()).count(), 0);
+        
+        app.world_mut().resource_mut::<DebugTools>().show_perf = true;
+        app.update();
+        assert_eq!(overlays.iter(app.world()).count(), 1);
+        
+        app.world_mut().resource_mut::<DebugTools>().show_perf = false;
+        app.update();
+        assert_eq!(overlays.iter(app.world()).count(), 0);
+    }
+    
+    #[test]
+    fn test_game_state_snapshot_has_pot_board_and_stacks() {
+        use bevy::state::app::StatesPlugin;
+        use crate::cards::{Rank, Suit};
+        use crate::player::PlayerType;
+        
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Turn)
+            .init_resource::<GameData>()
+            .insert_resource(BettingRound { current_bet: 40, pot: 260, players_to_act: vec![1], ..default() });
+        app.update();
+        {
+            let mut game_data = app.world_mut().resource_mut::<GameData>();
+            game_data.pot = 260;
+            game_data.community_cards = vec![
+                Card::new(Suit::Spades, Rank::Ace),
+                Card::new(Suit::Hearts, Rank::Ten),
+                Card::new(Suit::Clubs, Rank::Four),
+                Card::new(Suit::Diamonds, Rank::Nine),
+            ];
+        }
+        let mut human = Player::new(0, PlayerType::Human, 870, Vec3::ZERO);
+        human.hole_cards = vec![Card::new(Suit::Hearts, Rank::King), Card::new(Suit::Hearts, Rank::Queen)];
+        let mut folded = Player::new(1, PlayerType::AI, 940, Vec3::ZERO);
+        folded.has_folded = true;
+        app.world_mut().spawn(folded);
+        app.world_mut().spawn(human);
+        
+        let json: serde_json::Value = serde_json::from_str(&serialize_game_state(app.world_mut())).unwrap();
+        assert_eq!(json["state"], "Turn");
+        assert_eq!(json["pot"], 260);
+        assert_eq!(json["community_cards"].as_array().unwrap().len(), 4);
+        assert_eq!(json["betting_round"]["current_bet"], 40);
+        assert_eq!(json["players"][0]["chips"], 870);
+        assert_eq!(json["players"][0]["hole_cards"][0], "K♥");
+        assert_eq!(json["players"][1]["chips"], 940);
+        assert_eq!(json["players"][1]["has_folded"], true);
+    }
+}
@@ -0,0 +1,235 @@
+use bevy::prelude::*;
+use crate::betting::{BettingRound, PlayerAction};
+use crate::betting_ui::HumanPlayerInput;
+use crate::game_config::GameConfig;
+use crate::game_speed::{GameClock, GameSpeed};
+use crate::player::{Player, PlayerType};
+use crate::teaching::{ExplanationType, TeachingState};
+
+// Ring drawn around the player on the clock
+const RING_RADIUS: f32 = 70.0;
+const RING_COLOR: Color = Color::srgb(1.0, 0.8, 0.2);
+const RING_URGENT_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+
+// Resource for the human's shot clock, running only when a decision time is configured
+#[derive(Resource, Debug, Default)]
+pub struct DecisionTimer {
+    pub timer: Option<Timer>,
+    pub player_id: u32,
+}
+
+impl DecisionTimer {
+    pub fn start(&mut self, player_id: u32, seconds: f32) {
+        self.timer = Some(Timer::from_seconds(seconds, TimerMode::Once));
+        self.player_id = player_id;
+    }
+    
+    /// Share of the decision time still left (1.0 when the turn starts)
+    pub fn fraction_remaining(&self) -> f32 {
+        self.timer.as_ref().map_or(0.0, |timer| timer.fraction_remaining())
+    }
+}
+
+// Resource for the coaching pause: the human's action only registers once they've had time to think
+#[derive(Resource, Debug, Default)]
+pub struct MinThinkTime {
+    pub seconds: Option<f32>, // Minimum think time per decision; none by default
+    pub timer: Option<Timer>,
+    pub buffered: Option<PlayerAction>, // Action picked too quickly, held until the pause is over
+}
+
+// Shrinking ring around the player who is on the clock
+#[derive(Component, Debug)]
+pub struct DecisionRing {
+    pub player_id: u32,
+    pub fill: f32, // Share of the ring still drawn, matching the time left
+}
+
+// System to start the clock when the human's turn begins and act for them when it runs out
+pub fn run_decision_timer(
+    game_config: Res<GameConfig>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+    game_clock: Res<GameClock>,
+    game_speed: Option<Res<GameSpeed>>,
+    mut decision_timer: ResMut<DecisionTimer>,
+    mut human_input: ResMut<HumanPlayerInput>,
+) {
+    let Some(seconds) = game_config.decision_seconds else {
+        return;
+    };
+    
+    let human_player = betting_round
+        .peek_next_player()
+        .and_then(|id| players.iter().find(|p| p.id == id && matches!(p.player_type, PlayerType::Human)));
+    let Some(human_player) = human_player else {
+        decision_timer.timer = None;
+        return;
+    };
+    // The clock is cleared between turns, so an empty one means the turn just started
+    if decision_timer.timer.is_none() {
+        decision_timer.start(human_player.id, seconds);
+    }
+    
+    if game_speed.is_some_and(|game_speed| game_speed.is_paused) || human_input.pending_action.is_some() {
+        return;
+    }
+    let Some(timer) = decision_timer.timer.as_mut() else {
+        return;
+    };
+    if timer.tick(game_clock.delta).just_finished() {
+        // Out of time: check if it's free, otherwise fold
+        let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+        let action = if call_amount == 0 { PlayerAction::Check } else { PlayerAction::Fold };
+        info!("⏰ Time's up! Playing {:?}", action);
+        human_input.pending_action = Some(action);
+    }
+}
+
+// System to hold back an instant decision until the minimum think time has passed
+pub fn enforce_min_think_time(
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+    game_clock: Res<GameClock>,
+    mut min_think_time: ResMut<MinThinkTime>,
+    mut human_input: ResMut<HumanPlayerInput>,
+    teaching_state: Option<ResMut<TeachingState>>,
+) {
+    let Some(seconds) = min_think_time.seconds else {
+        return;
+    };
+    
+    let humans_turn = betting_round
+        .peek_next_player()
+        .is_some_and(|id| players.iter().any(|p| p.id == id && matches!(p.player_type, PlayerType::Human)));
+    if !humans_turn {
+        min_think_time.timer = None;
+        min_think_time.buffered = None;
+        return;
+    }
+    
+    // Like the shot clock, an empty timer means the turn just started
+    let timer = min_think_time.timer.get_or_insert_with(|| Timer::from_seconds(seconds, TimerMode::Once));
+    let thought_long_enough = timer.tick(game_clock.delta).finished();
+    
+    if thought_long_enough {
+        if let Some(action) = min_think_time.buffered.take() {
+            human_input.pending_action = Some(action);
+        }
+    } else if let Some(action) = human_input.pending_action.take() {
+        if min_think_time.buffered.is_none() {
+            if let Some(mut teaching_state) = teaching_state {
+                teaching_state.show_explanation(ExplanationType::PlayerAction(
+                    "Take a moment - what might they hold, and what does the pot offer you?".to_string(),
+                ));
+            }
+        }
+        min_think_time.buffered = Some(action);
+    }
+}
+
+// System to keep the ring on the player who is on the clock, sized to the time left
+pub fn update_decision_ring(
+    mut commands: Commands,
+    decision_timer: Res<DecisionTimer>,
+    mut rings: Query<(Entity, &mut DecisionRing)>,
+) {
+    let on_clock = decision_timer.timer.as_ref().filter(|timer| !timer.finished()).map(|_| decision_timer.player_id);
+    
+    for (entity, mut ring) in rings.iter_mut() {
+        if Some(ring.player_id) == on_clock {
+            ring.fill = decision_timer.fraction_remaining();
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+    
+    if let Some(player_id) = on_clock {
+        if !rings.iter().any(|(_, ring)| ring.player_id == player_id) {
+            commands.spawn(DecisionRing { player_id, fill: decision_timer.fraction_remaining() });
+        }
+    }
+}
+
+// System to draw each ring as an arc centered on the top that shrinks as time runs down
+pub fn draw_decision_rings(mut gizmos: Gizmos, rings: Query<&DecisionRing>, players: Query<&Player>) {
+    for ring in rings.iter() {
+        let Some(player) = players.iter().find(|p| p.id == ring.player_id) else {
+            continue;
+        };
+        
+        let color = if ring.fill < 0.25 { RING_URGENT_COLOR } else { RING_COLOR };
+        let arc_angle = ring.fill * std::f32::consts::TAU;
+        gizmos.arc_2d(player.position.truncate(), 0.0, arc_angle, RING_RADIUS, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    
+    #[test]
+    fn test_instant_human_action_waits_for_min_think_time() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(GameClock { manual_step: Some(Duration::from_millis(500)), ..default() })
+            .insert_resource(MinThinkTime { seconds: Some(2.0), ..default() })
+            .insert_resource(BettingRound { players_to_act: vec![0, 1], ..default() })
+            .init_resource::<HumanPlayerInput>()
+            .init_resource::<TeachingState>()
+            .add_systems(First, crate::game_speed::advance_game_clock)
+            .add_systems(Update, enforce_min_think_time);
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        
+        // Clicked straight away: held back, with a nudge to think it over
+        app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = Some(PlayerAction::Call);
+        app.update();
+        assert!(app.world().resource::<HumanPlayerInput>().pending_action.is_none());
+        assert_eq!(app.world().resource::<MinThinkTime>().buffered, Some(PlayerAction::Call));
+        assert!(app.world().resource::<TeachingState>().current_explanation.as_ref().unwrap().contains("Take a moment"));
+        
+        app.update();
+        app.update();
+        assert!(app.world().resource::<HumanPlayerInput>().pending_action.is_none());
+        
+        // Two seconds in, the call goes through
+        app.update();
+        assert_eq!(app.world().resource::<HumanPlayerInput>().pending_action, Some(PlayerAction::Call));
+        assert!(app.world().resource::<MinThinkTime>().buffered.is_none());
+    }
+    
+    #[test]
+    fn test_ring_fill_matches_time_remaining() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<DecisionTimer>()
+            .add_systems(Update, update_decision_ring);
+        app.world_mut().resource_mut::<DecisionTimer>().start(0, 10.0);
+        
+        app.world_mut()
+            .resource_mut::<DecisionTimer>()
+            .timer
+            .as_mut()
+            .unwrap()
+            .tick(Duration::from_secs(4));
+        app.update();
+        
+        let mut rings = app.world_mut().query::<&DecisionRing>();
+        let ring = rings.single(app.world());
+        assert_eq!(ring.player_id, 0);
+        assert!((ring.fill - 0.6).abs() < 1e-5, "fill was {}", ring.fill);
+        assert_eq!(ring.fill, app.world().resource::<DecisionTimer>().fraction_remaining());
+        
+        // The ring goes away once the clock runs out
+        app.world_mut()
+            .resource_mut::<DecisionTimer>()
+            .timer
+            .as_mut()
+            .unwrap()
+            .tick(Duration::from_secs(6));
+        app.update();
+        assert_eq!(rings.iter(app.world()).count(), 0);
+    }
+}
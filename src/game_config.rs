@@ -0,0 +1,302 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use crate::cards::Card;
+use crate::game_state::GameState;
+use crate::poker_rules::{evaluate_hand, evaluate_omaha, HandEvaluation};
+
+// Resource for table rules chosen before the game starts
+#[derive(Resource, Debug, Clone, Default)]
+pub struct GameConfig {
+    pub allow_straddle: bool, // Player left of the big blind posts a blind raise before the deal
+    pub auto_muck_losing_hands: bool, // Players who can't beat the best shown hand muck at showdown
+    pub heads_up: bool, // Quick practice mode: one human against a single AI
+    pub variant: GameVariant,
+    pub raise_step: u32, // Raise stepper increment; 0 steps by the current minimum raise
+    pub fast_fold: bool, // After the human folds, settle the hand at once and deal them a new one
+    pub card_scale: Option<f32>, // Board card size multiplier for larger screens (default 1.0)
+    pub decision_seconds: Option<f32>, // Shot clock for the human's decisions; none by default
+    pub scenario: Option<ScenarioConfig>, // Focused lesson that replaces the normal deal
+    pub lesson_path: Option<String>, // Lesson script to run, from `--lesson=<file.ron>`
+    pub practice: bool, // Stack the first hands so the learner meets each key situation once
+    pub showdown_pacing: ShowdownPacing,
+    pub reduced_motion: bool, // Accessibility: cards and chips snap into place instead of animating
+    pub human_auto_muck: bool, // The human mucks a losing hand at showdown unless they were the aggressor
+    pub min_think_seconds: Option<f32>, // Coaching pause: the human's actions wait at least this long each turn
+    pub ai_seed: Option<u64>, // Base seed for the AI seats' RNGs; each seat adds its id. Random by default
+    pub num_players: Option<u32>, // Seats at the table (default 3): the human and AIs in the rest; heads-up always plays two
+    pub starting_chips: Option<u32>, // Each player's stack at the start of a game (default $1000)
+    pub small_blind: Option<u32>, // Small blind (default $10); the big blind is twice this
+}
+
+// Table size limits: heads-up up to a 6-max table
+pub const MIN_PLAYERS: u32 = 2;
+pub const MAX_PLAYERS: u32 = 6;
+
+// Seconds the GUI spends on each hand shown at showdown
+pub const GUI_REVEAL_SECONDS: f32 = 1.0;
+
+// How long the showdown lingers before the pot is awarded
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ShowdownPacing {
+    #[default]
+    Instant, // Resolve at once, for headless sims and tests
+    Paced { per_player_secs: f32 }, // Give the learner time to look at each hand
+}
+
+impl ShowdownPacing {
+    pub fn reveal_seconds(&self, players_at_showdown: usize) -> f32 {
+        match self {
+            ShowdownPacing::Instant => 0.0,
+            ShowdownPacing::Paced { per_player_secs } => per_player_secs * players_at_showdown as f32,
+        }
+    }
+}
+
+// Which poker game is dealt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameVariant {
+    #[default]
+    Holdem,
+    Omaha, // Four hole cards; a hand uses exactly two of them with three from the board
+}
+
+impl GameVariant {
+    pub fn hole_card_count(&self) -> usize {
+        match self {
+            GameVariant::Holdem => 2,
+            GameVariant::Omaha => 4,
+        }
+    }
+    
+    pub fn evaluate(&self, hole_cards: &[Card], community_cards: &[Card]) -> HandEvaluation {
+        match self {
+            GameVariant::Holdem => evaluate_hand(hole_cards, community_cards),
+            GameVariant::Omaha => evaluate_omaha(hole_cards, community_cards),
+        }
+    }
+}
+
+// A focused lesson (e.g. "practice river decisions"): the hand starts on a given street with preset cards
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ScenarioConfig {
+    pub hole_cards: Vec<Card>,   // The human's hole cards
+    pub board: Vec<Card>,        // Community cards already dealt when the hand starts
+    pub start_street: GameState, // PreFlop, Flop, Turn or River
+}
+
+impl ScenarioConfig {
+    // A full set of hole cards for the variant, the right board for the street, and no card twice
+    pub fn is_valid(&self, variant: GameVariant) -> bool {
+        // Board size by street: none pre-flop, then 3, 4 and 5 cards
+        let board_len = match self.start_street.street_index() {
+            Some(0) => 0,
+            Some(street) => street as usize + 2,
+            None => return false,
+        };
+        
+        let mut cards = self.hole_cards.clone();
+        cards.extend_from_slice(&self.board);
+        let all_unique = cards.iter().enumerate().all(|(i, card)| !cards[..i].contains(card));
+        
+        self.hole_cards.len() == variant.hole_card_count() && self.board.len() == board_len && all_unique
+    }
+}
+
+// Launch options that can't be played together, caught before the game starts
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameConfigError {
+    StraddleNeedsThreePlayers, // Heads-up has no seat left of the big blind to straddle from
+    ScenarioHoleCards { expected: usize, found: usize }, // Scenario deals the wrong number of hole cards for the variant
+    InvalidScenario, // Board size doesn't match the starting street, or a card is repeated
+    LessonNeedsHoldem, // Lesson scripts deal two-card Hold'em hands
+    PracticeNeedsHoldem, // Practice situations deal two-card Hold'em hands
+    PracticeWithLesson, // Both would stack the same hands
+    TableSize(u32), // Fewer than two or more than six seats
+    BigBlindOverStack { big_blind: u32, starting_chips: u32 }, // Blinds would put players all-in every hand
+}
+
+impl std::fmt::Display for GameConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GameConfigError::StraddleNeedsThreePlayers => write!(f, "a straddle needs at least three players, so it can't be used heads-up"),
+            GameConfigError::ScenarioHoleCards { expected, found } => {
+                write!(f, "the scenario deals {} hole cards but this variant needs {}", found, expected)
+            },
+            GameConfigError::InvalidScenario => {
+                write!(f, "the scenario's board size must match its starting street and every card must be unique")
+            },
+            GameConfigError::LessonNeedsHoldem => write!(f, "lessons are written for Hold'em and can't be played as Omaha"),
+            GameConfigError::PracticeNeedsHoldem => write!(f, "practice mode deals Hold'em hands and can't be played as Omaha"),
+            GameConfigError::PracticeWithLesson => write!(f, "practice mode and a lesson can't run at the same time"),
+            GameConfigError::TableSize(players) => {
+                write!(f, "a table seats {} to {} players, not {}", MIN_PLAYERS, MAX_PLAYERS, players)
+            },
+            GameConfigError::BigBlindOverStack { big_blind, starting_chips } => {
+                write!(f, "a ${} big blind is more than the ${} starting stack", big_blind, starting_chips)
+            },
+        }
+    }
+}
+
+impl GameConfig {
+    /// Check the options make a playable game; run once at startup
+    pub fn validate(&self) -> Result<(), GameConfigError> {
+        if self.allow_straddle && self.heads_up {
+            return Err(GameConfigError::StraddleNeedsThreePlayers);
+        }
+        if let Some(scenario) = &self.scenario {
+            let expected = self.variant.hole_card_count();
+            if scenario.hole_cards.len() != expected {
+                return Err(GameConfigError::ScenarioHoleCards { expected, found: scenario.hole_cards.len() });
+            }
+            if !scenario.is_valid(self.variant) {
+                return Err(GameConfigError::InvalidScenario);
+            }
+        }
+        if self.lesson_path.is_some() && self.variant != GameVariant::Holdem {
+            return Err(GameConfigError::LessonNeedsHoldem);
+        }
+        if self.practice && self.variant != GameVariant::Holdem {
+            return Err(GameConfigError::PracticeNeedsHoldem);
+        }
+        if self.practice && self.lesson_path.is_some() {
+            return Err(GameConfigError::PracticeWithLesson);
+        }
+        let players = self.player_count();
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&players) {
+            return Err(GameConfigError::TableSize(players));
+        }
+        let big_blind = self.small_blind() * 2;
+        if big_blind > self.starting_chips() {
+            return Err(GameConfigError::BigBlindOverStack { big_blind, starting_chips: self.starting_chips() });
+        }
+        Ok(())
+    }
+    
+    pub fn player_count(&self) -> u32 {
+        if self.heads_up { 2 } else { self.num_players.unwrap_or(3) }
+    }
+    
+    pub fn starting_chips(&self) -> u32 {
+        self.starting_chips.unwrap_or(1000)
+    }
+    
+    pub fn small_blind(&self) -> u32 {
+        self.small_blind.unwrap_or(10)
+    }
+    
+    // How much the raise stepper's +/- buttons change the raise amount
+    pub fn raise_increment(&self, min_raise: u32) -> u32 {
+        if self.raise_step > 0 { self.raise_step } else { min_raise }
+    }
+    
+    // Build the config from command-line launch options (e.g. `--heads-up`, `--omaha`, `--fast-fold`, `--raise-step=25`, `--card-scale=1.5`, `--decision-time=20`, `--think-time=2`, `--lesson=intro.ron`, `--practice`, `--instant-showdown`, `--reduced-motion`, `--muck-my-losing-hands`, `--ai-seed=42`, `--players=6`, `--starting-chips=2000`, `--small-blind=25`)
+    pub fn from_args(args: impl Iterator<Item = String>) -> Self {
+        let mut config = Self {
+            showdown_pacing: ShowdownPacing::Paced { per_player_secs: GUI_REVEAL_SECONDS },
+            ..Self::default()
+        };
+        for arg in args {
+            if arg == "--heads-up" {
+                config.heads_up = true;
+            } else if arg == "--omaha" {
+                config.variant = GameVariant::Omaha;
+            } else if arg == "--fast-fold" {
+                config.fast_fold = true;
+            } else if arg == "--practice" {
+                config.practice = true;
+            } else if arg == "--instant-showdown" {
+                config.showdown_pacing = ShowdownPacing::Instant;
+            } else if arg == "--reduced-motion" {
+                config.reduced_motion = true;
+            } else if arg == "--muck-my-losing-hands" {
+                config.human_auto_muck = true;
+            } else if let Some(step) = arg.strip_prefix("--raise-step=") {
+                config.raise_step = step.parse().unwrap_or_default();
+            } else if let Some(seconds) = arg.strip_prefix("--decision-time=") {
+                config.decision_seconds = seconds.parse().ok().filter(|seconds: &f32| *seconds > 0.0);
+            } else if let Some(seconds) = arg.strip_prefix("--think-time=") {
+                config.min_think_seconds = seconds.parse().ok().filter(|seconds: &f32| *seconds > 0.0);
+            } else if let Some(scale) = arg.strip_prefix("--card-scale=") {
+                config.card_scale = scale.parse().ok().filter(|scale: &f32| *scale > 0.0);
+            } else if let Some(seed) = arg.strip_prefix("--ai-seed=") {
+                config.ai_seed = seed.parse().ok();
+            } else if let Some(players) = arg.strip_prefix("--players=") {
+                config.num_players = players.parse().ok();
+            } else if let Some(chips) = arg.strip_prefix("--starting-chips=") {
+                config.starting_chips = chips.parse().ok().filter(|chips: &u32| *chips > 0);
+            } else if let Some(blind) = arg.strip_prefix("--small-blind=") {
+                config.small_blind = blind.parse().ok().filter(|blind: &u32| *blind > 0);
+            } else if let Some(path) = arg.strip_prefix("--lesson=") {
+                config.lesson_path = Some(path.to_string());
+            }
+        }
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{Rank, Suit};
+    
+    fn river_scenario(hole_cards: Vec<Card>) -> ScenarioConfig {
+        ScenarioConfig {
+            hole_cards,
+            board: vec![
+                Card::new(Suit::Hearts, Rank::King),
+                Card::new(Suit::Hearts, Rank::Nine),
+                Card::new(Suit::Hearts, Rank::Four),
+                Card::new(Suit::Clubs, Rank::Seven),
+                Card::new(Suit::Spades, Rank::Jack),
+            ],
+            start_street: GameState::River,
+        }
+    }
+    
+    #[test]
+    fn test_invalid_setups_report_the_matching_error() {
+        assert_eq!(GameConfig::default().validate(), Ok(()));
+        
+        let straddle_heads_up = GameConfig { allow_straddle: true, heads_up: true, ..default() };
+        assert_eq!(straddle_heads_up.validate(), Err(GameConfigError::StraddleNeedsThreePlayers));
+        
+        let two_hole_cards = vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Hearts, Rank::Two)];
+        let omaha_with_two_cards = GameConfig {
+            variant: GameVariant::Omaha,
+            scenario: Some(river_scenario(two_hole_cards.clone())),
+            ..default()
+        };
+        assert_eq!(
+            omaha_with_two_cards.validate(),
+            Err(GameConfigError::ScenarioHoleCards { expected: 4, found: 2 })
+        );
+        
+        let mut short_board = river_scenario(two_hole_cards.clone());
+        short_board.board.pop();
+        let invalid_scenario = GameConfig { scenario: Some(short_board), ..default() };
+        assert_eq!(invalid_scenario.validate(), Err(GameConfigError::InvalidScenario));
+        assert_eq!(GameConfig { scenario: Some(river_scenario(two_hole_cards.clone())), ..default() }.validate(), Ok(()));
+        
+        // Four hole cards are a complete Omaha hand, not an invalid scenario
+        let mut four_hole_cards = two_hole_cards;
+        four_hole_cards.extend([Card::new(Suit::Diamonds, Rank::Queen), Card::new(Suit::Diamonds, Rank::Ten)]);
+        let omaha_scenario = GameConfig { variant: GameVariant::Omaha, scenario: Some(river_scenario(four_hole_cards)), ..default() };
+        assert_eq!(omaha_scenario.validate(), Ok(()));
+        
+        let omaha_lesson = GameConfig::from_args(["--omaha", "--lesson=intro.ron"].map(String::from).into_iter());
+        assert_eq!(omaha_lesson.validate(), Err(GameConfigError::LessonNeedsHoldem));
+        
+        let practice_lesson = GameConfig::from_args(["--practice", "--lesson=intro.ron"].map(String::from).into_iter());
+        assert_eq!(practice_lesson.validate(), Err(GameConfigError::PracticeWithLesson));
+        
+        let full_ring = GameConfig::from_args(["--players=9"].map(String::from).into_iter());
+        assert_eq!(full_ring.validate(), Err(GameConfigError::TableSize(9)));
+        assert_eq!(GameConfig { num_players: Some(6), ..default() }.validate(), Ok(()));
+        
+        let huge_blinds = GameConfig::from_args(["--small-blind=600"].map(String::from).into_iter());
+        assert_eq!(huge_blinds.validate(), Err(GameConfigError::BigBlindOverStack { big_blind: 1200, starting_chips: 1000 }));
+        let deep_stacks = GameConfig { small_blind: Some(600), starting_chips: Some(5000), ..default() };
+        assert_eq!(deep_stacks.validate(), Ok(()));
+    }
+}
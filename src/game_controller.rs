@@ -1,15 +1,20 @@
 use bevy::prelude::*;
-use crate::cards::Deck;
+use crate::cards::{Card, Deck, DuplicateCard, FULL_DECK_SIZE};
 use crate::player::{Player, PlayerType};
-use crate::game_state::{GameState, GameData, GamePosition};
-use crate::betting::BettingRound;
-use crate::poker_rules::{evaluate_hand, hand_rank_name};
+use crate::game_state::{GameState, GameData, GameEvent, GamePosition};
+use crate::game_config::{GameConfig, GameVariant};
+use crate::betting::{build_side_pots, BettingRound, PlayerAction, SidePot};
+use crate::poker_rules::{hand_rank_name, HandEvaluation};
+use crate::teaching::TeachingState;
+use crate::game_speed::GameClock;
 
 // Resource to control game timing
 #[derive(Resource)]
 pub struct GameController {
     pub state_timer: Timer,
     pub auto_advance: bool,
+    pub step_requested: bool, // With auto-advance off, run the next phase transition once
+    pub deck_seed: Option<u64>, // Shuffle every hand from this seed, to replay a deal exactly
 }
 
 impl Default for GameController {
@@ -17,13 +22,15 @@ impl Default for GameController {
         Self {
             state_timer: Timer::from_seconds(2.0, TimerMode::Once),
             auto_advance: true,
+            step_requested: false,
+            deck_seed: None,
         }
     }
 }
 
 // System to handle automatic game state transitions
 pub fn game_state_controller(
-    time: Res<Time>,
+    game_clock: Res<GameClock>,
     mut controller: ResMut<GameController>,
     mut game_state: ResMut<NextState<GameState>>,
     current_state: Res<State<GameState>>,
@@ -32,14 +39,39 @@ pub fn game_state_controller(
     mut game_position: ResMut<GamePosition>,
     mut players: Query<&mut Player>,
     mut betting_round: ResMut<BettingRound>,
+    game_config: Res<GameConfig>,
+    teaching_state: Option<Res<TeachingState>>,
+    mut game_events: EventWriter<GameEvent>,
 ) {
-    if !controller.auto_advance {
+    // A tutorial checkpoint holds the hand until the learner dismisses it
+    if teaching_state.is_some_and(|teaching_state| teaching_state.at_checkpoint()) {
+        return;
+    }
+    
+    // With auto-advance off, only a requested step moves the hand along
+    let stepping = !controller.auto_advance && controller.step_requested;
+    if !controller.auto_advance && !stepping {
         return;
     }
     
     // Check if we should pause auto-advance for human player input
     match current_state.get() {
         GameState::PreFlop | GameState::Flop | GameState::Turn | GameState::River => {
+            // Fast fold: once the human is out, don't make them watch the rest of the hand
+            let human_folded = players.iter().any(|p| matches!(p.player_type, PlayerType::Human) && p.has_folded);
+            if game_config.fast_fold && human_folded {
+                game_data.pot = betting_round.pot;
+                settle_without_human(&mut deck, &mut players, &mut game_data, &mut game_position, &game_config);
+                game_events.send(hand_ended(&game_data));
+                
+                // Still end the hand through GameOver so busts, streaks and the recap are recorded
+                info!("⚡ Fast fold: dealing you a new hand");
+                game_state.set(GameState::GameOver);
+                controller.state_timer = Timer::from_seconds(0.5, TimerMode::Once);
+                controller.state_timer.reset();
+                return;
+            }
+            
             // Check if it's a human player's turn to act
             if let Some(current_player_id) = betting_round.peek_next_player() {
                 for player in players.iter() {
@@ -56,38 +88,60 @@ pub fn game_state_controller(
         _ => {}, // Allow auto-advance for non-betting phases
     }
     
-    controller.state_timer.tick(time.delta());
+    controller.state_timer.tick(game_clock.delta);
     
-    if controller.state_timer.finished() {
+    if controller.state_timer.finished() || stepping {
         match current_state.get() {
             GameState::Setup => {
-                // Initialize new round
-                deck.reset();
+                // Initialize new round with every card back in the deck
+                match controller.deck_seed {
+                    Some(seed) => *deck = Deck::from_seed(seed),
+                    None => deck.reset(),
+                }
+                if !deck.ensure_full() {
+                    error!("Deck didn't hold {} distinct cards after the reshuffle; rebuilt it", FULL_DECK_SIZE);
+                }
                 game_data.new_round();
                 
-                // Reset all players for new round
+                // Reset per-hand player state; stacks (including pots just won) carry over
                 for mut player in players.iter_mut() {
-                    player.clear_hand();
-                    player.current_bet = 0;
-                    player.has_folded = false;
+                    player.reset_for_new_hand();
                 }
                 
                 // Initialize betting round
                 let player_ids: Vec<u32> = players.iter().map(|p| p.id).collect();
-                *betting_round = BettingRound::new(player_ids, 10); // $10 small blind
+                *betting_round = BettingRound::new(player_ids, game_position.small_blind_amount);
                 
                 info!("Starting new poker round!");
+                game_events.send(GameEvent::HandStarted);
                 game_state.set(GameState::Dealing);
                 controller.state_timer.reset();
             },
             
             GameState::Dealing => {
-                // First post blinds before dealing
-                post_blinds(&mut players, &game_position, &mut game_data);
+                // First post blinds (and any straddle) before dealing
+                let (opening_bet, straddler) = post_blinds(&mut players, &game_position, &mut game_data, &game_config);
                 
-                // Deal 2 cards to each player
+                // A lesson scenario presets the human's cards and the board
+                let scenario = game_config.scenario.as_ref().filter(|scenario| scenario.is_valid(game_config.variant));
+                if game_config.scenario.is_some() && scenario.is_none() {
+                    warn!("Ignoring invalid scenario: board size must match the starting street and cards must be unique");
+                }
+                if let Some(scenario) = scenario {
+                    // Keep the scenario's cards out of everyone else's hands
+                    deck.cards.retain(|card| !scenario.hole_cards.contains(card) && !scenario.board.contains(card));
+                }
+                
+                // Deal hole cards to each player: 2 in Hold'em, 4 in Omaha
                 for mut player in players.iter_mut() {
-                    for _ in 0..2 {
+                    if let Some(scenario) = scenario.filter(|_| matches!(player.player_type, PlayerType::Human)) {
+                        for &card in &scenario.hole_cards {
+                            player.add_card(card);
+                        }
+                        continue;
+                    }
+                    
+                    for _ in 0..game_config.variant.hole_card_count() {
                         if let Some(card) = deck.deal() {
                             player.add_card(card);
                         }
@@ -95,19 +149,51 @@ pub fn game_state_controller(
                 }
                 
                 info!("Cards dealt to all players, blinds posted");
+                game_events.send(GameEvent::CardsDealt);
                 
-                // Start pre-flop betting with proper betting order
-                let active_players: Vec<u32> = players
-                    .iter()
-                    .filter(|p| !p.has_folded)
-                    .map(|p| p.id)
+                // Catch dealing bugs (e.g. a scenario reusing a card) while developing
+                if cfg!(debug_assertions) {
+                    if let Err(duplicate) = validate_no_duplicate_cards(players.iter(), &game_data.community_cards) {
+                        error!("Dealing error: {}", duplicate);
+                    }
+                }
+                deck.assert_invariant(&dealt_cards(&players, &game_data.community_cards));
+                
+                // Start pre-flop betting left of the big blind; a straddler acts last instead
+                let mut preflop_order: Vec<u32> = game_position
+                    .get_betting_order(true)
+                    .into_iter()
+                    .filter(|id| players.iter().any(|p| p.id == *id && !p.has_folded))
                     .collect();
-                betting_round.reset_for_new_round(active_players);
+                if let Some(straddler) = straddler {
+                    preflop_order.retain(|&id| id != straddler);
+                    preflop_order.push(straddler);
+                }
+                betting_round.reset_for_new_round(preflop_order);
                 
-                // Set current bet to big blind amount
-                game_data.current_bet = game_position.big_blind_amount;
+                // Set current bet to the big blind (or straddle) amount; the posted blinds seed the pot
+                betting_round.current_bet = opening_bet;
+                betting_round.pot = game_data.pot;
+                game_data.current_bet = opening_bet;
                 
-                game_state.set(GameState::PreFlop);
+                if let Some(scenario) = scenario.filter(|scenario| scenario.start_street != GameState::PreFlop) {
+                    // Jump straight to the lesson's street; the blinds form the starting pot
+                    game_data.community_cards = scenario.board.clone();
+                    if cfg!(debug_assertions) {
+                        if let Err(duplicate) = validate_no_duplicate_cards(players.iter(), &game_data.community_cards) {
+                            error!("Scenario error: {}", duplicate);
+                        }
+                    }
+                    deck.assert_invariant(&dealt_cards(&players, &game_data.community_cards));
+                    start_postflop_street(&mut betting_round, &mut players, &game_position);
+                    betting_round.current_bet = 0;
+                    game_data.current_bet = 0;
+                    
+                    info!("Starting lesson scenario on the {:?}", scenario.start_street);
+                    game_state.set(scenario.start_street);
+                } else {
+                    game_state.set(GameState::PreFlop);
+                }
                 controller.state_timer = Timer::from_seconds(0.5, TimerMode::Once); // Faster for betting
                 controller.state_timer.reset();
             },
@@ -116,6 +202,7 @@ pub fn game_state_controller(
                 // Check if betting is complete
                 if betting_round.is_complete() {
                     // Update pot in game data
+                    return_uncalled_bet(&mut players, &mut betting_round);
                     game_data.pot = betting_round.pot;
                     
                     let active_players = players.iter().filter(|p| !p.has_folded).count();
@@ -133,26 +220,35 @@ pub fn game_state_controller(
             },
             
             GameState::Flop => {
-                // Deal 3 community cards
-                for _ in 0..3 {
-                    if let Some(card) = deck.deal() {
-                        game_data.community_cards.push(card);
+                // Check if betting is complete, or deal the flop
+                if game_data.community_cards.len() < 3 {
+                    // Deal 3 community cards
+                    for _ in 0..3 {
+                        if let Some(card) = deck.deal() {
+                            game_data.community_cards.push(card);
+                        }
                     }
+                    
+                    info!("Flop dealt: {} community cards", game_data.community_cards.len());
+                    game_events.send(GameEvent::StreetDealt(GameState::Flop));
+                    deck.assert_invariant(&dealt_cards(&players, &game_data.community_cards));
+                    
+                    // Start post-flop betting
+                    start_postflop_street(&mut betting_round, &mut players, &game_position);
+                } else if betting_round.is_complete() {
+                    // Flop betting complete (including a checked-around street), move to turn
+                    return_uncalled_bet(&mut players, &mut betting_round);
+                    game_data.pot = betting_round.pot;
+                    
+                    let active_players = players.iter().filter(|p| !p.has_folded).count();
+                    if active_players <= 1 {
+                        game_state.set(GameState::Showdown);
+                    } else {
+                        game_state.set(GameState::Turn);
+                    }
+                    controller.state_timer = Timer::from_seconds(2.0, TimerMode::Once);
+                    controller.state_timer.reset();
                 }
-                
-                info!("Flop dealt: {} community cards", game_data.community_cards.len());
-                
-                // Start post-flop betting
-                let active_players: Vec<u32> = players
-                    .iter()
-                    .filter(|p| !p.has_folded)
-                    .map(|p| p.id)
-                    .collect();
-                betting_round.reset_for_new_round(active_players);
-                
-                game_state.set(GameState::Turn);
-                controller.state_timer = Timer::from_seconds(0.5, TimerMode::Once);
-                controller.state_timer.reset();
             },
             
             GameState::Turn => {
@@ -163,16 +259,14 @@ pub fn game_state_controller(
                         game_data.community_cards.push(card);
                     }
                     info!("Turn dealt: {} community cards", game_data.community_cards.len());
+                    game_events.send(GameEvent::StreetDealt(GameState::Turn));
+                    deck.assert_invariant(&dealt_cards(&players, &game_data.community_cards));
                     
                     // Start turn betting
-                    let active_players: Vec<u32> = players
-                        .iter()
-                        .filter(|p| !p.has_folded)
-                        .map(|p| p.id)
-                        .collect();
-                    betting_round.reset_for_new_round(active_players);
+                    start_postflop_street(&mut betting_round, &mut players, &game_position);
                 } else if betting_round.is_complete() {
                     // Betting complete, move to river
+                    return_uncalled_bet(&mut players, &mut betting_round);
                     game_data.pot = betting_round.pot;
                     
                     let active_players = players.iter().filter(|p| !p.has_folded).count();
@@ -194,26 +288,33 @@ pub fn game_state_controller(
                         game_data.community_cards.push(card);
                     }
                     info!("River dealt: {} community cards", game_data.community_cards.len());
+                    game_events.send(GameEvent::StreetDealt(GameState::River));
+                    deck.assert_invariant(&dealt_cards(&players, &game_data.community_cards));
                     
                     // Start river betting
-                    let active_players: Vec<u32> = players
-                        .iter()
-                        .filter(|p| !p.has_folded)
-                        .map(|p| p.id)
-                        .collect();
-                    betting_round.reset_for_new_round(active_players);
+                    start_postflop_street(&mut betting_round, &mut players, &game_position);
                 } else if betting_round.is_complete() {
-                    // Final betting complete, move to showdown
+                    // Final betting complete, move to showdown; the pause scales with the hands to show
+                    return_uncalled_bet(&mut players, &mut betting_round);
                     game_data.pot = betting_round.pot;
                     game_state.set(GameState::Showdown);
-                    controller.state_timer = Timer::from_seconds(3.0, TimerMode::Once);
+                    let players_at_showdown = players.iter().filter(|p| !p.has_folded).count();
+                    let reveal_seconds = game_config.showdown_pacing.reveal_seconds(players_at_showdown);
+                    controller.state_timer = Timer::from_seconds(reveal_seconds, TimerMode::Once);
                     controller.state_timer.reset();
                 }
             },
             
             GameState::Showdown => {
-                // Evaluate hands and determine winner
-                determine_winner(&mut players, &game_data, &mut game_position);
+                // Evaluate hands and determine winner; the last street's raiser has to show
+                let last_aggressor = betting_round
+                    .action_history
+                    .iter()
+                    .rev()
+                    .find(|(_, action)| matches!(action, PlayerAction::Raise(_)))
+                    .map(|(player_id, _)| *player_id);
+                determine_winner(&mut players, &mut game_data, &mut game_position, &game_config, last_aggressor);
+                game_events.send(hand_ended(&game_data));
                 
                 game_state.set(GameState::GameOver);
                 controller.state_timer = Timer::from_seconds(5.0, TimerMode::Once);
@@ -237,21 +338,8 @@ pub fn game_state_controller(
                         info!("🎉 GAME OVER! All players are eliminated.");
                     }
                     
-                    // Reset the game after 10 seconds
-                    controller.state_timer = Timer::from_seconds(10.0, TimerMode::Once);
-                    if controller.state_timer.finished() {
-                        // Reset all players' chips for a new game
-                        for mut player in players.iter_mut() {
-                            player.chips = 1000; // Reset to starting chips
-                            player.clear_hand();
-                            player.current_bet = 0;
-                            player.has_folded = false;
-                        }
-                        info!("🔄 Starting new game! All players reset to $1000 chips.");
-                        game_state.set(GameState::Setup);
-                        controller.state_timer = Timer::from_seconds(2.0, TimerMode::Once);
-                        controller.state_timer.reset();
-                    }
+                    // Let the player decide what happens next instead of resetting on a timer
+                    game_state.set(GameState::PostGameChoice);
                 } else {
                     // Multiple players still have chips - continue to next round
                     info!("Round complete, starting new round...");
@@ -269,26 +357,84 @@ pub fn game_state_controller(
                     controller.state_timer.reset();
                 }
             },
+            
+            GameState::PostGameChoice => {
+                // Waiting on handle_post_game_choice
+            },
         }
     }
+    
+    // A step is used up once it has produced a phase transition
+    if stepping && matches!(*game_state, NextState::Pending(_)) {
+        controller.step_requested = false;
+    }
 }
 
-fn determine_winner(players: &mut Query<&mut Player>, game_data: &GameData, game_position: &mut GamePosition) {
+// Work out whose cards are shown at showdown. Players show in seat order; anyone set to
+// auto-muck who can't at least tie the best hand shown so far mucks instead.
+fn showdown_reveals(evaluations: &[(u32, HandEvaluation)], mucks_losing_hand: impl Fn(u32) -> bool) -> Vec<u32> {
+    let mut in_seat_order: Vec<&(u32, HandEvaluation)> = evaluations.iter().collect();
+    in_seat_order.sort_by_key(|(player_id, _)| *player_id);
+    
+    let mut reveals = Vec::new();
+    let mut best_shown: Option<&HandEvaluation> = None;
+    for (player_id, evaluation) in in_seat_order {
+        let can_win = best_shown.is_none_or(|best| evaluation >= best);
+        if !mucks_losing_hand(*player_id) || can_win {
+            reveals.push(*player_id);
+        }
+        if can_win {
+            best_shown = Some(evaluation);
+        }
+    }
+    reveals
+}
+
+fn determine_winner(
+    players: &mut Query<&mut Player>,
+    game_data: &mut GameData,
+    game_position: &mut GamePosition,
+    game_config: &GameConfig,
+    last_aggressor: Option<u32>,
+) {
     let mut evaluations = Vec::new();
     
-    // Evaluate each active player's hand
+    // Evaluate each active player's hand; one left without cards by a dealing bug can't win
     for player in players.iter() {
+        if !player.has_folded && player.hole_cards.is_empty() {
+            error!("Player {} reached showdown without hole cards; awarding the pot among the other hands", player.id);
+        }
         if !player.has_folded && !player.hole_cards.is_empty() {
-            let evaluation = evaluate_hand(&player.hole_cards, &game_data.community_cards);
+            // The cache pools hole and board cards, which Omaha's two-plus-three rule doesn't allow
+            let evaluation = match game_config.variant {
+                GameVariant::Holdem => game_data.eval_cache.evaluate(&player.hole_cards, &game_data.community_cards),
+                variant => variant.evaluate(&player.hole_cards, &game_data.community_cards),
+            };
             evaluations.push((player.id, evaluation, player.player_type));
         }
     }
     
     if evaluations.is_empty() {
-        info!("No active players for showdown");
+        if game_data.pot > 0 {
+            error!("No player at showdown holds cards; the ${} pot goes unawarded", game_data.pot);
+        } else {
+            info!("No active players for showdown");
+        }
         return;
     }
     
+    let shown: Vec<(u32, HandEvaluation)> = evaluations
+        .iter()
+        .map(|(player_id, evaluation, _)| (*player_id, evaluation.clone()))
+        .collect();
+    // The human mucks by their own setting, and never when they were the one betting
+    game_data.showdown_reveals = showdown_reveals(&shown, |player_id| {
+        match evaluations.iter().find(|(id, _, _)| *id == player_id).map(|(_, _, player_type)| player_type) {
+            Some(PlayerType::Human) => game_config.human_auto_muck && last_aggressor != Some(player_id),
+            _ => game_config.auto_muck_losing_hands,
+        }
+    });
+    
     // Sort by hand strength (best first)
     evaluations.sort_by(|(_, eval_a, _), (_, eval_b, _)| eval_b.cmp(eval_a));
     
@@ -309,36 +455,78 @@ fn determine_winner(players: &mut Query<&mut Player>, game_data: &GameData, game
         );
     }
     
-    // Winner is first in sorted list
-    let (winner_id, winner_evaluation, winner_type) = &evaluations[0];
-    let winner_name = match winner_type {
-        PlayerType::Human => "Human",
-        PlayerType::AI => "AI",
-    };
+    // Layer the pot by what each player put in; chips in the pot nobody is recorded as
+    // betting (e.g. a lesson's starting pot) are dead money in the main pot
+    let contributions: Vec<(u32, u32, bool)> = players
+        .iter()
+        .map(|p| (p.id, p.total_contribution(), evaluations.iter().any(|(id, _, _)| *id == p.id)))
+        .collect();
+    let mut side_pots = build_side_pots(&contributions);
+    let layered: u32 = side_pots.iter().map(|side_pot| side_pot.amount).sum();
+    match side_pots.first_mut() {
+        Some(main_pot) => main_pot.amount += game_data.pot.saturating_sub(layered),
+        None => side_pots.push(SidePot {
+            amount: game_data.pot,
+            eligible: evaluations.iter().map(|(id, _, _)| *id).collect(),
+            contributors: 0, // Nobody is recorded as betting it
+        }),
+    }
     
-    // CRITICAL FIX: Actually transfer chips to winner!
-    for mut player in players.iter_mut() {
-        if player.id == *winner_id {
-            player.chips += game_data.pot;
-            info!(
-                "💰 CHIPS TRANSFERRED: {} Player {} receives ${} (new total: ${})",
-                winner_name,
-                winner_id,
-                game_data.pot,
-                player.chips
-            );
-            break;
+    // Each layer goes to the best hand among the players eligible for it (evaluations are best first);
+    // equal hands split it, with any odd chips going one each from the left of the button
+    game_data.pot_awards.clear();
+    for side_pot in side_pots.iter().filter(|side_pot| side_pot.amount > 0) {
+        let Some((_, best_evaluation, _)) = evaluations.iter().find(|(id, _, _)| side_pot.eligible.contains(id)) else {
+            error!("Nobody at showdown is eligible for a ${} pot; it goes unawarded", side_pot.amount);
+            continue;
+        };
+        let mut winners: Vec<&(u32, HandEvaluation, PlayerType)> = evaluations
+            .iter()
+            .filter(|(id, evaluation, _)| side_pot.eligible.contains(id) && evaluation == best_evaluation)
+            .collect();
+        winners.sort_by_key(|(id, _, _)| seats_left_of_button(*id, game_position));
+        let share = side_pot.amount / winners.len() as u32;
+        let odd_chips = side_pot.amount % winners.len() as u32;
+        if winners.len() > 1 {
+            info!("🤝 Split pot: ${} shared by {} players", side_pot.amount, winners.len());
+        }
+        
+        for (index, (winner_id, winner_evaluation, winner_type)) in winners.into_iter().enumerate() {
+            let amount = share + u32::from((index as u32) < odd_chips);
+            let winner_name = match winner_type {
+                PlayerType::Human => "Human",
+                PlayerType::AI => "AI",
+            };
+            
+            for mut player in players.iter_mut().filter(|p| p.id == *winner_id) {
+                player.chips += amount;
+                if side_pot.is_uncalled() {
+                    info!("↩️ ${} nobody could match returned to Player {}", amount, winner_id);
+                } else {
+                    // One entry per winner, however many layers they take
+                    match game_data.pot_awards.iter_mut().find(|(awarded_id, _)| awarded_id == winner_id) {
+                        Some((_, awarded)) => *awarded += amount,
+                        None => game_data.pot_awards.push((*winner_id, amount)),
+                    }
+                    info!(
+                        "💰 CHIPS TRANSFERRED: {} Player {} receives ${} (new total: ${})",
+                        winner_name,
+                        winner_id,
+                        amount,
+                        player.chips
+                    );
+                    info!(
+                        "🏆 WINNER: {} Player {} with {} wins pot of ${}!",
+                        winner_name,
+                        winner_id,
+                        hand_rank_name(&winner_evaluation.rank),
+                        amount
+                    );
+                }
+            }
         }
     }
     
-    info!(
-        "🏆 WINNER: {} Player {} with {} wins pot of ${}!",
-        winner_name,
-        winner_id,
-        hand_rank_name(&winner_evaluation.rank),
-        game_data.pot
-    );
-    
     // Advance dealer button for next hand
     game_position.advance_dealer_button();
 }
@@ -385,17 +573,214 @@ pub fn toggle_auto_advance(
         if controller.auto_advance {
             info!("Game auto-advance ENABLED (press SPACE to pause)");
         } else {
-            info!("Game auto-advance PAUSED (press SPACE to resume)");
+            info!("Game auto-advance PAUSED (press SPACE to resume, N to step)");
         }
     }
+    
+    // Step through the hand one phase at a time while paused
+    if input.just_pressed(KeyCode::KeyN) && !controller.auto_advance {
+        controller.step_requested = true;
+        info!("⏭️ Stepping to the next phase");
+    }
+}
+
+// Prompt shown once a single player has won every chip
+#[derive(Component)]
+pub struct PostGamePrompt;
+
+// System to show the "play again?" prompt while waiting on the player's choice
+pub fn show_post_game_prompt(
+    mut commands: Commands,
+    current_state: Res<State<GameState>>,
+    prompts: Query<Entity, With<PostGamePrompt>>,
+    leaderboard: Option<Res<crate::leaderboard::Leaderboard>>,
+    elimination_order: Option<Res<crate::tournament::EliminationOrder>>,
+    players: Query<&Player>,
+) {
+    if !current_state.is_changed() {
+        return;
+    }
+    
+    for entity in prompts.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if *current_state.get() != GameState::PostGameChoice {
+        return;
+    }
+    
+    let mut prompt = "🏁 Game over!\n".to_string();
+    let human_player = players.iter().find(|p| matches!(p.player_type, PlayerType::Human));
+    if let (Some(elimination_order), Some(human_player)) = (elimination_order, human_player) {
+        let total_players = players.iter().count();
+        let place = elimination_order.finishing_position(human_player.id, total_players).unwrap_or(1);
+        prompt.push_str(&format!("You finished {} of {}\n", crate::tournament::ordinal(place), total_players));
+    }
+    if let Some(leaderboard) = leaderboard {
+        prompt.push_str(&format!("{}\n", leaderboard.summary()));
+    }
+    prompt.push_str("ENTER - Play again\nS - Play again at the next AI difficulty\nX - Quit");
+    
+    commands.spawn((
+        TextBundle::from_section(
+            prompt,
+            TextStyle {
+                font_size: 24.0,
+                color: Color::srgb(1.0, 1.0, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(35.0),
+            top: Val::Percent(40.0),
+            ..default()
+        })
+        .with_background_color(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        PostGamePrompt,
+    ));
+}
+
+// System to act on the post-game choice: start a fresh game or quit
+pub fn handle_post_game_choice(
+    input: Res<ButtonInput<KeyCode>>,
+    current_state: Res<State<GameState>>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut players: Query<&mut Player>,
+    mut difficulty_cycle: ResMut<crate::ai_player::AIDifficultyCycle>,
+    mut app_exit: EventWriter<AppExit>,
+    game_config: Res<GameConfig>,
+) {
+    if *current_state.get() != GameState::PostGameChoice {
+        return;
+    }
+    
+    if input.just_pressed(KeyCode::KeyX) {
+        info!("👋 Thanks for playing!");
+        app_exit.send(AppExit::Success);
+        return;
+    }
+    
+    let new_settings = input.just_pressed(KeyCode::KeyS);
+    if !input.just_pressed(KeyCode::Enter) && !new_settings {
+        return;
+    }
+    if new_settings {
+        // Applied by apply_difficulty_cycle when the new game's first hand starts
+        difficulty_cycle.pending_steps += 1;
+    }
+    
+    let starting_chips = game_config.starting_chips();
+    for mut player in players.iter_mut() {
+        player.chips = starting_chips;
+        player.reset_for_new_hand();
+    }
+    info!("🔄 Starting new game! All players reset to ${} chips.", starting_chips);
+    game_state.set(GameState::Setup);
+}
+
+/// Check that no card appears twice across all hole cards and the board
+pub fn validate_no_duplicate_cards<'a>(
+    players: impl IntoIterator<Item = &'a Player>,
+    community_cards: &[Card],
+) -> Result<(), DuplicateCard> {
+    let mut seen = std::collections::HashSet::new();
+    let hole_cards = players.into_iter().flat_map(|player| player.hole_cards.iter());
+    for &card in hole_cards.chain(community_cards) {
+        if !seen.insert(card) {
+            return Err(DuplicateCard { card });
+        }
+    }
+    Ok(())
 }
 
-// Helper function to post blinds at the start of each hand
+// The AI table conceptually plays on after a fast fold: run out the board and award the
+// pot at showdown without any further betting
+fn settle_without_human(
+    deck: &mut Deck,
+    players: &mut Query<&mut Player>,
+    game_data: &mut GameData,
+    game_position: &mut GamePosition,
+    game_config: &GameConfig,
+) {
+    while game_data.community_cards.len() < 5 {
+        match deck.deal() {
+            Some(card) => game_data.community_cards.push(card),
+            None => break,
+        }
+    }
+    deck.assert_invariant(&dealt_cards(players, &game_data.community_cards));
+    determine_winner(players, game_data, game_position, game_config, None);
+}
+
+// Everyone paid from the pot, in payout order, and the size of the pot they shared
+fn hand_ended(game_data: &GameData) -> GameEvent {
+    GameEvent::HandEnded {
+        winners: game_data.pot_awards.iter().map(|&(player_id, _)| player_id).collect(),
+        pot: game_data.pot,
+    }
+}
+
+// How many seats clockwise from the dealer button a player sits: 0 is the seat to the button's left
+fn seats_left_of_button(player_id: u32, game_position: &GamePosition) -> u32 {
+    let total_players = game_position.total_players.max(1);
+    (player_id + total_players - game_position.dealer_button % total_players - 1) % total_players
+}
+
+// When a street closes, the part of the biggest bet nobody matched goes back to whoever bet it;
+// folded players' bets count, as they were matched before the fold. Returns who got how much
+fn return_uncalled_bet(players: &mut Query<&mut Player>, betting_round: &mut BettingRound) -> Option<(u32, u32)> {
+    let mut bets: Vec<(u32, u32)> = players.iter().map(|p| (p.current_bet, p.id)).collect();
+    bets.sort_by(|a, b| b.cmp(a));
+    let (bettor_id, uncalled) = match bets.as_slice() {
+        [(top, bettor_id), (second, _), ..] if top > second => (*bettor_id, top - second),
+        _ => return None,
+    };
+    
+    for mut player in players.iter_mut().filter(|p| p.id == bettor_id) {
+        player.chips += uncalled;
+        player.current_bet -= uncalled;
+    }
+    betting_round.pot = betting_round.pot.saturating_sub(uncalled);
+    info!("↩️ ${} uncalled bet returned to Player {}", uncalled, bettor_id);
+    Some((bettor_id, uncalled))
+}
+
+// Every card out of the deck: all hole cards plus the board
+fn dealt_cards(players: &Query<&mut Player>, community_cards: &[Card]) -> Vec<Card> {
+    players
+        .iter()
+        .flat_map(|player| player.hole_cards.iter().copied())
+        .chain(community_cards.iter().copied())
+        .collect()
+}
+
+// Open a new post-flop street: bets from the last street are already in the pot, so
+// everyone's current bet starts again from zero
+fn start_postflop_street(betting_round: &mut BettingRound, players: &mut Query<&mut Player>, game_position: &GamePosition) {
+    for mut player in players.iter_mut() {
+        player.committed += player.current_bet;
+        player.current_bet = 0;
+    }
+    betting_round.reset_for_new_round(postflop_order(game_position, players));
+}
+
+// Post-flop streets start with the first active player left of the button
+fn postflop_order(game_position: &GamePosition, players: &Query<&mut Player>) -> Vec<u32> {
+    game_position
+        .get_betting_order(false)
+        .into_iter()
+        .filter(|id| players.iter().any(|p| p.id == *id && !p.has_folded))
+        .collect()
+}
+
+// Helper function to post blinds at the start of each hand.
+// Returns the amount players must call pre-flop and the straddler, if one posted.
 fn post_blinds(
     players: &mut Query<&mut Player>,
     game_position: &GamePosition,
     game_data: &mut GameData,
-) {
+    game_config: &GameConfig,
+) -> (u32, Option<u32>) {
     let small_blind_player = game_position.get_small_blind_player();
     let big_blind_player = game_position.get_big_blind_player();
     
@@ -408,6 +793,7 @@ fn post_blinds(
         if player.id == small_blind_player {
             let blind_amount = game_position.small_blind_amount.min(player.chips);
             player.chips = player.chips.saturating_sub(blind_amount);
+            player.current_bet = blind_amount;
             game_data.pot += blind_amount;
             info!("🔸 Player {} posts small blind: {} chips (remaining: {})", 
                   player.id, blind_amount, player.chips);
@@ -420,6 +806,7 @@ fn post_blinds(
         if player.id == big_blind_player {
             let blind_amount = game_position.big_blind_amount.min(player.chips);
             player.chips = player.chips.saturating_sub(blind_amount);
+            player.current_bet = blind_amount;
             game_data.pot += blind_amount;
             info!("🔹 Player {} posts big blind: {} chips (remaining: {})", 
                   player.id, blind_amount, player.chips);
@@ -427,5 +814,935 @@ fn post_blinds(
         }
     }
     
+    let mut opening_bet = game_position.big_blind_amount;
+    let mut straddler = None;
+    
+    // Post straddle (2x big blind) - needs a seat distinct from both blinds
+    if game_config.allow_straddle && game_position.total_players >= 3 {
+        let straddle_player = game_position.get_straddle_player();
+        let straddle_amount = game_position.big_blind_amount * 2;
+        for mut player in players.iter_mut() {
+            if player.id == straddle_player && player.chips >= straddle_amount {
+                player.chips -= straddle_amount;
+                player.current_bet = straddle_amount;
+                game_data.pot += straddle_amount;
+                opening_bet = straddle_amount;
+                straddler = Some(straddle_player);
+                info!("🔺 Player {} posts straddle: {} chips (remaining: {})", 
+                      player.id, straddle_amount, player.chips);
+                break;
+            }
+        }
+    }
+    
     info!("💰 Total pot after blinds: {} chips", game_data.pot);
+    (opening_bet, straddler)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::betting::PlayerAction;
+    use crate::test_support::{build_test_app, spawn_simple_ai_players, deal_hole_cards};
+    use crate::game_config::GameConfig;
+    
+    #[test]
+    fn test_player_bets_reset_when_flop_starts() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Dealing);
+        
+        // Play pre-flop until the flop is dealt, noting the pot as pre-flop betting ends
+        let mut preflop_pot = None;
+        for _ in 0..20 {
+            app.update();
+            let state = *app.world().resource::<State<GameState>>().get();
+            if state == GameState::PreFlop {
+                let mut players = app.world_mut().query::<&Player>();
+                assert!(players.iter(app.world()).any(|p| p.current_bet > 0), "blinds are posted pre-flop");
+                preflop_pot = Some(app.world().resource::<BettingRound>().pot);
+            }
+            if state == GameState::Flop && app.world().resource::<GameData>().community_cards.len() == 3 {
+                break;
+            }
+        }
+        
+        let mut players = app.world_mut().query::<&Player>();
+        assert!(players.iter(app.world()).all(|p| p.current_bet == 0));
+        let betting_round = app.world().resource::<BettingRound>();
+        assert_eq!(betting_round.current_bet, 0);
+        assert_eq!(Some(betting_round.pot), preflop_pot);
+        assert_eq!(app.world().resource::<GameData>().pot, betting_round.pot);
+    }
+    
+    #[test]
+    fn test_bets_reset_on_each_new_street_while_stacks_keep_what_was_paid() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        
+        // Check the table as the flop and then the turn are dealt
+        let mut streets_checked = Vec::new();
+        for _ in 0..40 {
+            app.update();
+            let board_size = app.world().resource::<GameData>().community_cards.len();
+            if !matches!(board_size, 3 | 4) || streets_checked.contains(&board_size) {
+                continue;
+            }
+            streets_checked.push(board_size);
+            
+            let pot = app.world().resource::<BettingRound>().pot;
+            let mut players = app.world_mut().query::<&Player>();
+            for player in players.iter(app.world()) {
+                assert_eq!(player.current_bet, 0, "player {} on a {}-card board", player.id, board_size);
+                assert_eq!(player.chips + player.committed, 1000, "player {} on a {}-card board", player.id, board_size);
+            }
+            let committed: u32 = players.iter(app.world()).map(|p| p.committed).sum();
+            assert_eq!(committed, pot);
+            if board_size == 4 {
+                break;
+            }
+        }
+        assert_eq!(streets_checked, vec![3, 4]);
+    }
+    
+    #[test]
+    fn test_betting_round_pot_starts_with_posted_blinds() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Dealing);
+        
+        for _ in 0..5 {
+            app.update();
+            if *app.world().resource::<State<GameState>>().get() == GameState::PreFlop {
+                break;
+            }
+        }
+        
+        // Everything put in pre-flop is in the pot: the blinds plus any action since
+        let game_position = app.world().resource::<GamePosition>();
+        let blinds = game_position.small_blind_amount + game_position.big_blind_amount;
+        let mut players = app.world_mut().query::<&Player>();
+        let committed: u32 = players.iter(app.world()).map(|p| p.current_bet).sum();
+        let betting_round = app.world().resource::<BettingRound>();
+        assert!(betting_round.pot >= blinds, "pot {} is missing the blinds", betting_round.pot);
+        assert_eq!(betting_round.pot, committed);
+    }
+    
+    #[test]
+    fn test_checked_around_flop_advances_to_turn() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        deal_hole_cards(&mut app);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Flop);
+        
+        // Run until the flop is dealt and everyone has checked
+        for _ in 0..10 {
+            app.update();
+            let betting_round = app.world().resource::<BettingRound>();
+            if app.world().resource::<GameData>().community_cards.len() == 3 && betting_round.is_complete() {
+                break;
+            }
+        }
+        
+        let history = app.world().resource::<BettingRound>().action_history.clone();
+        assert_eq!(history.len(), 3, "each player should be prompted exactly once");
+        assert!(history.iter().all(|(_, action)| *action == PlayerAction::Check));
+        let mut actors: Vec<u32> = history.iter().map(|(id, _)| *id).collect();
+        actors.sort();
+        assert_eq!(actors, vec![0, 1, 2]);
+        
+        // The controller moves on to the turn instead of hanging on the flop
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Turn);
+        assert_eq!(app.world().resource::<GameData>().community_cards.len(), 4);
+    }
+    
+    #[test]
+    fn test_straddle_posted_and_straddler_acts_last_preflop() {
+        let mut app = build_test_app();
+        app.insert_resource(GameConfig { allow_straddle: true, ..default() });
+        spawn_simple_ai_players(&mut app, 4);
+        app.world_mut().resource_mut::<GamePosition>().total_players = 4;
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Dealing);
+        // The game clock steps on every update, so one update runs the Dealing step
+        app.update();
+        
+        // Dealer 0: SB 1, BB 2, straddle 3
+        let straddler = app.world().resource::<GamePosition>().get_straddle_player();
+        assert_eq!(straddler, 3);
+        
+        let mut players = app.world_mut().query::<&Player>();
+        let straddle_player = players.iter(app.world()).find(|p| p.id == straddler).unwrap();
+        assert_eq!(straddle_player.current_bet, 40);
+        assert_eq!(straddle_player.chips, 960);
+        
+        let betting_round = app.world().resource::<BettingRound>();
+        assert_eq!(betting_round.current_bet, 40);
+        assert_eq!(betting_round.players_to_act.last(), Some(&straddler));
+        assert_eq!(betting_round.peek_next_player(), Some(0));
+    }
+    
+    #[test]
+    fn test_ai_only_hand_runs_to_completion() {
+        use crate::ai_player::{AIPlayerComponent, AIPersonality};
+        use crate::betting_ui::{self, HumanPlayerInput};
+        use crate::teaching::{self, TeachingState};
+        
+        let mut app = build_test_app();
+        app.init_resource::<TeachingState>().add_systems(
+            Update,
+            (
+                teaching::highlight_valid_actions,
+                teaching::provide_hand_analysis,
+                betting_ui::reset_raise_amount_on_new_hand,
+            ),
+        );
+        for id in 0..3 {
+            app.world_mut().spawn((
+                Player::new(id, PlayerType::AI, 1000, Vec3::ZERO),
+                AIPlayerComponent { personality: AIPersonality::intermediate() },
+            ));
+        }
+        app.world_mut().resource_mut::<HumanPlayerInput>().raise_amount = 55;
+        
+        let mut reached_game_over = false;
+        for _ in 0..200 {
+            app.update();
+            if *app.world().resource::<State<GameState>>().get() == GameState::GameOver {
+                reached_game_over = true;
+                break;
+            }
+        }
+        
+        assert!(reached_game_over, "an AI-only hand should reach GameOver");
+        // Human-only state is left untouched
+        let human_input = app.world().resource::<HumanPlayerInput>();
+        assert_eq!(human_input.raise_amount, 55);
+        assert!(human_input.pending_action.is_none());
+        let teaching_state = app.world().resource::<TeachingState>();
+        assert!(teaching_state.last_game_state.is_none());
+    }
+    
+    #[test]
+    fn test_game_clock_steps_a_full_hand_through_the_same_phases_every_run() {
+        // The phase the table is in after each update, from the deal to the end of the hand
+        let phase_log = || {
+            let mut app = build_test_app();
+            spawn_simple_ai_players(&mut app, 3);
+            let mut phases = Vec::new();
+            for _ in 0..40 {
+                app.update();
+                let phase = *app.world().resource::<State<GameState>>().get();
+                phases.push(phase);
+                if phase == GameState::GameOver {
+                    break;
+                }
+            }
+            phases
+        };
+        
+        let first_run = phase_log();
+        assert_eq!(first_run, phase_log(), "the same clock steps should give the same transitions");
+        
+        let mut distinct = first_run.clone();
+        distinct.dedup();
+        assert_eq!(
+            distinct,
+            vec![
+                GameState::Setup,
+                GameState::Dealing,
+                GameState::PreFlop,
+                GameState::Flop,
+                GameState::Turn,
+                GameState::River,
+                GameState::Showdown,
+                GameState::GameOver,
+            ]
+        );
+    }
+    
+    #[test]
+    fn test_hand_emits_start_actions_and_end_events_in_order() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        let mut reader = app.world().resource::<Events<GameEvent>>().get_reader();
+        
+        let mut events = Vec::new();
+        for _ in 0..40 {
+            app.update();
+            events.extend(reader.read(app.world().resource::<Events<GameEvent>>()).cloned());
+            if *app.world().resource::<State<GameState>>().get() == GameState::GameOver {
+                break;
+            }
+        }
+        
+        let position = |matches: fn(&GameEvent) -> bool| events.iter().position(matches);
+        let started = position(|event| *event == GameEvent::HandStarted).expect("HandStarted");
+        let dealt = position(|event| *event == GameEvent::CardsDealt).expect("CardsDealt");
+        let first_action = position(|event| matches!(event, GameEvent::Action { .. })).expect("an Action");
+        let flop = position(|event| *event == GameEvent::StreetDealt(GameState::Flop)).expect("the flop");
+        let ended = position(|event| matches!(event, GameEvent::HandEnded { .. })).expect("HandEnded");
+        assert!(started < dealt && dealt < first_action && first_action < flop && flop < ended, "{:?}", events);
+        
+        let GameEvent::HandEnded { winners, pot } = &events[ended] else { unreachable!() };
+        assert!(!winners.is_empty());
+        assert!(*pot > 0, "the blinds alone make a pot");
+    }
+    
+    #[test]
+    fn test_three_handed_hand_acts_in_position_order() {
+        let mut app = build_test_app();
+        app.world_mut().resource_mut::<GameController>().deck_seed = Some(3);
+        spawn_simple_ai_players(&mut app, 3);
+        let mut reader = app.world().resource::<Events<GameEvent>>().get_reader();
+        
+        // The button moves on once the hand is over, so note where it was for this one
+        let mut events = Vec::new();
+        let mut dealer_button = None;
+        for _ in 0..40 {
+            app.update();
+            events.extend(reader.read(app.world().resource::<Events<GameEvent>>()).cloned());
+            let state = *app.world().resource::<State<GameState>>().get();
+            if state == GameState::PreFlop && dealer_button.is_none() {
+                dealer_button = Some(app.world().resource::<GamePosition>().dealer_button);
+            }
+            if state == GameState::GameOver {
+                break;
+            }
+        }
+        let position = GamePosition { dealer_button: dealer_button.expect("reached pre-flop"), ..default() };
+        
+        // Who acted on each street, split at the cards that opened the next one
+        let mut streets: Vec<Vec<(u32, PlayerAction)>> = vec![Vec::new()];
+        for event in &events {
+            match event {
+                GameEvent::StreetDealt(_) => streets.push(Vec::new()),
+                GameEvent::Action { player, action } => streets.last_mut().unwrap().push((*player, action.clone())),
+                _ => {},
+            }
+        }
+        
+        // Pre-flop: under the gun first and the big blind last, whatever anyone does
+        let preflop: Vec<u32> = streets[0].iter().map(|(player, _)| *player).collect();
+        let preflop_order = position.get_betting_order(true);
+        assert_eq!(preflop_order.last(), Some(&position.get_big_blind_player()));
+        let first_orbit = preflop.len().min(3);
+        assert_eq!(preflop[..first_orbit], preflop_order[..first_orbit], "{:?}", events);
+        
+        // Post-flop: the first player left of the button still in the hand opens the action
+        let folded: Vec<u32> = streets[0].iter().filter(|(_, action)| *action == PlayerAction::Fold).map(|(player, _)| *player).collect();
+        if let Some(&(first_postflop, _)) = streets.get(1).and_then(|flop| flop.first()) {
+            let expected = position.get_betting_order(false).into_iter().find(|id| !folded.contains(id));
+            assert_eq!(Some(first_postflop), expected, "{:?}", events);
+        }
+    }
+    
+    #[test]
+    fn test_seeded_controller_deals_the_same_hand_every_time() {
+        let dealt_hands = || {
+            let mut app = build_test_app();
+            app.world_mut().resource_mut::<GameController>().deck_seed = Some(11);
+            spawn_simple_ai_players(&mut app, 3);
+            while *app.world().resource::<State<GameState>>().get() != GameState::PreFlop {
+                app.update();
+            }
+            let mut players = app.world_mut().query::<&Player>();
+            let mut hands: Vec<(u32, Vec<Card>)> = players.iter(app.world()).map(|p| (p.id, p.hole_cards.clone())).collect();
+            hands.sort_by_key(|(id, _)| *id);
+            hands
+        };
+        
+        let first = dealt_hands();
+        assert!(first.iter().all(|(_, hole_cards)| hole_cards.len() == 2));
+        assert_eq!(first, dealt_hands());
+    }
+    
+    #[test]
+    fn test_chip_stacks_carry_over_into_next_hand() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        
+        let mut after_first_hand: Option<Vec<(u32, u32)>> = None;
+        let mut first_round_number = 0;
+        let mut reached_second_hand = false;
+        for _ in 0..200 {
+            app.update();
+            let state = *app.world().resource::<State<GameState>>().get();
+            if state == GameState::GameOver && after_first_hand.is_none() {
+                let mut players = app.world_mut().query::<&Player>();
+                let mut stacks: Vec<(u32, u32)> = players.iter(app.world()).map(|p| (p.id, p.chips)).collect();
+                stacks.sort();
+                after_first_hand = Some(stacks);
+                first_round_number = app.world().resource::<GameData>().round_number;
+            } else if state == GameState::Setup && after_first_hand.is_some() {
+                reached_second_hand = true;
+                break;
+            }
+        }
+        
+        assert!(reached_second_hand, "a second hand should start after GameOver");
+        let after_first_hand = after_first_hand.unwrap();
+        assert!(after_first_hand.iter().any(|&(_, chips)| chips != 1000), "the first hand should move chips");
+        
+        // The new hand starts from the first hand's stacks with per-hand state cleared
+        let mut players = app.world_mut().query::<&Player>();
+        let mut players: Vec<Player> = players.iter(app.world()).cloned().collect();
+        players.sort_by_key(|p| p.id);
+        let stacks: Vec<(u32, u32)> = players.iter().map(|p| (p.id, p.chips)).collect();
+        assert_eq!(stacks, after_first_hand);
+        for player in &players {
+            assert!(player.hole_cards.is_empty());
+            assert!(!player.has_folded);
+            assert_eq!(player.current_bet, 0);
+        }
+        let game_data = app.world().resource::<GameData>();
+        assert_eq!(game_data.round_number, first_round_number + 1);
+        assert_eq!(game_data.pot, 0);
+    }
+    
+    #[test]
+    fn test_deck_is_full_again_when_the_next_hand_starts() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        
+        let mut finished_hand = false;
+        let mut next_hand_deck = None;
+        for _ in 0..200 {
+            app.update();
+            let state = *app.world().resource::<State<GameState>>().get();
+            if state == GameState::GameOver {
+                finished_hand = true;
+                assert!(app.world().resource::<Deck>().cards.len() < FULL_DECK_SIZE);
+            } else if state == GameState::Setup && finished_hand {
+                // Setup reshuffles as it starts; nothing is dealt until the Dealing step runs
+                next_hand_deck = Some(app.world().resource::<Deck>().cards.clone());
+                break;
+            }
+        }
+        
+        let deck = Deck { cards: next_hand_deck.expect("a second hand should start after GameOver") };
+        assert_eq!(deck.cards.len(), FULL_DECK_SIZE);
+        assert_eq!(deck.check_invariant(&[]), Ok(()));
+    }
+    
+    #[test]
+    fn test_fast_fold_redeals_instead_of_waiting_for_showdown() {
+        use crate::betting::PlayerAction;
+        use crate::betting_ui::HumanPlayerInput;
+        
+        let mut app = build_test_app();
+        app.world_mut().resource_mut::<GameConfig>().fast_fold = true;
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        for id in 1..3 {
+            app.world_mut().spawn(Player::new(id, PlayerType::AI, 1000, Vec3::ZERO));
+        }
+        
+        let mut states = Vec::new();
+        let mut folded_in_round = None;
+        for _ in 0..30 {
+            app.update();
+            let state = *app.world().resource::<State<GameState>>().get();
+            states.push(state);
+            
+            let round_number = app.world().resource::<GameData>().round_number;
+            if folded_in_round.is_some_and(|round| round_number > round) {
+                break;
+            }
+            if state == GameState::PreFlop && app.world().resource::<BettingRound>().peek_next_player() == Some(0) {
+                app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = Some(PlayerAction::Fold);
+                folded_in_round = Some(round_number);
+            }
+        }
+        
+        let folded_in_round = folded_in_round.expect("the human should get a turn pre-flop");
+        assert_eq!(app.world().resource::<GameData>().round_number, folded_in_round + 1, "the human should be dealt a new hand");
+        
+        // Straight from pre-flop to the end of the hand, without the flop or showdown; GameOver
+        // still runs so busts, streaks and the recap are recorded
+        let fold_index = states.iter().position(|&state| state == GameState::PreFlop).unwrap();
+        let mut later_states = states[fold_index..].iter().filter(|&&state| state != GameState::PreFlop);
+        assert_eq!(later_states.next(), Some(&GameState::GameOver), "fast fold shouldn't wait for showdown: {:?}", states);
+        assert_eq!(later_states.find(|&&state| state != GameState::GameOver), Some(&GameState::Setup));
+    }
+    
+    #[test]
+    fn test_total_victory_prompts_and_play_again_starts_new_game() {
+        use crate::ai_player::AIDifficultyCycle;
+        
+        let mut app = build_test_app();
+        app.init_resource::<AIDifficultyCycle>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, handle_post_game_choice);
+        spawn_simple_ai_players(&mut app, 2);
+        {
+            let world = app.world_mut();
+            let mut players = world.query::<&mut Player>();
+            for mut player in players.iter_mut(world) {
+                player.chips = if player.id == 0 { 2000 } else { 0 };
+            }
+        }
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::GameOver);
+        
+        // The game waits at the prompt instead of resetting on its own
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::PostGameChoice);
+        
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().reset_all();
+        app.update();
+        
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Setup);
+        let mut players = app.world_mut().query::<&Player>();
+        assert!(players.iter(app.world()).all(|p| p.chips == 1000));
+    }
+    
+    #[test]
+    fn test_auto_muck_hides_losing_ai_cards_at_showdown() {
+        use crate::cards::{Card, Suit, Rank};
+        use crate::rendering::{self, CardBack};
+        
+        let mut app = build_test_app();
+        app.insert_resource(GameConfig { auto_muck_losing_hands: true, ..default() })
+            .init_resource::<crate::teaching::TeachingState>()
+            .add_systems(Update, rendering::render_face_down_cards);
+        
+        let winner = app.world_mut().spawn(Player::new(0, PlayerType::AI, 1000, Vec3::ZERO)).id();
+        let loser = app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO)).id();
+        app.world_mut().get_mut::<Player>(winner).unwrap().hole_cards =
+            vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Spades, Rank::Ace)];
+        app.world_mut().get_mut::<Player>(loser).unwrap().hole_cards =
+            vec![Card::new(Suit::Hearts, Rank::Seven), Card::new(Suit::Clubs, Rank::Two)];
+        app.world_mut().resource_mut::<GameData>().community_cards = vec![
+            Card::new(Suit::Diamonds, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Diamonds, Rank::Four),
+            Card::new(Suit::Hearts, Rank::Jack),
+        ];
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Showdown);
+        
+        for _ in 0..5 {
+            app.update();
+            if *app.world().resource::<State<GameState>>().get() == GameState::GameOver {
+                break;
+            }
+        }
+        
+        assert_eq!(app.world().resource::<GameData>().showdown_reveals, vec![0]);
+        // Only the mucked loser keeps face-down cards
+        let mut card_backs = app.world_mut().query_filtered::<Entity, With<CardBack>>();
+        assert_eq!(card_backs.iter(app.world()).count(), 2);
+    }
+    
+    #[test]
+    fn test_human_auto_muck_keeps_a_losing_passive_hand_face_down() {
+        use crate::cards::{Card, Suit, Rank};
+        use crate::rendering::{self, CardBack};
+        
+        let showdown = |human_auto_muck: bool, human_raised: bool| {
+            let mut app = build_test_app();
+            app.insert_resource(GameConfig { human_auto_muck, ..default() })
+                .init_resource::<crate::teaching::TeachingState>()
+                .add_systems(Update, rendering::render_face_down_cards);
+            
+            let winner = app.world_mut().spawn(Player::new(0, PlayerType::AI, 1000, Vec3::ZERO)).id();
+            let human = app.world_mut().spawn(Player::new(1, PlayerType::Human, 1000, Vec3::ZERO)).id();
+            app.world_mut().get_mut::<Player>(winner).unwrap().hole_cards =
+                vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Spades, Rank::Ace)];
+            app.world_mut().get_mut::<Player>(human).unwrap().hole_cards =
+                vec![Card::new(Suit::Hearts, Rank::Seven), Card::new(Suit::Clubs, Rank::Two)];
+            app.world_mut().resource_mut::<GameData>().community_cards = vec![
+                Card::new(Suit::Diamonds, Rank::Ace),
+                Card::new(Suit::Clubs, Rank::King),
+                Card::new(Suit::Spades, Rank::Nine),
+                Card::new(Suit::Diamonds, Rank::Four),
+                Card::new(Suit::Hearts, Rank::Jack),
+            ];
+            // The river's action: the human either checked and called, or bet and got called
+            app.world_mut().resource_mut::<BettingRound>().action_history = if human_raised {
+                vec![(1, PlayerAction::Raise(50)), (0, PlayerAction::Call)]
+            } else {
+                vec![(1, PlayerAction::Check), (0, PlayerAction::Raise(50)), (1, PlayerAction::Call)]
+            };
+            app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Showdown);
+            for _ in 0..5 {
+                app.update();
+                if *app.world().resource::<State<GameState>>().get() == GameState::GameOver {
+                    break;
+                }
+            }
+            
+            let mut card_backs = app.world_mut().query_filtered::<Entity, With<CardBack>>();
+            (app.world().resource::<GameData>().showdown_reveals.clone(), card_backs.iter(app.world()).count())
+        };
+        
+        // Mucked: the losing human's two cards are covered, and the winner's are shown
+        assert_eq!(showdown(true, false), (vec![0], 2));
+        // Always show (the default) leaves the loss face-up to learn from
+        assert_eq!(showdown(false, false), (vec![0, 1], 0));
+        // The aggressor has to show even with auto-muck on
+        assert_eq!(showdown(true, true), (vec![0, 1], 0));
+    }
+    
+    #[test]
+    fn test_showdown_pacing_sets_how_many_updates_the_reveal_takes() {
+        use crate::game_config::ShowdownPacing;
+        
+        // Updates spent in Showdown after river betting closes with every player still in
+        let showdown_updates = |pacing: ShowdownPacing, player_count: u32| {
+            let mut app = build_test_app();
+            app.insert_resource(GameConfig { showdown_pacing: pacing, ..default() });
+            spawn_simple_ai_players(&mut app, player_count);
+            deal_hole_cards(&mut app);
+            
+            let board: Vec<Card> = app.world_mut().resource_mut::<Deck>().cards.drain(..5).collect();
+            app.world_mut().resource_mut::<GameData>().community_cards = board;
+            app.world_mut().resource_mut::<BettingRound>().betting_complete = true;
+            app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::River);
+            app.update();
+            
+            let mut updates = 0;
+            for _ in 0..20 {
+                app.update();
+                match *app.world().resource::<State<GameState>>().get() {
+                    GameState::Showdown => updates += 1,
+                    GameState::GameOver => break,
+                    _ => {},
+                }
+            }
+            updates
+        };
+        
+        assert_eq!(showdown_updates(ShowdownPacing::Instant, 3), 1);
+        assert_eq!(showdown_updates(ShowdownPacing::Instant, 5), 1);
+        
+        // The test app advances 3s per update, so 3s per hand shown is one update per player
+        let paced = ShowdownPacing::Paced { per_player_secs: 3.0 };
+        assert_eq!(showdown_updates(paced, 2), 2);
+        assert_eq!(showdown_updates(paced, 4), 4);
+    }
+    
+    #[test]
+    fn test_player_without_hole_cards_does_not_stop_the_pot_being_awarded() {
+        use crate::cards::{Rank, Suit};
+        
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        app.world_mut().resource_mut::<GameData>().community_cards = vec![
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Spades, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::Four),
+        ];
+        app.world_mut().resource_mut::<GameData>().pot = 300;
+        
+        // Fixed hands so there's one clear winner; a dealing bug left player 1 in the hand with no cards
+        let mut players = app.world_mut().query::<&mut Player>();
+        for mut player in players.iter_mut(app.world_mut()) {
+            player.hole_cards = match player.id {
+                0 => vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::Ace)],
+                2 => vec![Card::new(Suit::Spades, Rank::King), Card::new(Suit::Hearts, Rank::King)],
+                _ => Vec::new(),
+            };
+        }
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Showdown);
+        app.update();
+        app.update();
+        
+        let pot_awards = app.world().resource::<GameData>().pot_awards.clone();
+        assert_eq!(pot_awards.len(), 1);
+        let (winner_id, amount) = pot_awards[0];
+        assert!(winner_id == 0 || winner_id == 2, "pot went to player {}", winner_id);
+        assert_eq!(amount, 300);
+        let mut players = app.world_mut().query::<&Player>();
+        let winner = players.iter(app.world()).find(|p| p.id == winner_id).unwrap();
+        assert_eq!(winner.chips, 1300);
+        
+        // With nobody holding cards the pot is left alone rather than panicking
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 2);
+        app.world_mut().resource_mut::<GameData>().pot = 300;
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Showdown);
+        app.update();
+        app.update();
+        assert!(app.world().resource::<GameData>().pot_awards.is_empty());
+        let mut players = app.world_mut().query::<&Player>();
+        assert!(players.iter(app.world()).all(|p| p.chips == 1000));
+    }
+    
+    #[test]
+    fn test_uncalled_overbet_is_returned_before_the_pot_is_awarded() {
+        use crate::cards::{Rank, Suit};
+        
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 2);
+        app.world_mut().resource_mut::<GameData>().community_cards = vec![
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Spades, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::Four),
+        ];
+        
+        // Player 0 shoves 500 on the river; player 1 can only call 200 all-in, and holds the better hand
+        let mut players = app.world_mut().query::<&mut Player>();
+        for mut player in players.iter_mut(app.world_mut()) {
+            if player.id == 0 {
+                player.hole_cards = vec![Card::new(Suit::Hearts, Rank::King), Card::new(Suit::Hearts, Rank::Queen)];
+                player.current_bet = 500;
+                player.chips = 500;
+            } else {
+                player.hole_cards = vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Diamonds, Rank::Ace)];
+                player.current_bet = 200;
+                player.chips = 0;
+            }
+        }
+        app.world_mut().resource_mut::<BettingRound>().pot = 100 + 500 + 200;
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::River);
+        app.update();
+        app.update();
+        app.update();
+        
+        // Only the matched 200 each (plus the 100 already in) is contested
+        assert_eq!(app.world().resource::<GameData>().pot_awards, vec![(1, 500)]);
+        let mut players = app.world_mut().query::<&Player>();
+        let chips: Vec<(u32, u32)> = players.iter(app.world()).map(|p| (p.id, p.chips)).collect();
+        assert!(chips.contains(&(0, 800)), "{:?}", chips);
+        assert!(chips.contains(&(1, 500)), "{:?}", chips);
+    }
+    
+    #[test]
+    fn test_each_side_pot_goes_to_the_best_hand_eligible_for_it() {
+        use crate::cards::{Rank, Suit};
+        
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        app.world_mut().resource_mut::<GameData>().community_cards = vec![
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Spades, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::Four),
+        ];
+        
+        // All-ins for 50, 150 and 400; the shortest stack holds the best hand, the middle one the next best
+        let hands = [
+            [Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Diamonds, Rank::Ace)],
+            [Card::new(Suit::Hearts, Rank::King), Card::new(Suit::Diamonds, Rank::King)],
+            [Card::new(Suit::Hearts, Rank::Queen), Card::new(Suit::Diamonds, Rank::Queen)],
+        ];
+        let mut players = app.world_mut().query::<&mut Player>();
+        for mut player in players.iter_mut(app.world_mut()) {
+            player.hole_cards = hands[player.id as usize].to_vec();
+            player.current_bet = [50, 150, 400][player.id as usize];
+            player.chips = 0;
+        }
+        app.world_mut().resource_mut::<BettingRound>().pot = 600;
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::River);
+        app.update();
+        app.update();
+        app.update();
+        
+        // Main pot $150 to player 0, side pot $200 to player 1, and the unmatched $250 back to player 2
+        assert_eq!(app.world().resource::<GameData>().pot_awards, vec![(0, 150), (1, 200)]);
+        let mut players = app.world_mut().query::<&Player>();
+        let mut chips: Vec<(u32, u32)> = players.iter(app.world()).map(|p| (p.id, p.chips)).collect();
+        chips.sort();
+        assert_eq!(chips, vec![(0, 150), (1, 200), (2, 250)]);
+    }
+    
+    #[test]
+    fn test_board_playing_for_both_hands_splits_the_pot() {
+        use crate::cards::{Rank, Suit};
+        
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        // Broadway on the board: nobody's hole cards can improve it
+        app.world_mut().resource_mut::<GameData>().community_cards = vec![
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::King),
+            Card::new(Suit::Hearts, Rank::Queen),
+            Card::new(Suit::Spades, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::Ten),
+        ];
+        app.world_mut().resource_mut::<GamePosition>().dealer_button = 1;
+        
+        // Player 0 folded after putting in 25; players 1 and 2 called 50 each, for an odd $125 pot
+        let mut players = app.world_mut().query::<&mut Player>();
+        for mut player in players.iter_mut(app.world_mut()) {
+            player.chips = 500;
+            match player.id {
+                0 => {
+                    player.hole_cards = vec![Card::new(Suit::Hearts, Rank::Two), Card::new(Suit::Diamonds, Rank::Three)];
+                    player.current_bet = 25;
+                    player.has_folded = true;
+                },
+                1 => {
+                    player.hole_cards = vec![Card::new(Suit::Hearts, Rank::Four), Card::new(Suit::Diamonds, Rank::Five)];
+                    player.current_bet = 50;
+                },
+                _ => {
+                    player.hole_cards = vec![Card::new(Suit::Spades, Rank::Six), Card::new(Suit::Clubs, Rank::Seven)];
+                    player.current_bet = 50;
+                },
+            }
+        }
+        app.world_mut().resource_mut::<BettingRound>().pot = 125;
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::River);
+        app.update();
+        app.update();
+        app.update();
+        
+        // Half each, with the odd chip to player 2, first to the left of the button
+        let mut awards = app.world().resource::<GameData>().pot_awards.clone();
+        awards.sort();
+        assert_eq!(awards, vec![(1, 62), (2, 63)]);
+        let mut players = app.world_mut().query::<&Player>();
+        let mut chips: Vec<(u32, u32)> = players.iter(app.world()).map(|p| (p.id, p.chips)).collect();
+        chips.sort();
+        assert_eq!(chips, vec![(0, 500), (1, 562), (2, 563)]);
+    }
+    
+    #[test]
+    fn test_river_scenario_starts_with_full_board_and_human_cards() {
+        use crate::cards::{Card, Rank, Suit};
+        use crate::game_config::ScenarioConfig;
+        
+        let hole_cards = vec![
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Spades, Rank::King),
+        ];
+        let board = vec![
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Diamonds, Rank::Four),
+        ];
+        
+        let mut app = build_test_app();
+        app.insert_resource(GameConfig {
+            scenario: Some(ScenarioConfig {
+                hole_cards: hole_cards.clone(),
+                board: board.clone(),
+                start_street: GameState::River,
+            }),
+            ..default()
+        });
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(2, PlayerType::AI, 1000, Vec3::ZERO));
+        
+        for _ in 0..10 {
+            app.update();
+            if *app.world().resource::<State<GameState>>().get() == GameState::River {
+                break;
+            }
+        }
+        
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::River);
+        assert_eq!(app.world().resource::<GameData>().community_cards, board);
+        
+        let mut players = app.world_mut().query::<&Player>();
+        let human = players.iter(app.world()).find(|p| p.id == 0).unwrap();
+        assert_eq!(human.hole_cards, hole_cards);
+        
+        // Nobody else was dealt one of the scenario's cards
+        for player in players.iter(app.world()).filter(|p| p.id != 0) {
+            assert_eq!(player.hole_cards.len(), 2);
+            assert!(player.hole_cards.iter().all(|card| !hole_cards.contains(card) && !board.contains(card)));
+        }
+    }
+    
+    #[test]
+    fn test_postflop_action_starts_left_of_button() {
+        let mut app = build_test_app();
+        spawn_simple_ai_players(&mut app, 3);
+        deal_hole_cards(&mut app);
+        // Button on seat 1 puts the small blind on seat 2
+        app.world_mut().resource_mut::<GamePosition>().dealer_button = 1;
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Flop);
+        
+        for _ in 0..10 {
+            app.update();
+            let betting_round = app.world().resource::<BettingRound>();
+            if app.world().resource::<GameData>().community_cards.len() == 3 && betting_round.is_complete() {
+                break;
+            }
+        }
+        let actors: Vec<u32> = app.world().resource::<BettingRound>().action_history.iter().map(|(id, _)| *id).collect();
+        assert_eq!(actors, vec![2, 0, 1]);
+        
+        // Once the small blind has folded, the next active seat opens the turn
+        let mut players = app.world_mut().query::<&mut Player>();
+        for mut player in players.iter_mut(app.world_mut()) {
+            if player.id == 2 {
+                player.has_folded = true;
+            }
+        }
+        for _ in 0..10 {
+            app.update();
+            let betting_round = app.world().resource::<BettingRound>();
+            if app.world().resource::<GameData>().community_cards.len() == 4 && betting_round.is_complete() {
+                break;
+            }
+        }
+        let actors: Vec<u32> = app.world().resource::<BettingRound>().action_history.iter().map(|(id, _)| *id).collect();
+        assert_eq!(actors, vec![0, 1]);
+    }
+    
+    #[test]
+    fn test_step_key_advances_one_phase_per_press() {
+        let mut app = build_test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, toggle_auto_advance);
+        spawn_simple_ai_players(&mut app, 3);
+        app.world_mut().resource_mut::<GameController>().auto_advance = false;
+        
+        // Paused: nothing moves without a step
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Setup);
+        
+        for expected in [GameState::Dealing, GameState::PreFlop, GameState::Flop] {
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyN);
+            app.update();
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().reset_all();
+            for _ in 0..8 {
+                app.update();
+            }
+            assert_eq!(*app.world().resource::<State<GameState>>().get(), expected);
+        }
+    }
+    
+    #[test]
+    fn test_duplicate_ace_of_spades_is_detected() {
+        use crate::cards::{Card, Rank, Suit};
+        
+        let ace_of_spades = Card::new(Suit::Spades, Rank::Ace);
+        let mut human = Player::new(0, PlayerType::Human, 1000, Vec3::ZERO);
+        human.hole_cards = vec![ace_of_spades, Card::new(Suit::Hearts, Rank::King)];
+        let mut ai = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        ai.hole_cards = vec![Card::new(Suit::Clubs, Rank::Two), Card::new(Suit::Diamonds, Rank::Seven)];
+        let board = vec![Card::new(Suit::Hearts, Rank::Nine)];
+        
+        assert_eq!(validate_no_duplicate_cards([&human, &ai], &board), Ok(()));
+        
+        ai.hole_cards[0] = ace_of_spades;
+        assert_eq!(
+            validate_no_duplicate_cards([&human, &ai], &board),
+            Err(DuplicateCard { card: ace_of_spades })
+        );
+        
+        // A board card repeated in a hand is caught too
+        ai.hole_cards[0] = board[0];
+        assert!(validate_no_duplicate_cards([&human, &ai], &board).is_err());
+    }
 }
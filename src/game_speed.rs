@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::time::Duration;
 
 // Resource to manage game speed and pause state
 #[derive(Resource)]
@@ -18,6 +19,19 @@ impl Default for GameSpeed {
     }
 }
 
+// Resource every game timer ticks from: the frame time normally, or a fixed step per
+// update when a test drives the game, so all timers stay in lockstep
+#[derive(Resource, Debug, Default)]
+pub struct GameClock {
+    pub manual_step: Option<Duration>,
+    pub delta: Duration, // Time to tick game timers by this update
+}
+
+// System (in `First`) to read this update's time step before any game timer ticks
+pub fn advance_game_clock(time: Res<Time>, mut game_clock: ResMut<GameClock>) {
+    game_clock.delta = game_clock.manual_step.unwrap_or(time.delta());
+}
+
 // Component for timed events that can be affected by game speed
 #[derive(Component)]
 pub struct GameTimer {
@@ -46,6 +60,8 @@ impl Plugin for GameSpeedPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<GameSpeed>()
+            .init_resource::<GameClock>()
+            .add_systems(First, advance_game_clock)
             .add_systems(Update, (
                 handle_speed_controls,
                 update_game_timers,
@@ -93,7 +109,7 @@ fn handle_speed_controls(
 fn update_game_timers(
     mut timer_query: Query<&mut GameTimer>,
     game_speed: Res<GameSpeed>,
-    time: Res<Time>,
+    game_clock: Res<GameClock>,
 ) {
     if game_speed.is_paused {
         return;
@@ -106,7 +122,7 @@ fn update_game_timers(
         }
         
         // Tick the timer
-        game_timer.timer.tick(time.delta());
+        game_timer.timer.tick(game_clock.delta);
     }
 }
 
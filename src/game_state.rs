@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum GameState {
     #[default]
     Setup,
@@ -11,6 +12,45 @@ pub enum GameState {
     River,
     Showdown,
     GameOver,
+    PostGameChoice, // One player has every chip; waiting for play again / quit
+}
+
+impl GameState {
+    /// Betting street number: PreFlop is 0, Flop 1, Turn 2, River 3; None outside the betting streets
+    pub fn street_index(&self) -> Option<u8> {
+        match self {
+            GameState::PreFlop => Some(0),
+            GameState::Flop => Some(1),
+            GameState::Turn => Some(2),
+            GameState::River => Some(3),
+            _ => None,
+        }
+    }
+    
+    // Name shown to the player for this phase
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            GameState::Setup => "Setup",
+            GameState::Dealing => "Dealing Cards",
+            GameState::PreFlop => "Pre-Flop",
+            GameState::Flop => "Flop",
+            GameState::Turn => "Turn",
+            GameState::River => "River",
+            GameState::Showdown => "Showdown",
+            GameState::GameOver => "Game Over",
+            GameState::PostGameChoice => "Play Again?",
+        }
+    }
+}
+
+// Structured record of what happens in a hand, for anything outside the game that wants to follow it
+#[derive(Event, Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    HandStarted,
+    CardsDealt,
+    Action { player: u32, action: crate::betting::PlayerAction },
+    StreetDealt(GameState),
+    HandEnded { winners: Vec<u32>, pot: u32 },
 }
 
 #[derive(Resource, Debug)]
@@ -20,6 +60,9 @@ pub struct GameData {
     pub current_bet: u32,
     pub community_cards: Vec<crate::cards::Card>,
     pub round_number: u32,
+    pub showdown_reveals: Vec<u32>, // Players whose hole cards are shown face-up at showdown
+    pub pot_awards: Vec<(u32, u32)>, // (player_id, amount) paid out from the pot at showdown
+    pub eval_cache: crate::poker_rules::HandEvalCache, // Hand evaluations memoized for the current hand
 }
 
 // New resource for managing dealer position and blinds
@@ -43,14 +86,27 @@ impl Default for GamePosition {
 }
 
 impl GamePosition {
+    pub fn is_heads_up(&self) -> bool {
+        self.total_players == 2
+    }
+    
     pub fn get_small_blind_player(&self) -> u32 {
-        // Small blind is next player after dealer
-        (self.dealer_button + 1) % self.total_players
+        // Heads-up the dealer posts the small blind; otherwise it's the next player after dealer
+        if self.is_heads_up() {
+            self.dealer_button
+        } else {
+            (self.dealer_button + 1) % self.total_players
+        }
     }
     
     pub fn get_big_blind_player(&self) -> u32 {
-        // Big blind is two players after dealer
-        (self.dealer_button + 2) % self.total_players
+        // Big blind is the next player after the small blind
+        (self.get_small_blind_player() + 1) % self.total_players
+    }
+    
+    pub fn get_straddle_player(&self) -> u32 {
+        // Straddle is posted by the player left of the big blind
+        (self.dealer_button + 3) % self.total_players
     }
     
     pub fn advance_dealer_button(&mut self) {
@@ -62,18 +118,15 @@ impl GamePosition {
     pub fn get_betting_order(&self, is_preflop: bool) -> Vec<u32> {
         let mut order = Vec::new();
         
-        if is_preflop {
-            // Pre-flop: start with player after big blind
-            let first_to_act = (self.dealer_button + 3) % self.total_players;
-            for i in 0..self.total_players {
-                order.push((first_to_act + i) % self.total_players);
-            }
+        let first_to_act = if is_preflop {
+            // Pre-flop: start with player after big blind (the dealer when heads-up)
+            (self.get_big_blind_player() + 1) % self.total_players
         } else {
-            // Post-flop: start with small blind
-            let first_to_act = self.get_small_blind_player();
-            for i in 0..self.total_players {
-                order.push((first_to_act + i) % self.total_players);
-            }
+            // Post-flop: start with player after the dealer (the big blind when heads-up)
+            (self.dealer_button + 1) % self.total_players
+        };
+        for i in 0..self.total_players {
+            order.push((first_to_act + i) % self.total_players);
         }
         
         order
@@ -88,6 +141,9 @@ impl Default for GameData {
             current_bet: 0,
             community_cards: Vec::new(),
             round_number: 1,
+            showdown_reveals: Vec::new(),
+            pot_awards: Vec::new(),
+            eval_cache: Default::default(),
         }
     }
 }
@@ -107,5 +163,97 @@ impl GameData {
         self.current_bet = 0;
         self.community_cards.clear();
         self.current_player = 0;
+        self.showdown_reveals.clear();
+        self.pot_awards.clear();
+        self.eval_cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_street_index_and_display_name_for_each_state() {
+        let expected = [
+            (GameState::Setup, None, "Setup"),
+            (GameState::Dealing, None, "Dealing Cards"),
+            (GameState::PreFlop, Some(0), "Pre-Flop"),
+            (GameState::Flop, Some(1), "Flop"),
+            (GameState::Turn, Some(2), "Turn"),
+            (GameState::River, Some(3), "River"),
+            (GameState::Showdown, None, "Showdown"),
+            (GameState::GameOver, None, "Game Over"),
+            (GameState::PostGameChoice, None, "Play Again?"),
+        ];
+        for (state, street_index, display_name) in expected {
+            assert_eq!(state.street_index(), street_index, "{:?}", state);
+            assert_eq!(state.display_name(), display_name);
+        }
+    }
+    
+    #[test]
+    fn test_betting_order_preflop() {
+        // Three-handed with the button on seat 0: blinds on 1 and 2, so the button acts first
+        let mut position = GamePosition::default();
+        assert_eq!(position.get_betting_order(true), vec![0, 1, 2]);
+        
+        // Four-handed, button on seat 1: blinds on 2 and 3, under the gun is seat 0
+        position.total_players = 4;
+        position.dealer_button = 1;
+        assert_eq!(position.get_betting_order(true), vec![0, 1, 2, 3]);
+        position.dealer_button = 3;
+        assert_eq!(position.get_betting_order(true), vec![2, 3, 0, 1]);
+    }
+    
+    #[test]
+    fn test_betting_order_postflop() {
+        // After the flop the small blind, left of the button, acts first
+        let mut position = GamePosition::default();
+        assert_eq!(position.get_betting_order(false), vec![1, 2, 0]);
+        
+        position.total_players = 4;
+        position.dealer_button = 2;
+        assert_eq!(position.get_betting_order(false), vec![3, 0, 1, 2]);
+        
+        // Heads-up the big blind acts first after the flop, and the button first before it
+        position.total_players = 2;
+        position.dealer_button = 0;
+        assert_eq!(position.get_betting_order(false), vec![1, 0]);
+        assert_eq!(position.get_betting_order(true), vec![0, 1]);
+    }
+    
+    #[test]
+    fn test_heads_up_button_posts_the_small_blind() {
+        let mut position = GamePosition { total_players: 2, ..default() };
+        for dealer in [0, 1] {
+            position.dealer_button = dealer;
+            let other = 1 - dealer;
+            assert_eq!(position.get_small_blind_player(), dealer);
+            assert_eq!(position.get_big_blind_player(), other);
+            
+            // The button acts first before the flop and last after it
+            assert_eq!(position.get_betting_order(true), vec![dealer, other]);
+            assert_eq!(position.get_betting_order(false), vec![other, dealer]);
+        }
+        
+        // Moving the button swaps the blinds
+        position.advance_dealer_button();
+        assert_eq!((position.get_small_blind_player(), position.get_big_blind_player()), (0, 1));
+    }
+    
+    #[test]
+    fn test_pot_grows_and_current_player_wraps_around() {
+        let mut game_data = GameData::default();
+        game_data.add_to_pot(100);
+        game_data.add_to_pot(40);
+        assert_eq!(game_data.pot, 140);
+        
+        let mut seats = vec![game_data.current_player];
+        for _ in 0..3 {
+            game_data.next_player(3);
+            seats.push(game_data.current_player);
+        }
+        assert_eq!(seats, vec![0, 1, 2, 0]);
     }
 }
@@ -0,0 +1,203 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::calculator::card_labels;
+use crate::game_config::{GameConfig, GameVariant};
+use crate::game_state::{GameData, GameState};
+use crate::player::{Player, PlayerType};
+use crate::poker_rules::{best_five_cards, describe_evaluation};
+use crate::rendering::{revealed_folded_players, RenderedCard};
+use crate::teaching::TeachingState;
+
+// Resource for the "inspect hand" tooltip: the latest tap and what it revealed
+#[derive(Resource, Debug, Default)]
+pub struct HandInspector {
+    pub tap: Option<Vec2>,          // World position of a tap not yet checked against the cards
+    pub tooltip: Option<String>,    // Description of the tapped hand, while it's open
+}
+
+// Marker for the tooltip text
+#[derive(Component)]
+pub struct HandTooltip;
+
+// Plugin for tapping a face-up hand to see what it makes
+pub struct HandInspectPlugin;
+
+impl Plugin for HandInspectPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<HandInspector>()
+            .add_systems(Startup, setup_hand_tooltip)
+            .add_systems(Update, (queue_card_taps, inspect_tapped_hand, update_hand_tooltip).chain());
+    }
+}
+
+fn setup_hand_tooltip(mut commands: Commands) {
+    // Tooltip (top center), empty until a hand is tapped
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 18.0,
+                color: Color::srgb(1.0, 0.95, 0.7),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(35.0),
+            top: Val::Px(60.0),
+            ..default()
+        }),
+        HandTooltip,
+    ));
+}
+
+// System to turn a click or touch into a world position for the inspector
+fn queue_card_taps(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut inspector: ResMut<HandInspector>,
+) {
+    let screen_position = if mouse_input.just_pressed(MouseButton::Left) {
+        windows.get_single().ok().and_then(|window| window.cursor_position())
+    } else {
+        touches.iter_just_pressed().next().map(|touch| touch.position())
+    };
+    let Some(screen_position) = screen_position else {
+        return;
+    };
+    
+    if let Ok((camera, camera_transform)) = cameras.get_single() {
+        inspector.tap = camera.viewport_to_world_2d(camera_transform, screen_position);
+    }
+}
+
+/// System to describe the hand under the latest tap; tapping anything else closes the tooltip
+pub fn inspect_tapped_hand(
+    mut inspector: ResMut<HandInspector>,
+    cards: Query<(&RenderedCard, &Transform, &Sprite)>,
+    players: Query<&Player>,
+    game_data: Res<GameData>,
+    game_state: Res<State<GameState>>,
+    game_config: Res<GameConfig>,
+    teaching_state: Res<TeachingState>,
+) {
+    // A new hand means new cards, so an open tooltip would be stale
+    if game_state.is_changed() {
+        inspector.tooltip = None;
+    }
+    let Some(tap) = inspector.tap.take() else {
+        return;
+    };
+    
+    let tapped_owner = cards.iter().find_map(|(rendered_card, transform, sprite)| {
+        let half_size = sprite.custom_size.unwrap_or_default() / 2.0;
+        let offset = (tap - transform.translation.truncate()).abs();
+        (offset.x <= half_size.x && offset.y <= half_size.y).then_some(rendered_card.owner_id).flatten()
+    });
+    
+    // Only hands the learner can already see: their own, ones shown at showdown and revealed folds
+    let folded_reveals = revealed_folded_players(&players, &teaching_state);
+    let player = tapped_owner
+        .and_then(|owner_id| players.iter().find(|p| p.id == owner_id))
+        .filter(|player| {
+            matches!(player.player_type, PlayerType::Human)
+                || (*game_state.get() == GameState::GameOver && game_data.showdown_reveals.contains(&player.id))
+                || folded_reveals.contains(&player.id)
+        });
+    
+    inspector.tooltip = player.filter(|player| !player.hole_cards.is_empty()).map(|player| {
+        let evaluation = game_config.variant.evaluate(&player.hole_cards, &game_data.community_cards);
+        // Omaha's two-plus-three rule picks the cards differently, so only name the hand there
+        match game_config.variant {
+            GameVariant::Holdem => format!(
+                "{}, playing {}",
+                describe_evaluation(&evaluation),
+                card_labels(&best_five_cards(&player.hole_cards, &game_data.community_cards))
+            ),
+            GameVariant::Omaha => describe_evaluation(&evaluation),
+        }
+    });
+    if let Some(tooltip) = &inspector.tooltip {
+        info!("🔍 {}", tooltip);
+    }
+}
+
+fn update_hand_tooltip(
+    inspector: Res<HandInspector>,
+    mut tooltip_query: Query<&mut Text, With<HandTooltip>>,
+) {
+    if !inspector.is_changed() {
+        return;
+    }
+    
+    if let Ok(mut text) = tooltip_query.get_single_mut() {
+        text.sections[0].value = inspector.tooltip.clone().map_or(String::new(), |tooltip| format!("🔍 {}", tooltip));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::cards::{Card, Rank, Suit};
+    
+    #[test]
+    fn test_tapping_a_revealed_hand_shows_its_description() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<GameData>()
+            .init_resource::<GameConfig>()
+            .init_resource::<TeachingState>()
+            .init_resource::<HandInspector>()
+            .add_systems(Update, inspect_tapped_hand);
+        
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        let hole_cards = [Card::new(Suit::Hearts, Rank::King), Card::new(Suit::Hearts, Rank::Queen)];
+        let mut players = app.world_mut().query::<&mut Player>();
+        for mut player in players.iter_mut(app.world_mut()) {
+            player.hole_cards = if player.id == 0 {
+                hole_cards.to_vec()
+            } else {
+                vec![Card::new(Suit::Clubs, Rank::Ace), Card::new(Suit::Spades, Rank::Ace)]
+            };
+        }
+        app.world_mut().resource_mut::<GameData>().community_cards = vec![
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Diamonds, Rank::Four),
+        ];
+        
+        // One card sprite per player, like the table renders them
+        let card_sprite = |card: Card, owner_id: u32, x: f32| {
+            (
+                SpriteBundle {
+                    sprite: Sprite { custom_size: Some(Vec2::new(50.0, 70.0)), ..default() },
+                    transform: Transform::from_xyz(x, -50.0, 1.0),
+                    ..default()
+                },
+                RenderedCard { card, owner_id: Some(owner_id) },
+            )
+        };
+        app.world_mut().spawn(card_sprite(hole_cards[0], 0, 0.0));
+        app.world_mut().spawn(card_sprite(Card::new(Suit::Clubs, Rank::Ace), 1, 300.0));
+        
+        app.world_mut().resource_mut::<HandInspector>().tap = Some(Vec2::new(10.0, -40.0));
+        app.update();
+        assert_eq!(
+            app.world().resource::<HandInspector>().tooltip.as_deref(),
+            Some("Flush, King high, playing K♥ Q♥ 9♥ 5♥ 2♥")
+        );
+        
+        // The AI's cards are still face-down before showdown, so tapping them closes the tooltip
+        app.world_mut().resource_mut::<HandInspector>().tap = Some(Vec2::new(300.0, -50.0));
+        app.update();
+        assert!(app.world().resource::<HandInspector>().tooltip.is_none());
+    }
+}
@@ -0,0 +1,291 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use crate::cards::Card;
+use crate::game_state::{GameData, GameState};
+use crate::odds::estimate_equity;
+use crate::player::{Player, PlayerType};
+use crate::poker_rules::{evaluate_hand, hand_rank_name, HandRank};
+use crate::teaching::{CoachLevel, ExplanationType, TeachingState};
+
+// Run-outs per street snapshot; the recap only needs rough numbers
+const RECAP_EQUITY_ITERATIONS: usize = 500;
+
+// Folding below this equity was the right call; showing down below it cost chips
+const WEAK_HAND_EQUITY: f32 = 0.3;
+// Folding at or above this equity gave up a hand worth playing
+const STRONG_HAND_EQUITY: f32 = 0.5;
+
+// Resource recording the human's current hand for the post-hand recap
+#[derive(Resource, Debug, Clone, Default)]
+pub struct HandRecord {
+    pub hole_cards: Vec<Card>,
+    pub street_equity: Vec<(GameState, f32)>, // Equity as each street began, in order
+    pub folded_on: Option<GameState>, // Street the human folded on, if they did
+}
+
+impl HandRecord {
+    pub fn clear(&mut self) {
+        self.hole_cards.clear();
+        self.street_equity.clear();
+        self.folded_on = None;
+    }
+    
+    // Store the human's equity at the start of a street
+    pub fn snapshot<R: Rng>(
+        &mut self,
+        street: GameState,
+        hole_cards: &[Card],
+        community_cards: &[Card],
+        opponents: usize,
+        rng: &mut R,
+    ) {
+        let equity = estimate_equity(hole_cards, community_cards, opponents, RECAP_EQUITY_ITERATIONS, rng);
+        self.hole_cards = hole_cards.to_vec();
+        self.street_equity.retain(|(recorded, _)| *recorded != street);
+        self.street_equity.push((street, equity));
+    }
+    
+    /// One-line summary of how the human's equity moved street by street
+    pub fn equity_recap(&self) -> Option<String> {
+        let (first, last) = (self.street_equity.first()?, self.street_equity.last()?);
+        let steps: Vec<String> = self
+            .street_equity
+            .iter()
+            .map(|(street, equity)| format!("{} {:.0}%", street.display_name(), equity * 100.0))
+            .collect();
+        
+        let verdict = if self.street_equity.len() < 2 {
+            ""
+        } else if last.1 > first.1 + 0.1 {
+            " - your hand improved"
+        } else if last.1 < first.1 - 0.1 {
+            " - your hand fell behind"
+        } else {
+            " - roughly where you started"
+        };
+        Some(format!("Equity by street: {}{}", steps.join(" → "), verdict))
+    }
+    
+    /// One-line takeaway from the human's decisions this hand and how it ended
+    pub fn takeaway(&self, won_pot: bool) -> Option<String> {
+        if let Some(street) = self.folded_on {
+            let (_, equity) = self.street_equity.iter().find(|(recorded, _)| *recorded == street)?;
+            return if *equity < WEAK_HAND_EQUITY {
+                Some(format!(
+                    "✅ Takeaway: you correctly folded a weak hand on the {} (~{:.0}% equity)",
+                    street.display_name(),
+                    equity * 100.0
+                ))
+            } else if *equity >= STRONG_HAND_EQUITY {
+                Some(format!(
+                    "🤔 Takeaway: you folded on the {} with ~{:.0}% equity - that hand was worth playing on",
+                    street.display_name(),
+                    equity * 100.0
+                ))
+            } else {
+                None
+            };
+        }
+        
+        let (street, equity) = self.street_equity.last()?;
+        if won_pot {
+            Some("🏆 Takeaway: you played the hand through and won the pot".to_string())
+        } else if *equity < WEAK_HAND_EQUITY {
+            Some(format!(
+                "⚠️ Takeaway: you stayed in on the {} with only ~{:.0}% equity - weak hands are cheaper to fold",
+                street.display_name(),
+                equity * 100.0
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// An empathetic note when the human was ahead with a strong made hand on the turn and the
+/// river card gave the winner a better one
+pub fn bad_beat_message(human_hole: &[Card], winner_hole: &[Card], community_cards: &[Card]) -> Option<String> {
+    if community_cards.len() != 5 {
+        return None;
+    }
+    
+    let turn_board = &community_cards[..4];
+    let human_on_turn = evaluate_hand(human_hole, turn_board);
+    let winner_on_turn = evaluate_hand(winner_hole, turn_board);
+    let human_final = evaluate_hand(human_hole, community_cards);
+    let winner_final = evaluate_hand(winner_hole, community_cards);
+    if human_on_turn.rank < HandRank::ThreeOfAKind || human_on_turn <= winner_on_turn || human_final >= winner_final {
+        return None;
+    }
+    
+    Some(format!(
+        "Bad beat! Your {} was ahead on the turn, but the river gave your opponent a {}. You played it right - getting your money in ahead is how you win over time, and variance means it won't hold every hand.",
+        hand_rank_name(&human_on_turn.rank).to_lowercase(),
+        hand_rank_name(&winner_final.rank).to_lowercase()
+    ))
+}
+
+// System to snapshot the human's equity as each street starts and show the recap after the hand
+pub fn track_hand_equity(
+    current_state: Res<State<GameState>>,
+    game_data: Res<GameData>,
+    players: Query<&Player>,
+    mut hand_record: ResMut<HandRecord>,
+    mut teaching_state: ResMut<TeachingState>,
+) {
+    if !current_state.is_changed() {
+        return;
+    }
+    
+    match current_state.get() {
+        GameState::Setup => hand_record.clear(),
+        GameState::GameOver if teaching_state.tutorial_mode() => {
+            let human_player = players.iter().find(|p| matches!(p.player_type, PlayerType::Human) && !p.has_folded);
+            let winner = game_data
+                .pot_awards
+                .first()
+                .and_then(|&(winner_id, _)| players.iter().find(|p| p.id == winner_id));
+            let bad_beat = match (human_player, winner) {
+                (Some(human), Some(winner)) if human.id != winner.id => {
+                    bad_beat_message(&human.hole_cards, &winner.hole_cards, &game_data.community_cards)
+                },
+                _ => None,
+            };
+            
+            // The per-hand takeaway is for learners who want coaching on everything
+            let takeaway = players
+                .iter()
+                .find(|p| matches!(p.player_type, PlayerType::Human))
+                .filter(|_| teaching_state.coach_level == CoachLevel::Verbose)
+                .and_then(|human| hand_record.takeaway(game_data.pot_awards.iter().any(|&(winner_id, _)| winner_id == human.id)));
+            
+            let recap: Vec<String> = [bad_beat, hand_record.equity_recap(), takeaway].into_iter().flatten().collect();
+            if !recap.is_empty() {
+                teaching_state.show_explanation(ExplanationType::Recap(recap.join("\n")));
+            }
+        },
+        street if street.street_index().is_some() => {
+            let Some(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)) else {
+                return;
+            };
+            if human_player.has_folded || human_player.hole_cards.is_empty() {
+                return;
+            }
+            
+            let opponents = players.iter().filter(|p| p.id != human_player.id && !p.has_folded).count();
+            hand_record.snapshot(
+                *street,
+                &human_player.hole_cards,
+                &game_data.community_cards,
+                opponents,
+                &mut thread_rng(),
+            );
+        },
+        _ => {},
+    }
+}
+
+// System to note the street the human folded on, for the per-hand takeaway
+pub fn record_human_fold(
+    current_state: Res<State<GameState>>,
+    players: Query<&Player>,
+    mut hand_record: ResMut<HandRecord>,
+) {
+    if hand_record.folded_on.is_some() || current_state.get().street_index().is_none() {
+        return;
+    }
+    if players.iter().any(|p| matches!(p.player_type, PlayerType::Human) && p.has_folded) {
+        hand_record.folded_on = Some(*current_state.get());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use crate::cards::{Rank, Suit};
+    
+    #[test]
+    fn test_completed_flush_draw_shows_rising_equity() {
+        let hole = vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Hearts, Rank::Four)];
+        let flop = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+        let mut turn = flop.clone();
+        turn.push(Card::new(Suit::Spades, Rank::Five));
+        let mut river = turn.clone();
+        river.push(Card::new(Suit::Hearts, Rank::Ten));
+        
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut hand_record = HandRecord::default();
+        hand_record.snapshot(GameState::Flop, &hole, &flop, 1, &mut rng);
+        hand_record.snapshot(GameState::Turn, &hole, &turn, 1, &mut rng);
+        hand_record.snapshot(GameState::River, &hole, &river, 1, &mut rng);
+        
+        let equity: Vec<f32> = hand_record.street_equity.iter().map(|(_, equity)| *equity).collect();
+        assert!(equity[2] > equity[0], "flush on the river should beat the flop draw: {:?}", equity);
+        assert!((equity[2] - 1.0).abs() < f32::EPSILON, "the nut flush can't lose");
+        
+        let recap = hand_record.equity_recap().unwrap();
+        assert!(recap.starts_with("Equity by street: Flop "), "{}", recap);
+        assert!(recap.contains("River 100%"));
+        assert!(recap.ends_with("your hand improved"));
+    }
+    
+    #[test]
+    fn test_folding_a_weak_hand_gets_a_positive_takeaway() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .insert_state(GameState::PreFlop)
+            .init_resource::<GameData>()
+            .init_resource::<HandRecord>()
+            .init_resource::<TeachingState>()
+            .add_systems(Update, (track_hand_equity, record_human_fold));
+        let human = app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO)).id();
+        for id in 1..4 {
+            app.world_mut().spawn(Player::new(id, PlayerType::AI, 1000, Vec3::ZERO));
+        }
+        
+        // Seven-deuce offsuit against three opponents, folded before the flop
+        let hole = vec![Card::new(Suit::Clubs, Rank::Seven), Card::new(Suit::Diamonds, Rank::Two)];
+        app.world_mut().get_mut::<Player>(human).unwrap().hole_cards = hole.clone();
+        app.world_mut().resource_mut::<HandRecord>().snapshot(GameState::PreFlop, &hole, &[], 3, &mut StdRng::seed_from_u64(8));
+        app.world_mut().get_mut::<Player>(human).unwrap().has_folded = true;
+        app.update();
+        assert_eq!(app.world().resource::<HandRecord>().folded_on, Some(GameState::PreFlop));
+        
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::GameOver);
+        app.update();
+        let recap = app.world().resource::<TeachingState>().current_explanation.clone().unwrap();
+        assert!(recap.contains("✅ Takeaway: you correctly folded a weak hand on the Pre-Flop"), "{}", recap);
+        
+        // Throwing away a strong hand is called out instead
+        let mut hand_record = HandRecord { folded_on: Some(GameState::Flop), ..default() };
+        hand_record.street_equity.push((GameState::Flop, 0.8));
+        assert!(hand_record.takeaway(false).unwrap().starts_with("🤔"));
+    }
+    
+    #[test]
+    fn test_turn_flush_losing_to_rivered_full_house_is_a_bad_beat() {
+        let human = [Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Hearts, Rank::Four)];
+        let opponent = [Card::new(Suit::Spades, Rank::King), Card::new(Suit::Clubs, Rank::King)];
+        let mut board = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Five),
+        ];
+        
+        // The set of kings fills up on a paired river
+        board.push(Card::new(Suit::Diamonds, Rank::Eight));
+        let message = bad_beat_message(&human, &opponent, &board).unwrap();
+        assert!(message.starts_with("Bad beat! Your flush was ahead on the turn"), "{}", message);
+        assert!(message.contains("full house"));
+        
+        // A blank river keeps the flush in front
+        board[4] = Card::new(Suit::Diamonds, Rank::Three);
+        assert!(bad_beat_message(&human, &opponent, &board).is_none());
+    }
+}
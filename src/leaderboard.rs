@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use crate::game_state::{GameData, GameState};
+use crate::player::{Player, PlayerType};
+
+// Saved next to the game so best results survive between sessions
+pub const LEADERBOARD_PATH: &str = "leaderboard.ron";
+
+// Resource holding the human's best session results, persisted to disk
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub best_final_stack: u32, // Biggest stack the human finished a game with
+    pub best_win_streak: u32,  // Most hands won in a row
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+// Why the leaderboard couldn't be saved
+#[derive(Debug)]
+pub enum LeaderboardSaveError {
+    Io(std::io::Error),
+    Serialize(ron::Error),
+}
+
+impl std::fmt::Display for LeaderboardSaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LeaderboardSaveError::Io(error) => write!(f, "couldn't write leaderboard: {}", error),
+            LeaderboardSaveError::Serialize(error) => write!(f, "couldn't serialize leaderboard: {}", error),
+        }
+    }
+}
+
+impl Leaderboard {
+    // Read the saved leaderboard; a missing or unreadable file starts a fresh one
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let mut leaderboard = match std::fs::read_to_string(path) {
+            Ok(source) => ron::from_str(&source).unwrap_or_else(|error| {
+                warn!("🏆 Ignoring unreadable leaderboard {}: {}", path.display(), error);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+        leaderboard.path = path.to_path_buf();
+        leaderboard
+    }
+    
+    pub fn save(&self) -> Result<(), LeaderboardSaveError> {
+        let source = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(LeaderboardSaveError::Serialize)?;
+        std::fs::write(&self.path, source).map_err(LeaderboardSaveError::Io)
+    }
+    
+    /// Keep whichever results beat the saved bests; returns true if anything improved
+    pub fn record(&mut self, final_stack: u32, win_streak: u32) -> bool {
+        let improved = final_stack > self.best_final_stack || win_streak > self.best_win_streak;
+        self.best_final_stack = self.best_final_stack.max(final_stack);
+        self.best_win_streak = self.best_win_streak.max(win_streak);
+        improved
+    }
+    
+    pub fn summary(&self) -> String {
+        format!(
+            "🏆 Best final stack: ${}\n🔥 Best win streak: {} hands",
+            self.best_final_stack, self.best_win_streak
+        )
+    }
+}
+
+// Resource tracking the human's results in the current game
+#[derive(Resource, Debug, Default)]
+pub struct SessionStats {
+    pub win_streak: u32,
+    pub best_win_streak: u32,
+}
+
+// System to count consecutive hands won by the human
+pub fn track_win_streak(
+    current_state: Res<State<GameState>>,
+    game_data: Res<GameData>,
+    players: Query<&Player>,
+    mut session_stats: ResMut<SessionStats>,
+) {
+    if !current_state.is_changed() || *current_state.get() != GameState::GameOver {
+        return;
+    }
+    
+    let Some(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)) else {
+        return;
+    };
+    if game_data.pot_awards.iter().any(|&(winner_id, _)| winner_id == human_player.id) {
+        session_stats.win_streak += 1;
+        session_stats.best_win_streak = session_stats.best_win_streak.max(session_stats.win_streak);
+    } else {
+        session_stats.win_streak = 0;
+    }
+}
+
+// System to add the finished game to the leaderboard and save any new bests
+pub fn record_session_results(
+    current_state: Res<State<GameState>>,
+    players: Query<&Player>,
+    mut session_stats: ResMut<SessionStats>,
+    mut leaderboard: ResMut<Leaderboard>,
+) {
+    if !current_state.is_changed() || *current_state.get() != GameState::PostGameChoice {
+        return;
+    }
+    
+    let final_stack = players
+        .iter()
+        .find(|p| matches!(p.player_type, PlayerType::Human))
+        .map_or(0, |p| p.chips);
+    if leaderboard.record(final_stack, session_stats.best_win_streak) {
+        info!("🏆 New personal best!");
+        if let Err(error) = leaderboard.save() {
+            warn!("🏆 {}", error);
+        }
+    }
+    *session_stats = SessionStats::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_high_score_persists_and_lower_score_does_not_displace_it() {
+        let path = std::env::temp_dir().join(format!("teach-poker-leaderboard-{}.ron", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        
+        let mut leaderboard = Leaderboard::load(&path);
+        assert_eq!(leaderboard.best_final_stack, 0);
+        assert!(leaderboard.record(3000, 4));
+        leaderboard.save().unwrap();
+        
+        let mut reloaded = Leaderboard::load(&path);
+        assert_eq!((reloaded.best_final_stack, reloaded.best_win_streak), (3000, 4));
+        
+        assert!(!reloaded.record(1500, 2));
+        assert_eq!((reloaded.best_final_stack, reloaded.best_win_streak), (3000, 4));
+        
+        // A better streak alone still counts as a new best
+        assert!(reloaded.record(0, 6));
+        assert_eq!((reloaded.best_final_stack, reloaded.best_win_streak), (3000, 6));
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use crate::game_config::{GameConfig, GameVariant, ScenarioConfig};
+use crate::game_state::GameState;
+use crate::teaching::{ExplanationType, TeachingState};
+
+// A lesson authored as data: each step is one hand with an optional preset scenario
+#[derive(Debug, Clone, Deserialize)]
+pub struct LessonScript {
+    pub title: String,
+    pub steps: Vec<LessonStep>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LessonStep {
+    pub message: String,                  // Shown in the teaching panel for this hand
+    #[serde(default)]
+    pub scenario: Option<ScenarioConfig>, // Cards to deal; a normal random hand when absent
+}
+
+// Why a lesson file couldn't be used
+#[derive(Debug)]
+pub enum LessonLoadError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    InvalidScenario(usize), // Index of the step with an impossible scenario
+}
+
+impl std::fmt::Display for LessonLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LessonLoadError::Io(error) => write!(f, "couldn't read lesson file: {}", error),
+            LessonLoadError::Parse(error) => write!(f, "couldn't parse lesson script: {}", error),
+            LessonLoadError::InvalidScenario(step) => write!(f, "step {} has an invalid scenario", step + 1),
+        }
+    }
+}
+
+impl LessonScript {
+    pub fn from_ron(source: &str) -> Result<Self, LessonLoadError> {
+        let script: Self = ron::from_str(source).map_err(LessonLoadError::Parse)?;
+        for (i, step) in script.steps.iter().enumerate() {
+            if step.scenario.as_ref().is_some_and(|scenario| !scenario.is_valid(GameVariant::Holdem)) {
+                return Err(LessonLoadError::InvalidScenario(i));
+            }
+        }
+        Ok(script)
+    }
+    
+    pub fn load(path: &str) -> Result<Self, LessonLoadError> {
+        let source = std::fs::read_to_string(path).map_err(LessonLoadError::Io)?;
+        Self::from_ron(&source)
+    }
+}
+
+// Resource that walks through a lesson script, one step per hand
+#[derive(Resource, Default)]
+pub struct LessonRunner {
+    pub script: Option<LessonScript>,
+    pub next_step: usize,
+    pub active_step: Option<LessonStep>,
+}
+
+impl LessonRunner {
+    pub fn start(&mut self, script: LessonScript) {
+        info!("📘 Starting lesson: {} ({} steps)", script.title, script.steps.len());
+        self.script = Some(script);
+        self.next_step = 0;
+        self.active_step = None;
+    }
+    
+    // Move on to the next step, or finish the lesson when none are left
+    pub fn advance(&mut self) -> Option<&LessonStep> {
+        let step = self.script.as_ref()?.steps.get(self.next_step).cloned();
+        if step.is_some() {
+            self.next_step += 1;
+        } else {
+            info!("📘 Lesson complete");
+            self.script = None;
+        }
+        self.active_step = step;
+        self.active_step.as_ref()
+    }
+}
+
+// Startup system: load the lesson named on the command line, if any
+pub fn load_lesson_script(game_config: Res<GameConfig>, mut lesson_runner: ResMut<LessonRunner>) {
+    let Some(path) = &game_config.lesson_path else {
+        return;
+    };
+    
+    match LessonScript::load(path) {
+        Ok(script) => lesson_runner.start(script),
+        Err(error) => warn!("📘 Lesson {} not loaded: {}", path, error),
+    }
+}
+
+// System to drive the game through the lesson: each new hand sets up the next step's
+// scenario, and its message is shown again once the hand reaches the lesson's street
+pub fn run_lesson_script(
+    game_state: Res<State<GameState>>,
+    mut lesson_runner: ResMut<LessonRunner>,
+    mut game_config: ResMut<GameConfig>,
+    mut teaching_state: ResMut<TeachingState>,
+) {
+    if !game_state.is_changed() || (lesson_runner.script.is_none() && lesson_runner.active_step.is_none()) {
+        return;
+    }
+    
+    let step = if *game_state.get() == GameState::Setup {
+        let step = lesson_runner.advance().cloned();
+        game_config.scenario = step.as_ref().and_then(|step| step.scenario.clone());
+        step
+    } else {
+        let start_street = lesson_runner
+            .active_step
+            .as_ref()
+            .map(|step| step.scenario.as_ref().map_or(GameState::PreFlop, |scenario| scenario.start_street));
+        if start_street != Some(*game_state.get()) {
+            return;
+        }
+        lesson_runner.active_step.clone()
+    };
+    
+    if let Some(step) = step {
+        teaching_state.show_explanation(ExplanationType::Lesson(step.message));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::cards::{Card, Rank, Suit};
+    
+    const RIVER_LESSON: &str = r#"(
+        title: "Value betting the river",
+        steps: [
+            (
+                message: "You have the nut flush. Bet for value!",
+                scenario: Some((
+                    hole_cards: [(suit: Hearts, rank: Ace), (suit: Hearts, rank: Two)],
+                    board: [
+                        (suit: Hearts, rank: King),
+                        (suit: Hearts, rank: Nine),
+                        (suit: Hearts, rank: Four),
+                        (suit: Clubs, rank: Seven),
+                        (suit: Spades, rank: Jack),
+                    ],
+                    start_street: River,
+                )),
+            ),
+            (
+                message: "Now a normal hand - spot the difference.",
+            ),
+        ],
+    )"#;
+    
+    #[test]
+    fn test_lesson_script_first_step_sets_scenario_and_message() {
+        let script = LessonScript::from_ron(RIVER_LESSON).unwrap();
+        assert_eq!(script.steps.len(), 2);
+        assert!(script.steps[1].scenario.is_none());
+        
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<GameConfig>()
+            .init_resource::<TeachingState>()
+            .init_resource::<LessonRunner>()
+            .add_systems(Update, run_lesson_script);
+        app.world_mut().resource_mut::<LessonRunner>().start(script);
+        app.update();
+        
+        let scenario = app.world().resource::<GameConfig>().scenario.clone().unwrap();
+        assert_eq!(scenario.start_street, GameState::River);
+        assert_eq!(scenario.hole_cards[0], Card::new(Suit::Hearts, Rank::Ace));
+        let explanation = app.world().resource::<TeachingState>().current_explanation.clone().unwrap();
+        assert!(explanation.contains("Bet for value"));
+    }
+    
+    #[test]
+    fn test_lesson_with_duplicate_card_is_rejected() {
+        let broken = RIVER_LESSON.replace("(suit: Spades, rank: Jack)", "(suit: Hearts, rank: Ace)");
+        assert!(matches!(LessonScript::from_ron(&broken), Err(LessonLoadError::InvalidScenario(0))));
+    }
+}
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 mod cards;
 mod player;
 mod game_state;
+mod game_config;
 mod rendering;
 mod ui;
 mod game_controller;
@@ -14,15 +15,39 @@ mod teaching;
 mod audio;
 mod game_speed;
 mod animations;
+mod debug_tools;
+mod odds;
+mod lesson;
+mod hand_record;
+mod theme;
+mod leaderboard;
+mod calculator;
+mod decision_timer;
+mod ranking_drill;
+mod tournament;
+mod practice;
+mod hand_inspect;
+
+#[cfg(test)]
+mod test_support;
 
 use cards::Deck;
 use game_state::{GameState, GameData};
-use player::{Player, PlayerType, HumanPlayer, AIPlayer, AIDifficulty};
+use player::{seat_positions, Player, PlayerType, HumanPlayer, AIPlayer, AIDifficulty};
 use game_controller::GameController;
-use ai_player::{AIPlayerComponent, AIPersonality};
+use ai_player::{AIPlayerComponent, AIPersonality, AIRng};
 use betting_ui::HumanPlayerInput;
 
 fn main() {
+    let game_config = game_config::GameConfig::from_args(std::env::args().skip(1));
+    if let Err(error) = game_config.validate() {
+        eprintln!("Can't start the game: {}", error);
+        std::process::exit(2);
+    }
+    let board_layout = rendering::BoardLayout::scaled(game_config.card_scale.unwrap_or(1.0));
+    let reduced_motion = animations::ReducedMotion { enabled: game_config.reduced_motion };
+    let min_think_time = decision_timer::MinThinkTime { seconds: game_config.min_think_seconds, ..default() };
+    
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -36,15 +61,36 @@ fn main() {
         .add_plugins(audio::AudioPlugin)
         .add_plugins(game_speed::GameSpeedPlugin)
         .add_plugins(animations::AnimationPlugin)
+        .add_plugins(debug_tools::DebugToolsPlugin)
+        .add_plugins(odds::OddsOverlayPlugin)
+        .add_plugins(theme::ThemePlugin)
+        .add_plugins(calculator::CalculatorPlugin)
+        .add_plugins(ranking_drill::RankingDrillPlugin)
+        .add_plugins(hand_inspect::HandInspectPlugin)
         .init_state::<GameState>()
         .init_resource::<Deck>()
         .init_resource::<GameData>()
+        .add_event::<game_state::GameEvent>()
+        .insert_resource(game_config)
+        .insert_resource(board_layout)
+        .insert_resource(reduced_motion)
         .init_resource::<game_state::GamePosition>()
         .init_resource::<GameController>()
         .init_resource::<betting::BettingRound>()
         .init_resource::<betting_ui::HumanPlayerInput>()
         .init_resource::<teaching::TeachingState>()
-        .add_systems(Startup, (setup, ui::setup_ui, betting_ui::setup_betting_ui, teaching::setup_teaching_ui))
+        .init_resource::<teaching::QuizMode>()
+        .init_resource::<teaching::OpponentReads>()
+        .init_resource::<ai_player::AIDifficultyCycle>()
+        .init_resource::<lesson::LessonRunner>()
+        .init_resource::<practice::PracticeSession>()
+        .init_resource::<hand_record::HandRecord>()
+        .insert_resource(leaderboard::Leaderboard::load(leaderboard::LEADERBOARD_PATH))
+        .init_resource::<leaderboard::SessionStats>()
+        .init_resource::<decision_timer::DecisionTimer>()
+        .insert_resource(min_think_time)
+        .init_resource::<tournament::EliminationOrder>()
+        .add_systems(Startup, (setup, ui::setup_ui, betting_ui::setup_betting_ui, teaching::setup_teaching_ui, lesson::load_lesson_script, practice::start_practice_session))
         .add_systems(
             Update,
             (
@@ -52,10 +98,37 @@ fn main() {
                 game_controller::game_state_controller,
                 game_controller::debug_game_state,
                 game_controller::toggle_auto_advance,
+                game_controller::show_post_game_prompt.after(leaderboard::record_session_results),
+                game_controller::handle_post_game_choice,
                 
                 // Betting systems
                 betting::ai_player_system,
                 betting::check_betting_round_complete,
+                betting::sync_current_player.after(betting::ai_player_system),
+                
+                // AI difficulty cycling
+                ai_player::handle_difficulty_cycle_input,
+                ai_player::apply_difficulty_cycle,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                // Session results
+                leaderboard::track_win_streak,
+                leaderboard::record_session_results,
+                tournament::track_eliminations,
+                
+                // Raise amount display
+                betting_ui::update_raise_amount_display
+                    .after(betting_ui::size_default_raise)
+                    .after(betting_ui::handle_raise_adjustment)
+                    .after(betting_ui::handle_raise_slider),
+                
+                // Pre-selected actions for the human
+                betting_ui::manage_pre_action_ui,
+                betting_ui::handle_pre_action_buttons,
+                betting_ui::apply_pre_action.before(betting::ai_player_system),
             ),
         )
         .add_systems(
@@ -65,16 +138,29 @@ fn main() {
                 betting_ui::manage_betting_ui_visibility,
                 betting_ui::handle_betting_buttons,
                 betting_ui::handle_raise_adjustment,
+                betting_ui::handle_raise_slider,
                 betting_ui::update_betting_button_text,
                 betting_ui::reset_raise_amount_on_new_hand,
+                betting_ui::size_default_raise.after(betting_ui::reset_raise_amount_on_new_hand),
                 
                 // Teaching systems
                 teaching::handle_teaching_input,
+                teaching::auto_fold_trash_hands.before(teaching::run_quiz_mode),
+                teaching::run_quiz_mode
+                    .after(betting_ui::handle_betting_buttons)
+                    .before(betting::ai_player_system),
                 teaching::provide_contextual_explanations,
                 teaching::explain_hand_rankings,
                 teaching::highlight_valid_actions,
                 teaching::provide_hand_analysis,
                 teaching::update_teaching_display,
+                teaching::track_opponent_ranges,
+                teaching::update_opponent_range_display.after(teaching::track_opponent_ranges),
+                teaching::suggest_spot_tips.after(teaching::track_opponent_ranges),
+                hand_record::track_hand_equity.after(teaching::provide_contextual_explanations),
+                lesson::run_lesson_script
+                    .after(teaching::provide_contextual_explanations)
+                    .before(game_controller::game_state_controller),
             ),
         )
         .add_systems(
@@ -83,7 +169,7 @@ fn main() {
                 // Rendering systems
                 rendering::render_player_cards,
                 rendering::render_community_cards,
-                rendering::render_card_backs_for_ai,
+                rendering::render_face_down_cards,
                 
                 // UI systems
                 ui::setup_player_ui,
@@ -92,45 +178,146 @@ fn main() {
                 ui::update_player_ui,
             ),
         )
+        .add_systems(
+            Update,
+            (
+                // Shot clock
+                decision_timer::run_decision_timer.before(betting::ai_player_system),
+                decision_timer::update_decision_ring.after(decision_timer::run_decision_timer),
+                decision_timer::draw_decision_rings,
+                decision_timer::enforce_min_think_time
+                    .after(decision_timer::run_decision_timer)
+                    .before(betting::ai_player_system),
+                
+                // Tutorial checkpoints
+                teaching::explain_blinds_on_first_hand.before(betting::ai_player_system),
+                
+                // Opponent tells
+                teaching::update_ai_tells,
+                
+                // Practice mode
+                practice::run_practice_session
+                    .after(teaching::provide_contextual_explanations)
+                    .before(game_controller::game_state_controller),
+                
+                // Per-hand takeaway
+                hand_record::record_human_fold.after(betting::ai_player_system),
+                
+                // Small-screen layout
+                teaching::arbitrate_teaching_panel_layout.after(betting_ui::manage_betting_ui_visibility),
+            ),
+        )
         .run();
 }
 
-fn setup(mut commands: Commands) {
+fn setup(
+    mut commands: Commands,
+    game_config: Res<game_config::GameConfig>,
+    mut game_position: ResMut<game_state::GamePosition>,
+) {
     // Spawn a camera
     commands.spawn(Camera2dBundle::default());
     
-    // Spawn 3 players: 1 human, 2 AI
-    // Player positions in a triangle around the table
-    let positions = [
-        Vec3::new(0.0, -200.0, 0.0),    // Human player (bottom)
-        Vec3::new(-300.0, 100.0, 0.0),  // AI player 1 (top left)
-        Vec3::new(300.0, 100.0, 0.0),   // AI player 2 (top right)
-    ];
-    
-    // Spawn human player
-    commands.spawn((
-        Player::new(0, PlayerType::Human, 1000, positions[0]),
-        HumanPlayer,
-    ));
+    // Table size and blinds from the config
+    let total_players = game_config.player_count();
+    game_position.total_players = total_players;
+    game_position.small_blind_amount = game_config.small_blind();
+    game_position.big_blind_amount = game_config.small_blind() * 2;
     
-    // Spawn AI players with advanced AI components
-    commands.spawn((
-        Player::new(1, PlayerType::AI, 1000, positions[1]),
-        AIPlayer { difficulty: AIDifficulty::Beginner },
-        AIPlayerComponent {
-            personality: AIPersonality::beginner(),
-        },
-    ));
+    // Every AI seat gets its own RNG from the base seed
+    let ai_seed = game_config.ai_seed.unwrap_or_else(rand::random);
+    let starting_chips = game_config.starting_chips();
     
-    commands.spawn((
-        Player::new(2, PlayerType::AI, 1000, positions[2]),
-        AIPlayer { difficulty: AIDifficulty::Intermediate },
-        AIPlayerComponent {
-            personality: AIPersonality::intermediate(),
-        },
-    ));
+    // Human player at the bottom, the rest around the table
+    for (seat, position) in (0..total_players).zip(seat_positions(total_players)) {
+        if seat == 0 {
+            commands.spawn((
+                Player::new(seat, PlayerType::Human, starting_chips, position),
+                HumanPlayer,
+            ));
+            continue;
+        }
+        
+        // Heads-up faces an intermediate AI; bigger tables mix the levels seat by seat
+        let difficulty = if game_config.heads_up {
+            AIDifficulty::Intermediate
+        } else {
+            [AIDifficulty::Beginner, AIDifficulty::Intermediate, AIDifficulty::Advanced, AIDifficulty::Expert][(seat as usize - 1) % 4]
+        };
+        commands.spawn((
+            Player::new(seat, PlayerType::AI, starting_chips, position),
+            AIPlayer { difficulty },
+            AIPlayerComponent {
+                personality: AIPersonality::for_difficulty(difficulty),
+            },
+            AIRng::for_seat(ai_seed, seat),
+        ));
+    }
     
-    println!("Poker Teacher Game Starting!");
-    println!("Players spawned: 1 Human, 2 AI");
+    println!("Poker Teacher Game Starting!{}", if game_config.heads_up { " (heads-up practice)" } else { "" });
+    println!("Players spawned: 1 Human, {} AI", total_players - 1);
     println!("Press SPACE to pause/resume auto-advance");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_config::GameConfig;
+    use crate::test_support::build_test_app;
+    
+    #[test]
+    fn test_heads_up_mode_spawns_two_players_with_heads_up_blinds() {
+        let mut app = build_test_app();
+        app.insert_resource(GameConfig { heads_up: true, ..default() })
+            .add_systems(Startup, setup);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Dealing);
+        app.update();
+        app.update();
+        
+        let mut players = app.world_mut().query::<&Player>();
+        let mut players: Vec<Player> = players.iter(app.world()).cloned().collect();
+        players.sort_by_key(|p| p.id);
+        assert_eq!(players.len(), 2);
+        assert_eq!(app.world().resource::<game_state::GamePosition>().total_players, 2);
+        
+        // Dealer (player 0) posts the small blind, the other player the big blind
+        assert_eq!(players[0].current_bet, 10);
+        assert_eq!(players[0].chips, 990);
+        assert_eq!(players[1].current_bet, 20);
+        assert_eq!(players[1].chips, 980);
+        
+        // The dealer acts first pre-flop
+        assert_eq!(app.world().resource::<betting::BettingRound>().peek_next_player(), Some(0));
+    }
+    
+    #[test]
+    fn test_six_player_table_spawns_every_seat_with_its_blinds() {
+        let mut app = build_test_app();
+        app.insert_resource(GameConfig { num_players: Some(6), starting_chips: Some(2000), small_blind: Some(25), ..default() })
+            .add_systems(Startup, setup);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Setup);
+        while *app.world().resource::<State<GameState>>().get() != GameState::Dealing {
+            app.update();
+        }
+        
+        // The hand's betting round is built for the configured blinds, not the default $10/$20
+        let betting_round = app.world().resource::<betting::BettingRound>();
+        assert_eq!((betting_round.big_blind, betting_round.min_raise), (50, 50));
+        app.update();
+        
+        let mut players = app.world_mut().query::<&Player>();
+        let mut players: Vec<Player> = players.iter(app.world()).cloned().collect();
+        players.sort_by_key(|p| p.id);
+        assert_eq!(players.iter().map(|p| p.id).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(players.iter().filter(|p| matches!(p.player_type, PlayerType::Human)).count(), 1);
+        
+        // Button on seat 0: blinds from seats 1 and 2, seat 3 is first to act
+        let game_position = app.world().resource::<game_state::GamePosition>();
+        assert_eq!(game_position.total_players, 6);
+        assert_eq!((game_position.get_small_blind_player(), game_position.get_big_blind_player()), (1, 2));
+        assert_eq!((players[1].current_bet, players[2].current_bet), (25, 50));
+        assert_eq!(players[2].chips, 1950);
+        assert_eq!(players[0].chips, 2000);
+        assert_eq!(game_position.get_betting_order(true), vec![3, 4, 5, 0, 1, 2]);
+    }
+}
@@ -0,0 +1,656 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+use crate::betting::BettingRound;
+use crate::cards::{Card, Deck, Rank, Suit};
+use crate::game_state::GameData;
+use crate::player::{Player, PlayerType};
+use crate::poker_rules::{evaluate_hand, HandRank};
+
+// Random run-outs used for the in-game equity estimate
+const EQUITY_ITERATIONS: usize = 1000;
+
+// Cached estimates kept before the equity cache starts over
+const MAX_EQUITY_CACHE_ENTRIES: usize = 4096;
+
+// Resource backing the "show odds" panel: pot odds, equity and outs for the human's hand
+#[derive(Resource, Default)]
+pub struct OddsOverlay {
+    pub enabled: bool,
+    pub pot_odds: f32,  // Share of the final pot the human must put in to call
+    pub pot_odds_ratio: (u32, u32), // The same odds as pot : call, e.g. 4:1
+    pub equity: f32,    // Monte Carlo estimate of winning against the live opponents
+    pub outs: usize,    // Unseen cards that turn a draw into a strong hand
+    pub outs_chance: f32, // Chance of hitting one of the outs by the river
+    pub equity_cache: EquityCache,
+}
+
+impl OddsOverlay {
+    pub fn refresh<R: Rng>(
+        &mut self,
+        hole_cards: &[Card],
+        community_cards: &[Card],
+        call_amount: u32,
+        pot: u32,
+        opponents: usize,
+        rng: &mut R,
+    ) {
+        let outs = count_outs(hole_cards, community_cards).len();
+        self.pot_odds = pot_odds(call_amount, pot);
+        self.pot_odds_ratio = pot_odds_ratio(call_amount, pot);
+        self.equity = self.equity_cache.estimate(hole_cards, community_cards, opponents, EQUITY_ITERATIONS, rng);
+        self.outs = outs;
+        self.outs_chance = outs_hit_probability(outs, community_cards.len());
+    }
+    
+    pub fn summary(&self) -> String {
+        let pot_odds = if self.pot_odds > 0.0 {
+            let (pot_share, call_share) = self.pot_odds_ratio;
+            format!("{}:{} ({:.0}% to call)", pot_share, call_share, self.pot_odds * 100.0)
+        } else {
+            "free to check".to_string()
+        };
+        format!(
+            "📊 Pot odds: {}\nEquity: ~{:.0}%\n{} outs ≈ {:.0}% by river",
+            pot_odds,
+            self.equity * 100.0,
+            self.outs,
+            self.outs_chance * 100.0,
+        )
+    }
+}
+
+// Marker for the odds panel text
+#[derive(Component)]
+pub struct OddsOverlayDisplay;
+
+// Plugin for the odds overlay
+pub struct OddsOverlayPlugin;
+
+impl Plugin for OddsOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<OddsOverlay>()
+            .add_systems(Startup, setup_odds_overlay)
+            .add_systems(Update, (handle_odds_input, update_odds_overlay).chain());
+    }
+}
+
+/// Fraction of the final pot a call contributes (0.0 when checking is free)
+pub fn pot_odds(call_amount: u32, pot: u32) -> f32 {
+    if call_amount == 0 {
+        return 0.0;
+    }
+    call_amount as f32 / (pot + call_amount) as f32
+}
+
+/// Pot odds as a pot : call ratio in lowest terms, e.g. (4, 1) for $20 into $80 ((0, 0) when checking is free)
+pub fn pot_odds_ratio(call_amount: u32, pot: u32) -> (u32, u32) {
+    if call_amount == 0 {
+        return (0, 0);
+    }
+    let divisor = greatest_common_divisor(pot, call_amount);
+    (pot / divisor, call_amount / divisor)
+}
+
+fn greatest_common_divisor(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { greatest_common_divisor(b, a % b) }
+}
+
+/// Unseen cards that improve a drawing hand to two pair or better without merely improving the board
+pub fn count_outs(hole_cards: &[Card], community_cards: &[Card]) -> Vec<Card> {
+    // Outs only make sense while there are cards still to come
+    if community_cards.len() < 3 || community_cards.len() >= 5 {
+        return Vec::new();
+    }
+    
+    let current_rank = evaluate_hand(hole_cards, community_cards).rank;
+    
+    unseen_cards(hole_cards, community_cards)
+        .into_iter()
+        .filter(|&card| {
+            let mut board = community_cards.to_vec();
+            board.push(card);
+            
+            let improved_rank = evaluate_hand(hole_cards, &board).rank;
+            let board_rank = evaluate_hand(&[], &board).rank;
+            improved_rank > current_rank && improved_rank >= HandRank::TwoPair && improved_rank > board_rank
+        })
+        .collect()
+}
+
+/// Ranks that would complete a straight using the hole cards, with the ace playing both high and low
+pub fn straight_draw_ranks(hole_cards: &[Card], community_cards: &[Card]) -> Vec<Rank> {
+    let mut all_cards = hole_cards.to_vec();
+    all_cards.extend_from_slice(community_cards);
+    if makes_straight(&all_cards) {
+        return Vec::new();
+    }
+    
+    ALL_RANKS
+        .into_iter()
+        .filter(|&rank| {
+            let card = Card::new(Suit::Spades, rank);
+            let mut with_card = all_cards.clone();
+            with_card.push(card);
+            let mut board_only = community_cards.to_vec();
+            board_only.push(card);
+            makes_straight(&with_card) && !makes_straight(&board_only)
+        })
+        .collect()
+}
+
+const ALL_RANKS: [Rank; 13] = [
+    Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six,
+    Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten,
+    Rank::Jack, Rank::Queen, Rank::King, Rank::Ace,
+];
+
+fn makes_straight(cards: &[Card]) -> bool {
+    // Index 1 is the low ace, 14 the high ace
+    let mut present = [false; 15];
+    for card in cards {
+        present[card.rank as usize] = true;
+        if card.rank == Rank::Ace {
+            present[1] = true;
+        }
+    }
+    
+    (1..=10).any(|low| (low..low + 5).all(|value| present[value]))
+}
+
+/// Chance of hitting at least one out by the river from the current street
+pub fn outs_hit_probability(outs: usize, community_len: usize) -> f32 {
+    let unseen = 52 - 2 - community_len;
+    let cards_to_come = 5usize.saturating_sub(community_len);
+    
+    // Probability of missing on every remaining card
+    let mut miss = 1.0;
+    for i in 0..cards_to_come {
+        let remaining = unseen - i;
+        miss *= remaining.saturating_sub(outs) as f32 / remaining as f32;
+    }
+    1.0 - miss
+}
+
+// Weighted set of two-card starting hands an opponent might hold
+#[derive(Debug, Clone, Default)]
+pub struct HandRange {
+    pub combos: Vec<([Card; 2], f32)>,
+}
+
+impl HandRange {
+    // Build a range by weighting every one of the 1326 starting-hand combos
+    pub fn from_weights(weight: impl Fn(Card, Card) -> f32) -> Self {
+        let cards = Deck::default().cards;
+        let mut combos = Vec::new();
+        for (i, &first) in cards.iter().enumerate() {
+            for &second in &cards[i + 1..] {
+                let combo_weight = weight(first, second);
+                if combo_weight > 0.0 {
+                    combos.push(([first, second], combo_weight));
+                }
+            }
+        }
+        Self { combos }
+    }
+    
+    // Uniform range; estimate_equity deals random hands directly, so this only checks it agrees
+    #[cfg(test)]
+    pub fn random() -> Self {
+        Self::from_weights(|_, _| 1.0)
+    }
+    
+    // Only the premiums: JJ+ and AK
+    pub fn premiums() -> Self {
+        Self::from_weights(|a, b| {
+            let pair = a.rank == b.rank && a.rank >= Rank::Jack;
+            let big_slick = a.rank.max(b.rank) == Rank::Ace && a.rank.min(b.rank) == Rank::King;
+            if pair || big_slick { 1.0 } else { 0.0 }
+        })
+    }
+    
+    // What a player who bets tends to hold: pairs, broadway cards and suited aces,
+    // plus a small share of everything else for bluffs
+    pub fn betting_range() -> Self {
+        Self::from_weights(|a, b| {
+            let pair = a.rank == b.rank;
+            let broadway = a.rank >= Rank::Ten && b.rank >= Rank::Ten;
+            let suited_ace = a.suit == b.suit && (a.rank == Rank::Ace || b.rank == Rank::Ace);
+            if pair || broadway || suited_ace { 1.0 } else { 0.15 }
+        })
+    }
+    
+    // The combos that don't use any of the dead cards, with running weight totals to sample from
+    fn live_combos(&self, dead_cards: &[Card]) -> LiveCombos {
+        let mut live = LiveCombos::default();
+        let mut total = 0.0;
+        for (combo, weight) in &self.combos {
+            if !dead_cards.contains(&combo[0]) && !dead_cards.contains(&combo[1]) {
+                total += weight;
+                live.combos.push(*combo);
+                live.cumulative_weights.push(total);
+            }
+        }
+        live
+    }
+}
+
+// A range with the known cards already taken out, built once per equity estimate
+#[derive(Debug, Default)]
+struct LiveCombos {
+    combos: Vec<[Card; 2]>,
+    cumulative_weights: Vec<f32>,
+}
+
+// Redraws allowed when a sampled combo collides with another opponent's cards
+const MAX_COMBO_REDRAWS: usize = 100;
+
+impl LiveCombos {
+    // Weighted draw of a combo that doesn't share a card with `taken`
+    fn sample<R: Rng>(&self, taken: &[Card], rng: &mut R) -> Option<[Card; 2]> {
+        let total = *self.cumulative_weights.last()?;
+        if total <= 0.0 {
+            return None;
+        }
+        
+        for _ in 0..MAX_COMBO_REDRAWS {
+            let pick = rng.gen::<f32>() * total;
+            let index = self.cumulative_weights.partition_point(|&weight| weight <= pick).min(self.combos.len() - 1);
+            let combo = self.combos[index];
+            if !taken.contains(&combo[0]) && !taken.contains(&combo[1]) {
+                return Some(combo);
+            }
+        }
+        None
+    }
+}
+
+/// Monte Carlo estimate of the share of the pot won against random opponent hands
+pub fn estimate_equity<R: Rng>(
+    hole_cards: &[Card],
+    community_cards: &[Card],
+    opponents: usize,
+    iterations: usize,
+    rng: &mut R,
+) -> f32 {
+    if opponents == 0 || iterations == 0 {
+        return 1.0;
+    }
+    
+    // Random hands need no weighting: deal the board and the opponents straight from the unseen cards
+    let mut deck = unseen_cards(hole_cards, community_cards);
+    let board_needed = 5usize.saturating_sub(community_cards.len());
+    let mut won = 0.0;
+    
+    for _ in 0..iterations {
+        let (dealt, _) = deck.partial_shuffle(rng, board_needed + opponents * 2);
+        let (board_rest, opponent_cards) = dealt.split_at(board_needed);
+        
+        let mut board = community_cards.to_vec();
+        board.extend_from_slice(board_rest);
+        won += pot_share(hole_cards, &board, opponent_cards.chunks(2));
+    }
+    
+    won / iterations as f32
+}
+
+// Share of the pot the hero takes against these hands on a complete board
+fn pot_share<'a>(hole_cards: &[Card], board: &[Card], opponent_hands: impl Iterator<Item = &'a [Card]>) -> f32 {
+    let hero = evaluate_hand(hole_cards, board);
+    let mut tied = 1;
+    for opponent_cards in opponent_hands {
+        match evaluate_hand(opponent_cards, board).cmp(&hero) {
+            std::cmp::Ordering::Greater => return 0.0,
+            std::cmp::Ordering::Equal => tied += 1,
+            std::cmp::Ordering::Less => {},
+        }
+    }
+    1.0 / tied as f32
+}
+
+/// Monte Carlo estimate of the share of the pot won when every opponent holds a hand from `range`
+pub fn estimate_equity_vs_range<R: Rng>(
+    hole_cards: &[Card],
+    community_cards: &[Card],
+    range: &HandRange,
+    opponents: usize,
+    iterations: usize,
+    rng: &mut R,
+) -> f32 {
+    if opponents == 0 || iterations == 0 {
+        return 1.0;
+    }
+    
+    // The range and the unseen cards are narrowed by the known cards once, not on every run-out
+    let known_cards: Vec<Card> = hole_cards.iter().chain(community_cards).copied().collect();
+    let live_combos = range.live_combos(&known_cards);
+    let unseen = unseen_cards(hole_cards, community_cards);
+    let board_needed = 5usize.saturating_sub(community_cards.len());
+    let mut won = 0.0;
+    let mut completed = 0;
+    
+    for _ in 0..iterations {
+        let mut opponent_cards: Vec<Card> = Vec::with_capacity(opponents * 2);
+        for _ in 0..opponents {
+            let Some(combo) = live_combos.sample(&opponent_cards, rng) else {
+                break;
+            };
+            opponent_cards.extend_from_slice(&combo);
+        }
+        if opponent_cards.len() < opponents * 2 {
+            continue; // Range exhausted by the dead cards
+        }
+        
+        let mut board = community_cards.to_vec();
+        board.extend(unseen.iter().filter(|card| !opponent_cards.contains(card)).choose_multiple(rng, board_needed));
+        won += pot_share(hole_cards, &board, opponent_cards.chunks(2));
+        completed += 1;
+    }
+    
+    if completed == 0 {
+        return 1.0;
+    }
+    won / completed as f32
+}
+
+// Every way of relabeling the four suits, as the new suit index for each old one
+fn suit_permutations() -> Vec<[usize; 4]> {
+    (0..256)
+        .map(|n| [n % 4, n / 4 % 4, n / 16 % 4, n / 64])
+        .filter(|perm| (0..4).all(|suit| perm.contains(&suit)))
+        .collect()
+}
+
+// Memoized equity estimates, shared between spots that are identical up to suit relabeling
+#[derive(Debug, Default)]
+pub struct EquityCache {
+    entries: HashMap<(Vec<Card>, usize, usize), f32>,
+}
+
+impl EquityCache {
+    /// Cache key: hole cards then board, each sorted, under whichever suit relabeling gives the
+    /// smallest result, so spots that only differ by swapping suits share a key
+    pub fn key(hole_cards: &[Card], community_cards: &[Card]) -> Vec<Card> {
+        const SUITS: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
+        let relabeled = |cards: &[Card], perm: &[usize; 4]| {
+            let mut cards: Vec<Card> = cards.iter().map(|card| Card::new(SUITS[perm[card.suit as usize]], card.rank)).collect();
+            cards.sort_by_key(|card| (card.rank, card.suit as u8));
+            cards
+        };
+        
+        suit_permutations()
+            .iter()
+            .map(|perm| {
+                let mut cards = relabeled(hole_cards, perm);
+                cards.extend(relabeled(community_cards, perm));
+                cards
+            })
+            .min_by_key(|cards| cards.iter().map(|card| (card.rank, card.suit as u8)).collect::<Vec<_>>())
+            .unwrap_or_default()
+    }
+    
+    pub fn estimate<R: Rng>(
+        &mut self,
+        hole_cards: &[Card],
+        community_cards: &[Card],
+        opponents: usize,
+        iterations: usize,
+        rng: &mut R,
+    ) -> f32 {
+        let key = (Self::key(hole_cards, community_cards), hole_cards.len(), opponents);
+        if let Some(&equity) = self.entries.get(&key) {
+            return equity;
+        }
+        
+        if self.entries.len() >= MAX_EQUITY_CACHE_ENTRIES {
+            self.entries.clear();
+        }
+        let equity = estimate_equity(hole_cards, community_cards, opponents, iterations, rng);
+        self.entries.insert(key, equity);
+        equity
+    }
+}
+
+fn unseen_cards(hole_cards: &[Card], community_cards: &[Card]) -> Vec<Card> {
+    Deck::default()
+        .cards
+        .into_iter()
+        .filter(|card| !hole_cards.contains(card) && !community_cards.contains(card))
+        .collect()
+}
+
+fn setup_odds_overlay(mut commands: Commands) {
+    // Odds panel (top left corner), hidden until enabled
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 14.0,
+                color: Color::srgb(1.0, 1.0, 0.8),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(10.0),
+            ..default()
+        }),
+        OddsOverlayDisplay,
+    ));
+}
+
+// System to toggle the odds overlay with O
+fn handle_odds_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut odds_overlay: ResMut<OddsOverlay>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyO) {
+        odds_overlay.enabled = !odds_overlay.enabled;
+        info!("📊 Odds overlay: {}", if odds_overlay.enabled { "SHOWN" } else { "HIDDEN" });
+    }
+}
+
+// System to recompute the odds on the human's turn and whenever new board cards land
+pub fn update_odds_overlay(
+    mut odds_overlay: ResMut<OddsOverlay>,
+    game_data: Res<GameData>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+    mut display_query: Query<&mut Text, With<OddsOverlayDisplay>>,
+    mut last_seen: Local<Option<(usize, bool)>>,
+) {
+    if !odds_overlay.enabled {
+        if odds_overlay.is_changed() {
+            if let Ok(mut text) = display_query.get_single_mut() {
+                text.sections[0].value = "".to_string();
+            }
+            *last_seen = None;
+        }
+        return;
+    }
+    
+    let Some(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)) else {
+        return; // No human at the table (e.g. an AI-only simulation)
+    };
+    if human_player.hole_cards.is_empty() || human_player.has_folded {
+        return;
+    }
+    
+    let human_to_act = betting_round.peek_next_player() == Some(human_player.id);
+    let seen = (game_data.community_cards.len(), human_to_act);
+    let board_changed = last_seen.is_none_or(|(board_len, _)| board_len != seen.0);
+    let turn_started = human_to_act && last_seen.is_none_or(|(_, was_to_act)| !was_to_act);
+    *last_seen = Some(seen);
+    
+    if !board_changed && !turn_started {
+        return;
+    }
+    
+    let opponents = players
+        .iter()
+        .filter(|p| p.id != human_player.id && !p.has_folded)
+        .count();
+    let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+    odds_overlay.refresh(
+        &human_player.hole_cards,
+        &game_data.community_cards,
+        call_amount,
+        betting_round.pot,
+        opponents,
+        &mut thread_rng(),
+    );
+    
+    if let Ok(mut text) = display_query.get_single_mut() {
+        text.sections[0].value = odds_overlay.summary();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    
+    #[test]
+    fn test_flush_draw_flop_reports_nine_outs_and_equity() {
+        let hole = vec![
+            Card::new(Suit::Hearts, Rank::Four),
+            Card::new(Suit::Hearts, Rank::Three),
+        ];
+        let flop = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+        
+        let mut odds_overlay = OddsOverlay::default();
+        odds_overlay.refresh(&hole, &flop, 20, 60, 2, &mut StdRng::seed_from_u64(7));
+        
+        assert_eq!(odds_overlay.outs, 9);
+        assert!((odds_overlay.outs_chance - 0.35).abs() < 0.01);
+        assert!((odds_overlay.pot_odds - 0.25).abs() < f32::EPSILON);
+        assert!(
+            (0.30..=0.45).contains(&odds_overlay.equity),
+            "equity {} not near 35%",
+            odds_overlay.equity
+        );
+        assert!(odds_overlay.summary().contains("9 outs ≈ 35% by river"));
+    }
+    
+    #[test]
+    fn test_pot_odds_ratio_is_reduced_and_shown_with_the_percentage() {
+        // $20 to call into $80 makes a $100 pot: 4:1, or 20% of the final pot
+        assert_eq!(pot_odds_ratio(20, 80), (4, 1));
+        assert!((pot_odds(20, 80) - 0.20).abs() < f32::EPSILON);
+        assert_eq!(pot_odds_ratio(30, 45), (3, 2));
+        assert_eq!(pot_odds_ratio(0, 80), (0, 0));
+        
+        let mut odds_overlay = OddsOverlay::default();
+        let hole = [Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::King)];
+        odds_overlay.refresh(&hole, &[], 20, 80, 1, &mut StdRng::seed_from_u64(3));
+        assert!(odds_overlay.summary().starts_with("📊 Pot odds: 4:1 (20% to call)"), "{}", odds_overlay.summary());
+    }
+    
+    #[test]
+    fn test_suit_isomorphic_spots_share_an_equity_cache_entry() {
+        let hearts = [Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Hearts, Rank::King)];
+        let rainbow = [
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Nine),
+        ];
+        let spades = [Card::new(Suit::Spades, Rank::King), Card::new(Suit::Spades, Rank::Ace)];
+        let relabeled_rainbow = [
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Seven),
+        ];
+        assert_eq!(EquityCache::key(&hearts, &rainbow), EquityCache::key(&spades, &relabeled_rainbow));
+        
+        // Two of the suited hand's hearts on board is a different spot (a flush draw)
+        let two_tone = [
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Nine),
+        ];
+        assert_ne!(EquityCache::key(&hearts, &rainbow), EquityCache::key(&hearts, &two_tone));
+        
+        // A pocket pair with a board card in one of its suits: which of the pair's suits it shares doesn't matter
+        let hearts_spades = [Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Spades, Rank::Ace)];
+        let spades_hearts = [Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::Ace)];
+        assert_eq!(
+            EquityCache::key(&hearts_spades, &[Card::new(Suit::Hearts, Rank::King)]),
+            EquityCache::key(&spades_hearts, &[Card::new(Suit::Spades, Rank::King)])
+        );
+        assert_eq!(
+            EquityCache::key(&hearts_spades, &[Card::new(Suit::Hearts, Rank::King)]),
+            EquityCache::key(&hearts_spades, &[Card::new(Suit::Spades, Rank::King)])
+        );
+        assert_ne!(
+            EquityCache::key(&hearts_spades, &[Card::new(Suit::Hearts, Rank::King)]),
+            EquityCache::key(&hearts_spades, &[Card::new(Suit::Clubs, Rank::King)])
+        );
+        
+        let mut cache = EquityCache::default();
+        let mut rng = StdRng::seed_from_u64(2);
+        let first = cache.estimate(&hearts, &rainbow, 1, 200, &mut rng);
+        let second = cache.estimate(&spades, &relabeled_rainbow, 1, 200, &mut rng);
+        assert_eq!(first, second);
+        assert_eq!(cache.entries.len(), 1);
+    }
+    
+    #[test]
+    fn test_wheel_draw_counts_the_five() {
+        let hole = vec![
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::Two),
+        ];
+        let flop = vec![
+            Card::new(Suit::Clubs, Rank::Three),
+            Card::new(Suit::Hearts, Rank::Four),
+            Card::new(Suit::Spades, Rank::King),
+        ];
+        
+        assert_eq!(straight_draw_ranks(&hole, &flop), vec![Rank::Five]);
+        let outs = count_outs(&hole, &flop);
+        assert_eq!(outs.iter().filter(|card| card.rank == Rank::Five).count(), 4);
+        assert_eq!(outs.len(), 4);
+    }
+    
+    #[test]
+    fn test_broadway_draw_counts_the_ten() {
+        let hole = vec![
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::King),
+        ];
+        let flop = vec![
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Spades, Rank::Three),
+        ];
+        
+        assert_eq!(straight_draw_ranks(&hole, &flop), vec![Rank::Ten]);
+        let outs = count_outs(&hole, &flop);
+        assert_eq!(outs.iter().filter(|card| card.rank == Rank::Ten).count(), 4);
+    }
+    
+    #[test]
+    fn test_medium_hand_has_less_equity_against_premiums_than_random() {
+        let nines = vec![
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Diamonds, Rank::Nine),
+        ];
+        let mut rng = StdRng::seed_from_u64(11);
+        
+        let vs_random = estimate_equity_vs_range(&nines, &[], &HandRange::random(), 1, 500, &mut rng);
+        let vs_premiums = estimate_equity_vs_range(&nines, &[], &HandRange::premiums(), 1, 500, &mut rng);
+        
+        assert!(vs_random > 0.6, "99 vs random was {}", vs_random);
+        let dealt_direct = estimate_equity(&nines, &[], 1, 500, &mut rng);
+        assert!((vs_random - dealt_direct).abs() < 0.06, "range {} vs direct deal {}", vs_random, dealt_direct);
+        assert!(vs_premiums < 0.35, "99 vs premiums was {}", vs_premiums);
+        // 6 combos of each pair JJ-AA plus 16 AK
+        assert_eq!(HandRange::premiums().combos.len(), 4 * 6 + 16);
+    }
+}
@@ -22,6 +22,7 @@ pub struct Player {
     pub chips: u32,
     pub hole_cards: Vec<Card>,
     pub current_bet: u32,
+    pub committed: u32, // Chips put in on earlier streets this hand
     pub has_folded: bool,
     pub position: Vec3, // For rendering position
 }
@@ -34,6 +35,7 @@ impl Player {
             chips,
             hole_cards: Vec::new(),
             current_bet: 0,
+            committed: 0,
             has_folded: false,
             position,
         }
@@ -60,6 +62,18 @@ impl Player {
         self.has_folded = false;
     }
     
+    // Clear everything that belongs to the last hand; the chip stack carries over
+    pub fn reset_for_new_hand(&mut self) {
+        self.clear_hand();
+        self.current_bet = 0;
+        self.committed = 0;
+    }
+    
+    // Everything this player has put into the pot this hand
+    pub fn total_contribution(&self) -> u32 {
+        self.committed + self.current_bet
+    }
+    
     pub fn fold(&mut self) {
         self.has_folded = true;
     }
@@ -80,8 +94,32 @@ pub struct AIPlayer {
     pub difficulty: AIDifficulty,
 }
 
-#[derive(Debug, Clone, Copy)]
+// Seats evenly around an oval table, clockwise from the human at the bottom
+pub fn seat_positions(total_players: u32) -> Vec<Vec3> {
+    (0..total_players)
+        .map(|seat| {
+            let angle = -std::f32::consts::FRAC_PI_2 - std::f32::consts::TAU * seat as f32 / total_players as f32;
+            Vec3::new(346.0 * angle.cos(), 200.0 * angle.sin(), 0.0).round()
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AIDifficulty {
     Beginner,
     Intermediate,
+    Advanced,
+    Expert, // Plays from simulated equity instead of hand tiers
+}
+
+impl AIDifficulty {
+    // Next level when cycling, wrapping back to Beginner
+    pub fn next(self) -> Self {
+        match self {
+            AIDifficulty::Beginner => AIDifficulty::Intermediate,
+            AIDifficulty::Intermediate => AIDifficulty::Advanced,
+            AIDifficulty::Advanced => AIDifficulty::Expert,
+            AIDifficulty::Expert => AIDifficulty::Beginner,
+        }
+    }
 }
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use crate::cards::{Card, Suit, Rank};
 use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandRank {
@@ -16,14 +18,24 @@ pub enum HandRank {
     RoyalFlush = 10,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct HandEvaluation {
     pub rank: HandRank,
     pub primary_value: u8,    // Main value (e.g., pair rank, high card)
     pub secondary_value: u8,  // Secondary value (e.g., kicker, second pair)
     pub kickers: Vec<u8>,     // Remaining cards for tie-breaking
+    pub best_five: Vec<Card>, // The cards that make the hand, high to low (the wheel's ace last)
 }
 
+// Hands are equal when they rank the same, whichever physical cards make them
+impl PartialEq for HandEvaluation {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for HandEvaluation {}
+
 impl PartialOrd for HandEvaluation {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -60,11 +72,150 @@ pub fn evaluate_hand(hole_cards: &[Card], community_cards: &[Card]) -> HandEvalu
     all_cards.extend_from_slice(hole_cards);
     all_cards.extend_from_slice(community_cards);
     
-    // Find the best 5-card hand from available cards
-    let best_hand = find_best_five_card_hand(&all_cards);
+    // Pick the best 5-card hand from rank and suit counts, then score just those five
+    let best_hand = pick_best_five_cards(&all_cards);
+    with_eval_totals(|totals| totals.hand_evaluations.fetch_add(1, Ordering::Relaxed));
     evaluate_five_card_hand(&best_hand)
 }
 
+/// Diagnostics: how much evaluation work the current hand has done
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvalStats {
+    pub hand_evaluations: usize,      // Full `evaluate_hand` / `evaluate_omaha` runs, cached or not
+    pub five_card_evaluations: usize, // Individual five-card hands scored; Omaha scores up to 60 per hand
+}
+
+// Running totals of every evaluation since startup, whoever asked for it
+struct EvalTotals {
+    hand_evaluations: AtomicUsize,
+    five_card_evaluations: AtomicUsize,
+}
+
+impl EvalTotals {
+    const fn new() -> Self {
+        Self {
+            hand_evaluations: AtomicUsize::new(0),
+            five_card_evaluations: AtomicUsize::new(0),
+        }
+    }
+    
+    fn snapshot(&self) -> EvalStats {
+        EvalStats {
+            hand_evaluations: self.hand_evaluations.load(Ordering::Relaxed),
+            five_card_evaluations: self.five_card_evaluations.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(not(test))]
+static EVAL_TOTALS: EvalTotals = EvalTotals::new();
+
+#[cfg(not(test))]
+fn with_eval_totals<R>(f: impl FnOnce(&EvalTotals) -> R) -> R {
+    f(&EVAL_TOTALS)
+}
+
+// Tests run in parallel, so each test thread keeps its own totals to assert exact counts against
+#[cfg(test)]
+thread_local! {
+    static EVAL_TOTALS: EvalTotals = const { EvalTotals::new() };
+}
+
+#[cfg(test)]
+fn with_eval_totals<R>(f: impl FnOnce(&EvalTotals) -> R) -> R {
+    EVAL_TOTALS.with(f)
+}
+
+/// Memoizes hand evaluations for the current hand, keyed by the sorted set of cards
+#[derive(Debug, Default)]
+pub struct HandEvalCache {
+    state: Mutex<HandEvalCacheState>,
+    pub disabled: bool, // Evaluate every lookup afresh, e.g. to measure what the cache saves
+}
+
+#[derive(Debug, Default)]
+struct HandEvalCacheState {
+    entries: HashMap<Vec<Card>, HandEvaluation>,
+    baseline: EvalStats, // Running totals when the current hand started
+}
+
+impl HandEvalCache {
+    pub fn evaluate(&self, hole_cards: &[Card], community_cards: &[Card]) -> HandEvaluation {
+        if self.disabled {
+            return evaluate_hand(hole_cards, community_cards);
+        }
+        
+        // The best five cards don't depend on which cards are hole vs board, so order-independent key
+        let mut key: Vec<Card> = hole_cards.iter().chain(community_cards).copied().collect();
+        key.sort_by_key(|card| (card.rank, card.suit as u8));
+        
+        let cached = self.state.lock().unwrap().entries.get(&key).cloned();
+        if let Some(evaluation) = cached {
+            return evaluation;
+        }
+        
+        // Evaluate without holding the lock; two systems racing on the same hand just both compute it
+        let evaluation = evaluate_hand(hole_cards, community_cards);
+        self.state.lock().unwrap().entries.insert(key, evaluation.clone());
+        evaluation
+    }
+    
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+    
+    // Every evaluation since the hand started, including ones that never went through the cache
+    pub fn stats(&self) -> EvalStats {
+        let totals = with_eval_totals(EvalTotals::snapshot);
+        let baseline = self.state.lock().unwrap().baseline;
+        EvalStats {
+            hand_evaluations: totals.hand_evaluations - baseline.hand_evaluations,
+            five_card_evaluations: totals.five_card_evaluations - baseline.five_card_evaluations,
+        }
+    }
+    
+    // Start a new hand: forget cached evaluations and restart the counters from here
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.baseline = with_eval_totals(EvalTotals::snapshot);
+    }
+}
+
+/// Evaluate two sets of hole cards against the same board and report which wins
+pub fn compare_hands(hole_a: &[Card], hole_b: &[Card], community_cards: &[Card]) -> std::cmp::Ordering {
+    let evaluation_a = evaluate_hand(hole_a, community_cards);
+    let evaluation_b = evaluate_hand(hole_b, community_cards);
+    evaluation_a.cmp(&evaluation_b)
+}
+
+/// Evaluate an Omaha hand: exactly two of the four hole cards plus exactly three board cards
+pub fn evaluate_omaha(hole_cards: &[Card], community_cards: &[Card]) -> HandEvaluation {
+    let mut best: Option<HandEvaluation> = None;
+    for hole_pair in combinations(hole_cards, 2) {
+        for board_three in combinations(community_cards, 3) {
+            let mut hand = hole_pair.clone();
+            hand.extend(board_three);
+            let evaluation = evaluate_five_card_hand(&hand);
+            if best.as_ref().is_none_or(|best| evaluation > *best) {
+                best = Some(evaluation);
+            }
+        }
+    }
+    
+    // Before the flop there is no legal five-card hand yet; rank the hole cards alone
+    match best {
+        Some(best) => {
+            with_eval_totals(|totals| totals.hand_evaluations.fetch_add(1, Ordering::Relaxed));
+            best
+        }
+        None => evaluate_hand(hole_cards, community_cards),
+    }
+}
+
+// Brute force over every five-card combination; the reference `pick_best_five_cards` is checked against
+#[cfg(test)]
 fn find_best_five_card_hand(cards: &[Card]) -> Vec<Card> {
     if cards.len() <= 5 {
         return cards.to_vec();
@@ -76,6 +227,7 @@ fn find_best_five_card_hand(cards: &[Card]) -> Vec<Card> {
         primary_value: 0,
         secondary_value: 0,
         kickers: vec![],
+        best_five: vec![],
     };
     
     // Generate all possible 5-card combinations
@@ -90,6 +242,79 @@ fn find_best_five_card_hand(cards: &[Card]) -> Vec<Card> {
     best_hand
 }
 
+/// The best five of `cards`, found from one pass of rank and suit counts instead of trying every
+/// combination: flushes by suit count, straights by a bitmask of the ranks present
+fn pick_best_five_cards(cards: &[Card]) -> Vec<Card> {
+    if cards.len() <= 5 {
+        return cards.to_vec();
+    }
+    
+    let mut sorted = cards.to_vec();
+    sorted.sort_by_key(|card| std::cmp::Reverse(card.rank));
+    
+    let mut suit_counts: HashMap<Suit, usize> = HashMap::new();
+    for card in &sorted {
+        *suit_counts.entry(card.suit).or_insert(0) += 1;
+    }
+    let flush_cards: Option<Vec<Card>> = suit_counts
+        .into_iter()
+        .find(|&(_, count)| count >= 5)
+        .map(|(suit, _)| sorted.iter().filter(|card| card.suit == suit).copied().collect());
+    
+    if let Some(straight_flush) = flush_cards.as_deref().and_then(straight_cards) {
+        return straight_flush;
+    }
+    
+    // Ranks grouped by how many of each there are, biggest groups then highest ranks first
+    let mut groups: Vec<(usize, Rank)> = Vec::new();
+    for card in &sorted {
+        match groups.iter_mut().find(|(_, rank)| *rank == card.rank) {
+            Some((count, _)) => *count += 1,
+            None => groups.push((1, card.rank)),
+        }
+    }
+    groups.sort_by(|a, b| b.cmp(a));
+    
+    let of_rank = |rank: Rank, n: usize| sorted.iter().filter(move |card| card.rank == rank).take(n).copied();
+    // The made part of the hand plus the highest other cards to fill it out to five
+    let with_kickers = |mut made: Vec<Card>| {
+        let used: Vec<Rank> = made.iter().map(|card| card.rank).collect();
+        made.extend(sorted.iter().filter(|card| !used.contains(&card.rank)).take(5 - made.len()));
+        made
+    };
+    
+    match groups.as_slice() {
+        [(4, quads), ..] => with_kickers(of_rank(*quads, 4).collect()),
+        [(3, trips), (count, pair), ..] if *count >= 2 => of_rank(*trips, 3).chain(of_rank(*pair, 2)).collect(),
+        _ if flush_cards.is_some() => flush_cards.unwrap_or_default().into_iter().take(5).collect(),
+        _ if straight_cards(&sorted).is_some() => straight_cards(&sorted).unwrap_or_default(),
+        [(3, trips), ..] => with_kickers(of_rank(*trips, 3).collect()),
+        [(2, high_pair), (2, low_pair), ..] => with_kickers(of_rank(*high_pair, 2).chain(of_rank(*low_pair, 2)).collect()),
+        [(2, pair), ..] => with_kickers(of_rank(*pair, 2).collect()),
+        _ => sorted.into_iter().take(5).collect(),
+    }
+}
+
+// The highest straight among `cards` (sorted high to low), one card per rank; the ace can play low
+fn straight_cards(cards: &[Card]) -> Option<Vec<Card>> {
+    let mut present: u16 = 0;
+    for card in cards {
+        present |= 1 << (card.rank as u8);
+        if card.rank == Rank::Ace {
+            present |= 1 << 1;
+        }
+    }
+    
+    let high = (5..=14u8).rev().find(|&high| present & (0b11111 << (high - 4)) == 0b11111 << (high - 4))?;
+    (high - 4..=high)
+        .rev()
+        .map(|value| {
+            let value = if value == 1 { 14 } else { value };
+            cards.iter().find(|card| card.rank as u8 == value).copied()
+        })
+        .collect()
+}
+
 fn combinations(cards: &[Card], k: usize) -> Vec<Vec<Card>> {
     if k == 0 {
         return vec![vec![]];
@@ -116,6 +341,7 @@ fn combinations(cards: &[Card], k: usize) -> Vec<Vec<Card>> {
 }
 
 fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
+    with_eval_totals(|totals| totals.five_card_evaluations.fetch_add(1, Ordering::Relaxed));
     if cards.len() != 5 {
         error!("Hand evaluation requires exactly 5 cards, got {}", cards.len());
         return HandEvaluation {
@@ -123,6 +349,7 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
             primary_value: 0,
             secondary_value: 0,
             kickers: vec![0; 5],
+            best_five: cards.to_vec(),
         };
     }
     
@@ -140,14 +367,22 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
     
     let is_flush = suits.iter().all(|&s| s == suits[0]);
     let is_straight = is_straight_hand(&ranks);
+    // In the wheel (A-2-3-4-5) the ace plays low, so the straight is five-high
+    let is_wheel = ranks == [14, 5, 4, 3, 2];
+    let straight_high = if is_wheel { 5 } else { ranks[0] };
+    let mut best_five = sorted_cards;
+    if is_straight && is_wheel {
+        best_five.rotate_left(1);
+    }
     
     // Check for royal flush
-    if is_flush && is_straight && ranks[0] == 14 { // Ace high straight
+    if is_flush && is_straight && straight_high == 14 { // Ace high straight
         return HandEvaluation {
             rank: HandRank::RoyalFlush,
             primary_value: 14,
             secondary_value: 0,
             kickers: vec![],
+            best_five,
         };
     }
     
@@ -155,9 +390,10 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
     if is_flush && is_straight {
         return HandEvaluation {
             rank: HandRank::StraightFlush,
-            primary_value: ranks[0],
+            primary_value: straight_high,
             secondary_value: 0,
             kickers: vec![],
+            best_five,
         };
     }
     
@@ -180,6 +416,7 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
             primary_value: *quad_rank,
             secondary_value: 0,
             kickers: vec![*kicker],
+            best_five,
         },
         
         // Full house
@@ -188,6 +425,7 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
             primary_value: *trip_rank,
             secondary_value: *pair_rank,
             kickers: vec![],
+            best_five,
         },
         
         // Three of a kind
@@ -199,6 +437,7 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
                 primary_value: *trip_rank,
                 secondary_value: 0,
                 kickers,
+                best_five,
             }
         },
         
@@ -214,6 +453,7 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
                 primary_value: high,
                 secondary_value: low,
                 kickers: vec![*kicker],
+                best_five,
             }
         },
         
@@ -226,6 +466,7 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
                 primary_value: *pair_rank,
                 secondary_value: 0,
                 kickers,
+                best_five,
             }
         },
         
@@ -237,13 +478,15 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
                     primary_value: ranks[0],
                     secondary_value: 0,
                     kickers: ranks[1..].to_vec(),
+                    best_five,
                 }
             } else if is_straight {
                 HandEvaluation {
                     rank: HandRank::Straight,
-                    primary_value: ranks[0],
+                    primary_value: straight_high,
                     secondary_value: 0,
                     kickers: vec![],
+                    best_five,
                 }
             } else {
                 HandEvaluation {
@@ -251,6 +494,7 @@ fn evaluate_five_card_hand(cards: &[Card]) -> HandEvaluation {
                     primary_value: ranks[0],
                     secondary_value: 0,
                     kickers: ranks[1..].to_vec(),
+                    best_five,
                 }
             }
         }
@@ -291,6 +535,35 @@ pub fn hand_rank_name(rank: &HandRank) -> &'static str {
     }
 }
 
+/// The five cards that make the best Hold'em hand, highest rank first (the wheel's ace last)
+pub fn best_five_cards(hole_cards: &[Card], community_cards: &[Card]) -> Vec<Card> {
+    evaluate_hand(hole_cards, community_cards).best_five
+}
+
+/// Plain-English name of a made hand, e.g. "Flush, King high" or "Full House, Kings full of Sevens"
+pub fn describe_evaluation(evaluation: &HandEvaluation) -> String {
+    let primary = evaluation.primary_value;
+    let secondary = evaluation.secondary_value;
+    let name = hand_rank_name(&evaluation.rank);
+    match evaluation.rank {
+        HandRank::HighCard | HandRank::Straight | HandRank::Flush | HandRank::StraightFlush => {
+            format!("{}, {} high", name, rank_name(primary))
+        },
+        HandRank::OnePair | HandRank::ThreeOfAKind | HandRank::FourOfAKind => {
+            format!("{}, {}", name, plural_rank_name(primary))
+        },
+        HandRank::TwoPair => format!("{}, {} and {}", name, plural_rank_name(primary), plural_rank_name(secondary)),
+        HandRank::FullHouse => format!("{}, {} full of {}", name, plural_rank_name(primary), plural_rank_name(secondary)),
+        HandRank::RoyalFlush => name.to_string(),
+    }
+}
+
+// "Kings", "Sixes"
+fn plural_rank_name(rank: u8) -> String {
+    let name = rank_name(rank);
+    if name.ends_with('x') { format!("{}es", name) } else { format!("{}s", name) }
+}
+
 pub fn rank_name(rank: u8) -> &'static str {
     match rank {
         2 => "Two",
@@ -343,4 +616,372 @@ mod tests {
         assert_eq!(eval.rank, HandRank::OnePair);
         assert_eq!(eval.primary_value, 14); // Ace
     }
+    
+    #[test]
+    fn test_compare_hands_set_beats_two_pair() {
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::King),
+            Card::new(Suit::Hearts, Rank::Jack),
+        ];
+        let set = vec![
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Diamonds, Rank::Nine),
+        ];
+        let two_pair = vec![
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Clubs, Rank::Jack),
+        ];
+        
+        assert_eq!(compare_hands(&set, &two_pair, &board), std::cmp::Ordering::Greater);
+        assert_eq!(compare_hands(&two_pair, &set, &board), std::cmp::Ordering::Less);
+    }
+    
+    #[test]
+    fn test_full_house_trips_rank_decides_before_pair_rank() {
+        // KKK22 beats QQQAA: the trips rank counts first, however big the other pair is
+        let kings_full = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Diamonds, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Two),
+        ];
+        let queens_full = vec![
+            Card::new(Suit::Hearts, Rank::Queen),
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Diamonds, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Ace),
+        ];
+        
+        let kings_eval = evaluate_five_card_hand(&kings_full);
+        let queens_eval = evaluate_five_card_hand(&queens_full);
+        assert_eq!(kings_eval.rank, HandRank::FullHouse);
+        assert_eq!((kings_eval.primary_value, kings_eval.secondary_value), (13, 2));
+        assert_eq!((queens_eval.primary_value, queens_eval.secondary_value), (12, 14));
+        assert_eq!(compare_hands(&kings_full, &queens_full, &[]), std::cmp::Ordering::Greater);
+        assert_eq!(compare_hands(&queens_full, &kings_full, &[]), std::cmp::Ordering::Less);
+    }
+    
+    #[test]
+    fn test_full_house_with_same_trips_is_decided_by_the_pair() {
+        // Trip kings on board: pocket threes make kings full of threes, pocket twos only kings full of twos
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Diamonds, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Four),
+        ];
+        let threes = vec![
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Clubs, Rank::Three),
+        ];
+        let twos = vec![
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+        assert_eq!(compare_hands(&threes, &twos, &board), std::cmp::Ordering::Greater);
+        
+        // Two sets of trips among seven cards play as the higher trips full of the lower
+        let queens = vec![
+            Card::new(Suit::Diamonds, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Queen),
+        ];
+        let two_trips_board = vec![
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Diamonds, Rank::Ace),
+        ];
+        let eval = evaluate_hand(&queens, &two_trips_board);
+        assert_eq!(eval.rank, HandRank::FullHouse);
+        assert_eq!((eval.primary_value, eval.secondary_value), (13, 12));
+    }
+    
+    #[test]
+    fn test_describe_evaluation_names_the_hand() {
+        let full_house = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Diamonds, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Six),
+        ];
+        assert_eq!(describe_evaluation(&evaluate_five_card_hand(&full_house)), "Full House, Kings full of Sixes");
+        
+        let hole = vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Clubs, Rank::Two)];
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Four),
+            Card::new(Suit::Hearts, Rank::Three),
+        ];
+        assert_eq!(describe_evaluation(&evaluate_hand(&hole, &board)), "One Pair, Aces");
+        let best: Vec<Rank> = best_five_cards(&hole, &board).iter().map(|card| card.rank).collect();
+        assert_eq!(best, vec![Rank::Ace, Rank::Ace, Rank::Nine, Rank::Seven, Rank::Four]);
+    }
+    
+    #[test]
+    fn test_evaluation_keeps_the_five_cards_that_make_the_hand() {
+        // Six hearts across hand and board: the flush is the five highest of them
+        let hole = vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Hearts, Rank::Three)];
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Spades, Rank::King),
+        ];
+        let evaluation = evaluate_hand(&hole, &board);
+        assert_eq!(evaluation.rank, HandRank::Flush);
+        assert!(evaluation.best_five.iter().all(|card| card.suit == Suit::Hearts));
+        let ranks: Vec<Rank> = evaluation.best_five.iter().map(|card| card.rank).collect();
+        assert_eq!(ranks, vec![Rank::Ace, Rank::Jack, Rank::Nine, Rank::Six, Rank::Three]);
+        
+        // In the wheel the ace is the low card
+        let wheel_hole = vec![Card::new(Suit::Clubs, Rank::Ace), Card::new(Suit::Diamonds, Rank::Two)];
+        let wheel_board = vec![
+            Card::new(Suit::Hearts, Rank::Three),
+            Card::new(Suit::Spades, Rank::Four),
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Diamonds, Rank::Jack),
+            Card::new(Suit::Hearts, Rank::King),
+        ];
+        let wheel = evaluate_hand(&wheel_hole, &wheel_board);
+        assert_eq!(wheel.rank, HandRank::Straight);
+        let ranks: Vec<Rank> = wheel.best_five.iter().map(|card| card.rank).collect();
+        assert_eq!(ranks, vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]);
+        
+        // Equal hands stay equal whichever cards make them
+        let other_wheel = evaluate_hand(
+            &[Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::Two)],
+            &wheel_board,
+        );
+        assert_eq!(wheel, other_wheel);
+        assert_ne!(wheel.best_five, other_wheel.best_five);
+    }
+    
+    #[test]
+    fn test_counting_evaluator_agrees_with_brute_force() {
+        use rand::prelude::*;
+        use rand::rngs::StdRng;
+        use crate::cards::Deck;
+        
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut deck = Deck::default().cards;
+        for _ in 0..3000 {
+            deck.shuffle(&mut rng);
+            let seven = &deck[..7];
+            let brute_force = evaluate_five_card_hand(&find_best_five_card_hand(seven));
+            let counted = evaluate_hand(&seven[..2], &seven[2..]);
+            assert_eq!(counted, brute_force, "{:?}", seven);
+            assert_eq!(counted.rank, brute_force.rank, "{:?}", seven);
+            assert!(counted.best_five.iter().all(|card| seven.contains(card)));
+        }
+    }
+    
+    #[test]
+    fn test_compare_hands_board_plays_is_equal() {
+        // Broadway on the board plays for both players
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Diamonds, Rank::Jack),
+            Card::new(Suit::Hearts, Rank::Ten),
+        ];
+        let hole_a = vec![
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Three),
+        ];
+        let hole_b = vec![
+            Card::new(Suit::Diamonds, Rank::Four),
+            Card::new(Suit::Hearts, Rank::Six),
+        ];
+        
+        assert_eq!(compare_hands(&hole_a, &hole_b, &board), std::cmp::Ordering::Equal);
+    }
+    
+    #[test]
+    fn test_combinations_sizes() {
+        let seven = vec![
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Four),
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Diamonds, Rank::Eight),
+            Card::new(Suit::Hearts, Rank::Ten),
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Spades, Rank::Ace),
+        ];
+        
+        // Choosing nothing yields the single empty combination
+        assert_eq!(combinations(&seven, 0), vec![Vec::<Card>::new()]);
+        assert_eq!(combinations(&seven, 7), vec![seven.clone()]);
+        assert!(combinations(&seven, 8).is_empty());
+        assert!(combinations(&[], 1).is_empty());
+        
+        let fives = combinations(&seven, 5);
+        assert_eq!(fives.len(), 21);
+        assert!(fives.iter().all(|combo| combo.len() == 5));
+        let unique: std::collections::HashSet<_> = fives.iter().collect();
+        assert_eq!(unique.len(), 21);
+    }
+    
+    #[test]
+    fn test_wheel_is_five_high_straight() {
+        let wheel = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Diamonds, Rank::Four),
+            Card::new(Suit::Hearts, Rank::Five),
+        ];
+        let six_high = vec![
+            Card::new(Suit::Clubs, Rank::Six),
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Diamonds, Rank::Four),
+            Card::new(Suit::Hearts, Rank::Five),
+        ];
+        
+        let wheel_eval = evaluate_five_card_hand(&wheel);
+        assert_eq!(wheel_eval.rank, HandRank::Straight);
+        assert_eq!(wheel_eval.primary_value, 5);
+        assert!(evaluate_five_card_hand(&six_high) > wheel_eval);
+        
+        // A suited wheel is a straight flush, not a royal flush
+        let steel_wheel: Vec<Card> = wheel.iter().map(|c| Card::new(Suit::Spades, c.rank)).collect();
+        assert_eq!(evaluate_five_card_hand(&steel_wheel).rank, HandRank::StraightFlush);
+    }
+    
+    #[test]
+    fn test_eval_cache_reuses_evaluation_and_clears_on_new_round() {
+        let hole = vec![
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Diamonds, Rank::Nine),
+        ];
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Spades, Rank::Two),
+        ];
+        let mut game_data = crate::game_state::GameData::default();
+        
+        let first = game_data.eval_cache.evaluate(&hole, &board);
+        // Same cards in a different order hit the same entry
+        let second = game_data.eval_cache.evaluate(&board, &hole);
+        assert_eq!(first, second);
+        assert_eq!(first, evaluate_hand(&hole, &board));
+        assert_eq!(game_data.eval_cache.len(), 1);
+        
+        game_data.new_round();
+        assert_eq!(game_data.eval_cache.len(), 0);
+        assert_eq!(game_data.eval_cache.stats(), EvalStats::default());
+    }
+    
+    #[test]
+    fn test_eval_cache_does_fewer_evaluations_than_uncached() {
+        let hands = [
+            vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::King)],
+            vec![Card::new(Suit::Clubs, Rank::Nine), Card::new(Suit::Diamonds, Rank::Nine)],
+        ];
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Jack),
+        ];
+        let cached = HandEvalCache::default();
+        let uncached = HandEvalCache { disabled: true, ..default() };
+        
+        // Each player's hand is looked up for several AI decisions and again at showdown
+        let run = |cache: &HandEvalCache| {
+            cache.clear();
+            for _ in 0..3 {
+                for hole in &hands {
+                    assert_eq!(cache.evaluate(hole, &board), evaluate_hand(hole, &board));
+                }
+            }
+            cache.stats()
+        };
+        let cached_stats = run(&cached);
+        let uncached_stats = run(&uncached);
+        
+        // The direct `evaluate_hand` calls count too: 6 of them on top of the cache's own work
+        assert_eq!(cached_stats.hand_evaluations, 2 + 6);
+        assert_eq!(uncached_stats.hand_evaluations, 6 + 6);
+        // Only the best five cards get scored, once per Hold'em evaluation
+        assert_eq!(cached_stats.five_card_evaluations, cached_stats.hand_evaluations);
+    }
+    
+    #[test]
+    fn test_eval_stats_count_every_omaha_five_card_hand() {
+        let hole = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Diamonds, Rank::Eight),
+            Card::new(Suit::Spades, Rank::Two),
+        ];
+        let flop = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Hearts, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Nine),
+        ];
+        let cache = HandEvalCache::default();
+        cache.clear();
+        
+        evaluate_omaha(&hole, &flop);
+        
+        // One hand, scored as each of the six hole-card pairs with the flop
+        assert_eq!(cache.stats(), EvalStats { hand_evaluations: 1, five_card_evaluations: 6 });
+    }
+    
+    #[test]
+    fn test_omaha_cannot_play_board_four_flush_with_one_hole_card() {
+        // Four hearts on board and one heart in hand: a flush in Hold'em, not in Omaha
+        let hole = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Diamonds, Rank::Eight),
+            Card::new(Suit::Spades, Rank::Two),
+        ];
+        let board = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Three),
+            Card::new(Suit::Clubs, Rank::Queen),
+        ];
+        
+        assert_eq!(evaluate_hand(&hole, &board).rank, HandRank::Flush);
+        assert!(evaluate_omaha(&hole, &board).rank < HandRank::Flush);
+    }
+    
+    #[test]
+    fn test_omaha_picks_best_legal_two_card_combo() {
+        // Pocket aces only make a pair here; 9-8 with 7-6-5 on board makes a straight
+        let hole = vec![
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Diamonds, Rank::Eight),
+        ];
+        let board = vec![
+            Card::new(Suit::Spades, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Diamonds, Rank::Five),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Spades, Rank::Two),
+        ];
+        
+        let evaluation = evaluate_omaha(&hole, &board);
+        assert_eq!(evaluation.rank, HandRank::Straight);
+        assert_eq!(evaluation.primary_value, Rank::Nine as u8);
+    }
 }
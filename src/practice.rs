@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use crate::cards::{Card, Rank, Suit};
+use crate::game_config::{GameConfig, ScenarioConfig};
+use crate::game_state::GameState;
+use crate::teaching::{ExplanationType, TeachingState};
+
+// The situations practice mode makes sure the learner sees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScenarioTag {
+    FlushDraw,
+    Set,
+    TopPair,
+    BluffSpot,
+}
+
+impl ScenarioTag {
+    pub const ALL: [ScenarioTag; 4] = [ScenarioTag::FlushDraw, ScenarioTag::Set, ScenarioTag::TopPair, ScenarioTag::BluffSpot];
+    
+    pub fn tip(&self) -> &'static str {
+        match self {
+            ScenarioTag::FlushDraw => "Flush draw - count your outs and compare them with the pot odds",
+            ScenarioTag::Set => "You flopped a set - build the pot while opponents can still pay you",
+            ScenarioTag::TopPair => "Top pair, top kicker - a good hand, but not one to go broke with",
+            ScenarioTag::BluffSpot => "Your draw missed on the river - betting is the only way to win this pot",
+        }
+    }
+    
+    /// Stack the deck for this situation; suits are shuffled so each session looks a little different
+    pub fn scenario<R: Rng>(&self, rng: &mut R) -> ScenarioConfig {
+        let mut suits = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
+        suits.shuffle(rng);
+        let card = |suit: usize, rank: Rank| Card::new(suits[suit], rank);
+        
+        let (hole_cards, board, start_street) = match self {
+            ScenarioTag::FlushDraw => (
+                vec![card(0, Rank::Ace), card(0, Rank::Nine)],
+                vec![card(0, Rank::King), card(0, Rank::Seven), card(1, Rank::Two)],
+                GameState::Flop,
+            ),
+            ScenarioTag::Set => (
+                vec![card(0, Rank::Eight), card(1, Rank::Eight)],
+                vec![card(2, Rank::Eight), card(0, Rank::King), card(3, Rank::Three)],
+                GameState::Flop,
+            ),
+            ScenarioTag::TopPair => (
+                vec![card(0, Rank::Ace), card(1, Rank::Queen)],
+                vec![card(2, Rank::Queen), card(3, Rank::Seven), card(0, Rank::Four)],
+                GameState::Flop,
+            ),
+            ScenarioTag::BluffSpot => (
+                vec![card(0, Rank::Six), card(0, Rank::Five)],
+                vec![card(1, Rank::Ace), card(2, Rank::King), card(3, Rank::Jack), card(1, Rank::Three), card(0, Rank::Two)],
+                GameState::River,
+            ),
+        };
+        ScenarioConfig { hole_cards, board, start_street }
+    }
+}
+
+// Resource for practice mode: the situations still to come this session, in shuffled order
+#[derive(Resource, Debug, Default)]
+pub struct PracticeSession {
+    pub remaining: Vec<ScenarioTag>,
+    pub active: Option<(ScenarioTag, GameState)>, // Situation being played and the street it starts on
+}
+
+impl PracticeSession {
+    pub fn start<R: Rng>(&mut self, rng: &mut R) {
+        self.remaining = ScenarioTag::ALL.to_vec();
+        self.remaining.shuffle(rng);
+        self.active = None;
+        info!("🎯 Practice mode: {} situations this session", self.remaining.len());
+    }
+    
+    // Take the next situation and stack the deck for it; none once every one has been played
+    pub fn next_scenario<R: Rng>(&mut self, rng: &mut R) -> Option<(ScenarioTag, ScenarioConfig)> {
+        let Some(tag) = self.remaining.pop() else {
+            if self.active.take().is_some() {
+                info!("🎯 Practice complete - back to normal hands");
+            }
+            return None;
+        };
+        let scenario = tag.scenario(rng);
+        self.active = Some((tag, scenario.start_street));
+        Some((tag, scenario))
+    }
+}
+
+// Startup system: shuffle the session's situations when practice mode is on
+pub fn start_practice_session(game_config: Res<GameConfig>, mut practice_session: ResMut<PracticeSession>) {
+    if game_config.practice {
+        practice_session.start(&mut thread_rng());
+    }
+}
+
+// System to stack each new hand for the next practice situation and explain it once the hand reaches it
+pub fn run_practice_session(
+    game_state: Res<State<GameState>>,
+    mut practice_session: ResMut<PracticeSession>,
+    mut game_config: ResMut<GameConfig>,
+    mut teaching_state: ResMut<TeachingState>,
+) {
+    if !game_state.is_changed() || (practice_session.remaining.is_empty() && practice_session.active.is_none()) {
+        return;
+    }
+    
+    if *game_state.get() == GameState::Setup {
+        let next = practice_session.next_scenario(&mut thread_rng());
+        game_config.scenario = next.map(|(_, scenario)| scenario);
+        return;
+    }
+    if let Some((tag, start_street)) = practice_session.active {
+        if start_street == *game_state.get() {
+            teaching_state.show_explanation(ExplanationType::Practice(tag.tip().to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use rand::rngs::StdRng;
+    use crate::game_config::GameVariant;
+    
+    #[test]
+    fn test_practice_session_covers_each_situation_exactly_once() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .insert_resource(GameConfig { practice: true, ..default() })
+            .init_resource::<TeachingState>()
+            .init_resource::<PracticeSession>()
+            .add_systems(Update, run_practice_session);
+        app.world_mut().resource_mut::<PracticeSession>().start(&mut StdRng::seed_from_u64(4));
+        
+        // Play a few more hands than there are situations
+        let mut seen = Vec::new();
+        for _ in 0..ScenarioTag::ALL.len() + 2 {
+            app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Setup);
+            app.update();
+            
+            let scenario = app.world().resource::<GameConfig>().scenario.clone();
+            let Some((tag, start_street)) = app.world().resource::<PracticeSession>().active else {
+                assert!(scenario.is_none(), "normal hands follow the practice session");
+                continue;
+            };
+            let scenario = scenario.unwrap();
+            assert!(scenario.is_valid(GameVariant::Holdem), "{:?} dealt an impossible hand", tag);
+            assert_eq!(scenario.start_street, start_street);
+            seen.push(tag);
+            
+            app.world_mut().resource_mut::<NextState<GameState>>().set(start_street);
+            app.update();
+            let explanation = app.world().resource::<TeachingState>().current_explanation.clone().unwrap();
+            assert!(explanation.contains(tag.tip()));
+        }
+        
+        assert_eq!(seen.len(), ScenarioTag::ALL.len());
+        for tag in ScenarioTag::ALL {
+            assert_eq!(seen.iter().filter(|&&seen_tag| seen_tag == tag).count(), 1, "{:?}", tag);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::cmp::Ordering;
+use crate::calculator::card_labels;
+use crate::cards::{Card, Deck};
+use crate::poker_rules::{compare_hands, evaluate_hand, hand_rank_name};
+
+// Which hand the learner says wins
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrillAnswer {
+    HandA,
+    HandB,
+    Tie,
+}
+
+// Resource for the hand-ranking practice mini-game: two five-card hands, pick the winner
+#[derive(Resource, Debug, Default)]
+pub struct RankingDrill {
+    pub active: bool,
+    pub hand_a: Vec<Card>,
+    pub hand_b: Vec<Card>,
+    pub correct: u32,
+    pub attempts: u32,
+    pub feedback: Option<String>, // Result of the last answer
+}
+
+impl RankingDrill {
+    // Deal two fresh five-card hands from one shuffled deck
+    pub fn deal<R: Rng>(&mut self, rng: &mut R) {
+        let mut cards = Deck::default().cards;
+        cards.shuffle(rng);
+        self.hand_a = cards[..5].to_vec();
+        self.hand_b = cards[5..10].to_vec();
+    }
+    
+    pub fn correct_answer(&self) -> DrillAnswer {
+        match compare_hands(&self.hand_a, &self.hand_b, &[]) {
+            Ordering::Greater => DrillAnswer::HandA,
+            Ordering::Less => DrillAnswer::HandB,
+            Ordering::Equal => DrillAnswer::Tie,
+        }
+    }
+    
+    /// Score the learner's pick and explain the result; returns true if they were right
+    pub fn answer(&mut self, answer: DrillAnswer) -> bool {
+        let correct_answer = self.correct_answer();
+        let is_correct = answer == correct_answer;
+        self.attempts += 1;
+        if is_correct {
+            self.correct += 1;
+        }
+        
+        let rank_a = hand_rank_name(&evaluate_hand(&self.hand_a, &[]).rank);
+        let rank_b = hand_rank_name(&evaluate_hand(&self.hand_b, &[]).rank);
+        let explanation = match correct_answer {
+            DrillAnswer::HandA => format!("Hand A's {} beats Hand B's {}", rank_a, rank_b),
+            DrillAnswer::HandB => format!("Hand B's {} beats Hand A's {}", rank_b, rank_a),
+            DrillAnswer::Tie => format!("Both hands make the same {} - it's a split", rank_a),
+        };
+        self.feedback = Some(format!("{} {}", if is_correct { "✅ Correct!" } else { "❌ Not quite." }, explanation));
+        is_correct
+    }
+    
+    pub fn summary(&self) -> String {
+        let mut lines = vec![
+            format!("🃏 Which hand wins?   Score: {}/{}", self.correct, self.attempts),
+            format!("A: {}", card_labels(&self.hand_a)),
+            format!("B: {}", card_labels(&self.hand_b)),
+            "← Hand A   → Hand B   ↓ Tie   (G to quit)".to_string(),
+        ];
+        if let Some(feedback) = &self.feedback {
+            lines.push(feedback.clone());
+        }
+        lines.join("\n")
+    }
+}
+
+// Marker for the mini-game text
+#[derive(Component)]
+pub struct RankingDrillDisplay;
+
+// Plugin for the hand-ranking practice mini-game
+pub struct RankingDrillPlugin;
+
+impl Plugin for RankingDrillPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RankingDrill>()
+            .add_systems(Startup, setup_ranking_drill)
+            .add_systems(Update, (handle_ranking_drill_input, update_ranking_drill_display).chain());
+    }
+}
+
+fn setup_ranking_drill(mut commands: Commands) {
+    // Mini-game panel (bottom left), empty until the drill starts
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.0,
+                color: Color::srgb(0.9, 0.95, 1.0),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            bottom: Val::Px(90.0),
+            ..default()
+        }),
+        RankingDrillDisplay,
+    ));
+}
+
+// System to start/stop the drill with G and answer with the arrow keys
+fn handle_ranking_drill_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut drill: ResMut<RankingDrill>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyG) {
+        drill.active = !drill.active;
+        if drill.active {
+            drill.feedback = None;
+            drill.deal(&mut thread_rng());
+        }
+        info!("🃏 Hand ranking practice: {}", if drill.active { "ON" } else { "OFF" });
+        return;
+    }
+    if !drill.active {
+        return;
+    }
+    
+    let answer = if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        DrillAnswer::HandA
+    } else if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        DrillAnswer::HandB
+    } else if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+        DrillAnswer::Tie
+    } else {
+        return;
+    };
+    drill.answer(answer);
+    drill.deal(&mut thread_rng());
+}
+
+fn update_ranking_drill_display(
+    drill: Res<RankingDrill>,
+    mut display_query: Query<&mut Text, With<RankingDrillDisplay>>,
+) {
+    if !drill.is_changed() {
+        return;
+    }
+    
+    if let Ok(mut text) = display_query.get_single_mut() {
+        text.sections[0].value = if drill.active { drill.summary() } else { String::new() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use crate::cards::{Rank, Suit};
+    use crate::poker_rules::HandRank;
+    
+    #[test]
+    fn test_drill_picks_the_winner_and_scores_the_answer() {
+        let mut drill = RankingDrill::default();
+        let mut rng = StdRng::seed_from_u64(9);
+        
+        // Deal until the hands are of different strength so there is a clear winner
+        loop {
+            drill.deal(&mut rng);
+            if evaluate_hand(&drill.hand_a, &[]).rank != evaluate_hand(&drill.hand_b, &[]).rank {
+                break;
+            }
+        }
+        assert!(drill.hand_a.iter().all(|card| !drill.hand_b.contains(card)));
+        
+        let a_wins = evaluate_hand(&drill.hand_a, &[]).rank > evaluate_hand(&drill.hand_b, &[]).rank;
+        let expected = if a_wins { DrillAnswer::HandA } else { DrillAnswer::HandB };
+        assert_eq!(drill.correct_answer(), expected);
+        
+        assert!(drill.answer(expected));
+        assert_eq!((drill.correct, drill.attempts), (1, 1));
+        assert!(drill.feedback.as_ref().unwrap().starts_with("✅ Correct!"));
+        
+        assert!(!drill.answer(DrillAnswer::Tie));
+        assert_eq!((drill.correct, drill.attempts), (1, 2));
+        
+        // A flush against a straight is decided by hand rank alone
+        drill.hand_a = [Rank::Two, Rank::Seven, Rank::Nine, Rank::Jack, Rank::King]
+            .map(|rank| Card::new(Suit::Hearts, rank))
+            .to_vec();
+        drill.hand_b = vec![
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Diamonds, Rank::Six),
+            Card::new(Suit::Spades, Rank::Seven),
+            Card::new(Suit::Clubs, Rank::Eight),
+            Card::new(Suit::Diamonds, Rank::Nine),
+        ];
+        assert_eq!(evaluate_hand(&drill.hand_a, &[]).rank, HandRank::Flush);
+        assert_eq!(drill.correct_answer(), DrillAnswer::HandA);
+    }
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use crate::cards::{Card, Suit, Rank};
-use crate::player::Player;
+use crate::player::{Player, PlayerType};
 use crate::game_state::GameState;
+use crate::teaching::TeachingState;
 
 // Constants for card rendering
 const CARD_WIDTH: f32 = 60.0;
@@ -19,6 +20,50 @@ pub struct RenderedCard {
 #[derive(Component)]
 pub struct CardBack;
 
+// Component for the greyed placeholder left in a folded player's card slots
+#[derive(Component)]
+pub struct FoldedGhost;
+
+// Component for the empty outlines the board cards are dealt into
+#[derive(Component)]
+pub struct BoardSlot;
+
+// The board always shows this many slots, filled left to right
+pub const BOARD_SLOTS: usize = 5;
+
+// Resource sizing the community cards, so the board can be scaled up on larger screens
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct BoardLayout {
+    pub card_size: Vec2,
+    pub spacing: f32, // Gap between neighbouring slots
+}
+
+impl Default for BoardLayout {
+    fn default() -> Self {
+        Self {
+            card_size: Vec2::new(CARD_WIDTH, CARD_HEIGHT),
+            spacing: 15.0,
+        }
+    }
+}
+
+impl BoardLayout {
+    /// The default layout with cards and gaps multiplied by `scale`
+    pub fn scaled(scale: f32) -> Self {
+        let layout = Self::default();
+        Self {
+            card_size: layout.card_size * scale,
+            spacing: layout.spacing * scale,
+        }
+    }
+    
+    // Center of a board slot, with the five slots centered on the table
+    pub fn slot_x(&self, index: usize) -> f32 {
+        let step = self.card_size.x + self.spacing;
+        (index as f32 - (BOARD_SLOTS as f32 - 1.0) / 2.0) * step
+    }
+}
+
 // Colors for suits
 const HEART_COLOR: Color = Color::srgb(0.8, 0.2, 0.2);
 const DIAMOND_COLOR: Color = Color::srgb(0.8, 0.2, 0.2);
@@ -26,6 +71,10 @@ const CLUB_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
 const SPADE_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
 const CARD_BACKGROUND: Color = Color::srgb(0.95, 0.95, 0.9);
 const CARD_BACK_COLOR: Color = Color::srgb(0.2, 0.3, 0.6);
+const FOLDED_CARD_ALPHA: f32 = 0.45;
+// Folded hands that stay hidden: opaque so the cards under them don't show through
+const FOLDED_GHOST_COLOR: Color = Color::srgb(0.32, 0.33, 0.36);
+const BOARD_SLOT_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.08);
 
 pub fn suit_color(suit: Suit) -> Color {
     match suit {
@@ -63,17 +112,36 @@ pub fn rank_symbol(rank: Rank) -> &'static str {
     }
 }
 
+// AI players whose folded hand is shown face-up because the teaching flag is on
+pub fn revealed_folded_players(players: &Query<&Player>, teaching_state: &TeachingState) -> Vec<u32> {
+    if !teaching_state.reveal_folded_cards {
+        return Vec::new();
+    }
+    
+    let mut ids: Vec<u32> = players
+        .iter()
+        .filter(|p| matches!(p.player_type, PlayerType::AI) && p.has_folded && !p.hole_cards.is_empty())
+        .map(|p| p.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
 // System to render cards for players
 pub fn render_player_cards(
     mut commands: Commands,
     players: Query<&Player>,
     rendered_cards: Query<Entity, With<RenderedCard>>,
     game_state: Res<State<GameState>>,
+    teaching_state: Res<TeachingState>,
+    mut last_folded_reveals: Local<Vec<u32>>,
 ) {
-    // Only update when game state changes to avoid constant re-rendering
-    if !game_state.is_changed() {
+    // Only update when game state changes or a fold is newly revealed, to avoid constant re-rendering
+    let folded_reveals = revealed_folded_players(&players, &teaching_state);
+    if !game_state.is_changed() && folded_reveals == *last_folded_reveals {
         return;
     }
+    *last_folded_reveals = folded_reveals.clone();
     
     // Clear existing rendered cards only when something changed
     for entity in rendered_cards.iter() {
@@ -94,19 +162,22 @@ pub fn render_player_cards(
                 1.0, // Above background
             );
             
-            spawn_card(&mut commands, card, card_pos, Some(player.id));
+            let dimmed = folded_reveals.contains(&player.id);
+            spawn_card(&mut commands, card, card_pos, Vec2::new(CARD_WIDTH, CARD_HEIGHT), Some(player.id), dimmed);
         }
     }
 }
 
-// System to render community cards
+// System to render community cards into five fixed board slots
 pub fn render_community_cards(
     mut commands: Commands,
     game_data: Res<crate::game_state::GameData>,
+    board_layout: Res<BoardLayout>,
     rendered_community_cards: Query<Entity, (With<RenderedCard>, Without<CardBack>)>,
+    board_slots: Query<Entity, With<BoardSlot>>,
 ) {
-    // Only update when game data changes
-    if !game_data.is_changed() {
+    // Only update when game data or the layout changes
+    if !game_data.is_changed() && !board_layout.is_changed() {
         return;
     }
     
@@ -116,29 +187,51 @@ pub fn render_community_cards(
             entity_commands.despawn_recursive();
         }
     }
+    for entity in board_slots.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
     
-    // Render community cards in the center
-    let card_spacing = CARD_WIDTH + 15.0;
-    let start_x = -(card_spacing * (game_data.community_cards.len() as f32 - 1.0)) / 2.0;
+    // Empty slots stay put so the board doesn't shift as the flop, turn and river arrive
+    for index in 0..BOARD_SLOTS {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: BOARD_SLOT_COLOR,
+                    custom_size: Some(board_layout.card_size),
+                    ..default()
+                },
+                transform: Transform::from_xyz(board_layout.slot_x(index), 0.0, 0.5),
+                ..default()
+            },
+            BoardSlot,
+        ));
+    }
     
-    for (i, &card) in game_data.community_cards.iter().enumerate() {
+    for (i, &card) in game_data.community_cards.iter().take(BOARD_SLOTS).enumerate() {
         let card_pos = Vec3::new(
-            start_x + i as f32 * card_spacing,
+            board_layout.slot_x(i),
             0.0, // Center of table
             1.0,
         );
         
-        spawn_card(&mut commands, card, card_pos, None);
+        spawn_card(&mut commands, card, card_pos, board_layout.card_size, None, false);
     }
 }
 
-fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, owner_id: Option<u32>) {
+fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, size: Vec2, owner_id: Option<u32>, dimmed: bool) {
+    // Corner symbols keep their inset from the card's edges at any size
+    let (half_width, half_height) = (size.x / 2.0, size.y / 2.0);
+    
+    // Folded hands are drawn faded so they read as out of the hand
+    let alpha = if dimmed { FOLDED_CARD_ALPHA } else { 1.0 };
+    let ink = suit_color(card.suit).with_alpha(alpha);
+    
     // Card background (rectangle)
     commands
         .spawn(SpriteBundle {
             sprite: Sprite {
-                color: CARD_BACKGROUND,
-                custom_size: Some(Vec2::new(CARD_WIDTH, CARD_HEIGHT)),
+                color: CARD_BACKGROUND.with_alpha(alpha),
+                custom_size: Some(size),
                 ..default()
             },
             transform: Transform::from_translation(position),
@@ -151,11 +244,11 @@ fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, owner_id: Opt
                     rank_symbol(card.rank),
                     TextStyle {
                         font_size: 16.0,
-                        color: suit_color(card.suit),
+                        color: ink,
                         ..default()
                     },
                 ),
-                transform: Transform::from_xyz(-CARD_WIDTH/2.0 + 8.0, CARD_HEIGHT/2.0 - 12.0, 0.1),
+                transform: Transform::from_xyz(-half_width + 8.0, half_height - 12.0, 0.1),
                 ..default()
             });
             
@@ -165,11 +258,11 @@ fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, owner_id: Opt
                     suit_symbol(card.suit),
                     TextStyle {
                         font_size: 14.0,
-                        color: suit_color(card.suit),
+                        color: ink,
                         ..default()
                     },
                 ),
-                transform: Transform::from_xyz(-CARD_WIDTH/2.0 + 8.0, CARD_HEIGHT/2.0 - 28.0, 0.1),
+                transform: Transform::from_xyz(-half_width + 8.0, half_height - 28.0, 0.1),
                 ..default()
             });
             
@@ -179,7 +272,7 @@ fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, owner_id: Opt
                     suit_symbol(card.suit),
                     TextStyle {
                         font_size: 24.0,
-                        color: suit_color(card.suit),
+                        color: ink,
                         ..default()
                     },
                 ),
@@ -193,11 +286,11 @@ fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, owner_id: Opt
                     rank_symbol(card.rank),
                     TextStyle {
                         font_size: 16.0,
-                        color: suit_color(card.suit),
+                        color: ink,
                         ..default()
                     },
                 ),
-                transform: Transform::from_xyz(CARD_WIDTH/2.0 - 8.0, -CARD_HEIGHT/2.0 + 12.0, 0.1)
+                transform: Transform::from_xyz(half_width - 8.0, -half_height + 12.0, 0.1)
                     .with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
                 ..default()
             });
@@ -208,11 +301,11 @@ fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, owner_id: Opt
                     suit_symbol(card.suit),
                     TextStyle {
                         font_size: 14.0,
-                        color: suit_color(card.suit),
+                        color: ink,
                         ..default()
                     },
                 ),
-                transform: Transform::from_xyz(CARD_WIDTH/2.0 - 8.0, -CARD_HEIGHT/2.0 + 28.0, 0.1)
+                transform: Transform::from_xyz(half_width - 8.0, -half_height + 28.0, 0.1)
                     .with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
                 ..default()
             });
@@ -220,26 +313,41 @@ fn spawn_card(commands: &mut Commands, card: Card, position: Vec3, owner_id: Opt
         .insert(RenderedCard { card, owner_id });
 }
 
-// System to render card backs for AI players (face-down cards)
-pub fn render_card_backs_for_ai(
+// System to draw face-down cards: the AIs' hidden hands, folded-hand ghosts, and a hand the human mucked at showdown
+pub fn render_face_down_cards(
     mut commands: Commands,
     players: Query<&Player>,
     card_backs: Query<Entity, With<CardBack>>,
     game_state: Res<State<GameState>>,
+    game_data: Res<crate::game_state::GameData>,
+    teaching_state: Res<TeachingState>,
+    mut last_folds: Local<(Vec<u32>, Vec<u32>)>,
 ) {
-    // Only update when game state changes
-    if !game_state.is_changed() {
+    // Only update when game state changes, someone folds, or a fold is newly revealed
+    let folded_reveals = revealed_folded_players(&players, &teaching_state);
+    let mut folded: Vec<u32> = players.iter().filter(|p| p.has_folded).map(|p| p.id).collect();
+    folded.sort();
+    if !game_state.is_changed() && (&folded, &folded_reveals) == (&last_folds.0, &last_folds.1) {
         return;
     }
+    *last_folds = (folded, folded_reveals.clone());
     
     // Clear existing card backs only when something changed
     for entity in card_backs.iter() {
         commands.entity(entity).despawn_recursive();
     }
     
-    // Render card backs for AI players only
+    // Hands shown at showdown are left face-up
+    let showdown_over = matches!(game_state.get(), GameState::GameOver);
+    
+    // The human always sees their own cards, unless they mucked them at showdown
     for player in players.iter() {
-        if matches!(player.player_type, crate::player::PlayerType::AI) && !player.hole_cards.is_empty() {
+        let shown_at_showdown = showdown_over && game_data.showdown_reveals.contains(&player.id);
+        let face_down = match player.player_type {
+            PlayerType::AI => !shown_at_showdown && !folded_reveals.contains(&player.id),
+            PlayerType::Human => showdown_over && !player.has_folded && !game_data.showdown_reveals.is_empty() && !shown_at_showdown,
+        };
+        if face_down && !player.hole_cards.is_empty() {
             let card_spacing = CARD_WIDTH + 10.0;
             let start_x = player.position.x - (card_spacing * (player.hole_cards.len() as f32 - 1.0)) / 2.0;
             
@@ -250,7 +358,12 @@ pub fn render_card_backs_for_ai(
                     1.0,
                 );
                 
-                spawn_card_back(&mut commands, card_pos);
+                // A folded hand leaves a ghost so the seat doesn't look like it's still in
+                if player.has_folded {
+                    spawn_folded_ghost(&mut commands, card_pos);
+                } else {
+                    spawn_card_back(&mut commands, card_pos);
+                }
             }
         }
     }
@@ -284,3 +397,146 @@ fn spawn_card_back(commands: &mut Commands, position: Vec3) {
         })
         .insert(CardBack);
 }
+
+fn spawn_folded_ghost(commands: &mut Commands, position: Vec3) {
+    commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                color: FOLDED_GHOST_COLOR,
+                custom_size: Some(Vec2::new(CARD_WIDTH, CARD_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_translation(position),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "Folded",
+                    TextStyle {
+                        font_size: 13.0,
+                        color: Color::srgb(0.6, 0.6, 0.62),
+                        ..default()
+                    },
+                ),
+                transform: Transform::from_xyz(0.0, 0.0, 0.1),
+                ..default()
+            });
+        })
+        .insert((CardBack, FoldedGhost));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::game_state::GameData;
+    
+    #[test]
+    fn test_folded_ai_cards_shown_face_up_and_dimmed_when_flag_on() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<GameData>()
+            .insert_resource(TeachingState { reveal_folded_cards: true, ..default() })
+            .add_systems(Update, (render_player_cards, render_face_down_cards));
+        
+        let mut folded = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        folded.hole_cards = vec![Card::new(Suit::Hearts, Rank::Seven), Card::new(Suit::Clubs, Rank::Two)];
+        folded.has_folded = true;
+        app.world_mut().spawn(folded);
+        let mut live = Player::new(2, PlayerType::AI, 1000, Vec3::new(200.0, 0.0, 0.0));
+        live.hole_cards = vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Spades, Rank::King)];
+        app.world_mut().spawn(live);
+        
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Flop);
+        app.update();
+        app.update();
+        
+        // Only the live AI keeps face-down cards
+        let mut card_backs = app.world_mut().query_filtered::<Entity, With<CardBack>>();
+        assert_eq!(card_backs.iter(app.world()).count(), 2);
+        
+        let mut cards = app.world_mut().query::<(&RenderedCard, &Sprite)>();
+        let folded_alphas: Vec<f32> = cards
+            .iter(app.world())
+            .filter(|(card, _)| card.owner_id == Some(1))
+            .map(|(_, sprite)| sprite.color.alpha())
+            .collect();
+        assert_eq!(folded_alphas.len(), 2);
+        assert!(folded_alphas.iter().all(|&alpha| alpha < 1.0));
+    }
+    
+    #[test]
+    fn test_folded_hidden_hand_leaves_a_ghost_while_live_hands_show_backs() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<GameData>()
+            .init_resource::<TeachingState>()
+            .add_systems(Update, render_face_down_cards);
+        
+        let mut folding = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        folding.hole_cards = vec![Card::new(Suit::Hearts, Rank::Seven), Card::new(Suit::Clubs, Rank::Two)];
+        app.world_mut().spawn(folding);
+        let mut live = Player::new(2, PlayerType::AI, 1000, Vec3::new(200.0, 0.0, 0.0));
+        live.hole_cards = vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Spades, Rank::King)];
+        app.world_mut().spawn(live);
+        
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Turn);
+        app.update();
+        app.update();
+        
+        // Folding mid-street swaps the backs for a ghost straight away
+        let mut players = app.world_mut().query::<&mut Player>();
+        for mut player in players.iter_mut(app.world_mut()) {
+            player.has_folded = player.id == 1;
+        }
+        app.update();
+        app.update();
+        
+        let mut slots = app.world_mut().query_filtered::<(&Transform, &Sprite, Has<FoldedGhost>), With<CardBack>>();
+        let slots: Vec<(f32, Color, bool)> = slots
+            .iter(app.world())
+            .map(|(transform, sprite, ghost)| (transform.translation.x, sprite.color, ghost))
+            .collect();
+        assert_eq!(slots.len(), 4);
+        for (x, color, ghost) in slots {
+            // The folded seat sits at x = 0, the live one at x = 200
+            assert_eq!(ghost, x < 100.0);
+            assert_eq!(color, if ghost { FOLDED_GHOST_COLOR } else { CARD_BACK_COLOR });
+        }
+    }
+    
+    #[test]
+    fn test_larger_board_layout_sizes_community_cards() {
+        let layout = BoardLayout { card_size: Vec2::new(90.0, 126.0), spacing: 20.0 };
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<GameData>()
+            .insert_resource(layout.clone())
+            .add_systems(Update, render_community_cards);
+        app.world_mut().resource_mut::<GameData>().community_cards = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Spades, Rank::Four),
+        ];
+        app.update();
+        
+        let mut cards = app.world_mut().query::<(&RenderedCard, &Sprite, &Transform)>();
+        let mut board: Vec<(f32, f32)> = cards
+            .iter(app.world())
+            .filter(|(card, _, _)| card.owner_id.is_none())
+            .map(|(_, sprite, transform)| (sprite.custom_size.unwrap().x, transform.translation.x))
+            .collect();
+        board.sort_by(|a, b| a.1.total_cmp(&b.1));
+        assert_eq!(board.len(), 3);
+        assert!(board.iter().all(|&(width, _)| width == 90.0));
+        
+        // The flop fills the three leftmost of the five slots
+        let slot_xs: Vec<f32> = (0..3).map(|index| layout.slot_x(index)).collect();
+        assert_eq!(board.iter().map(|&(_, x)| x).collect::<Vec<_>>(), slot_xs);
+        let mut slots = app.world_mut().query::<&BoardSlot>();
+        assert_eq!(slots.iter(app.world()).count(), BOARD_SLOTS);
+    }
+}
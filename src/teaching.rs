@@ -1,11 +1,21 @@
 use bevy::prelude::*;
-use crate::game_state::GameState;
+use bevy::window::PrimaryWindow;
+use crate::game_state::{GamePosition, GameState};
 use crate::player::{Player, PlayerType};
+use crate::betting::{BettingRound, PlayerAction};
+use crate::betting_ui::{BettingUI, HumanPlayerInput};
+use crate::ai_player::{evaluate_hand_strength, HandStrength};
+use crate::poker_rules::HandEvalCache;
 
 // Teaching system components
 #[derive(Component)]
 pub struct TeachingPopup;
 
+// Where the teaching panel sits (px up from the bottom edge) when nothing needs the space
+const TEACHING_PANEL_BOTTOM: f32 = 10.0;
+// Gap kept between the teaching panel and betting controls it moved out from under
+const TEACHING_PANEL_GAP: f32 = 10.0;
+
 #[derive(Component)]
 pub struct HandRankingGuide;
 
@@ -18,34 +28,175 @@ pub struct TeachingMessageDisplay;
 #[derive(Component)]
 pub struct HandAnalysisDisplay;
 
+// How much coaching the learner wants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoachLevel {
+    Silent,     // No tips at all
+    KeyMoments, // Only the big decisions: pre-flop, showdown and game over
+    Verbose,    // An explanation on every phase transition
+}
+
+impl CoachLevel {
+    pub fn next(self) -> Self {
+        match self {
+            CoachLevel::Silent => CoachLevel::KeyMoments,
+            CoachLevel::KeyMoments => CoachLevel::Verbose,
+            CoachLevel::Verbose => CoachLevel::Silent,
+        }
+    }
+    
+    // Whether the phase explanation for this state should fire at this level
+    pub fn explains(self, state: &GameState) -> bool {
+        match self {
+            CoachLevel::Silent => false,
+            CoachLevel::KeyMoments => matches!(state, GameState::PreFlop | GameState::Showdown | GameState::GameOver),
+            CoachLevel::Verbose => true,
+        }
+    }
+}
+
+// The coach's voice: the same tip, phrased to suit how the learner likes to be taught
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoachPersona {
+    #[default]
+    Standard,    // Tips as written
+    Encouraging, // Softer framing and a little praise
+    Strict,      // Blunt instructions
+    Concise,     // Just the first sentence
+}
+
+impl CoachPersona {
+    pub fn next(self) -> Self {
+        match self {
+            CoachPersona::Standard => CoachPersona::Encouraging,
+            CoachPersona::Encouraging => CoachPersona::Strict,
+            CoachPersona::Strict => CoachPersona::Concise,
+            CoachPersona::Concise => CoachPersona::Standard,
+        }
+    }
+    
+    /// Reword a teaching message in this persona's voice
+    pub fn phrase(self, message: &str) -> String {
+        match self {
+            CoachPersona::Standard => message.to_string(),
+            CoachPersona::Encouraging => format!("Great spot to learn from! {} You're getting the hang of this.", message),
+            CoachPersona::Strict => format!("Pay attention. {}", message),
+            CoachPersona::Concise => {
+                let first_sentence_end = message
+                    .match_indices(['.', '!', '?'])
+                    .map(|(index, _)| index + 1)
+                    .find(|&end| message[end..].starts_with(' '));
+                first_sentence_end.map_or(message, |end| &message[..end]).to_string()
+            },
+        }
+    }
+}
+
 // Resource to track teaching state
 #[derive(Resource)]
 pub struct TeachingState {
-    pub tutorial_mode: bool,
+    pub coach_level: CoachLevel,
+    pub coach_persona: CoachPersona,
     pub show_hand_rankings: bool,
     pub show_rule_popup: bool,
+    pub reveal_folded_cards: bool, // Show folded AI hands face-up (dimmed) so the fold can be studied
+    pub show_tells: bool, // AIs give away strong hands with a visible habit, for practising reads
+    pub training_wheels: bool, // Beginner mode: unplayable starting hands are folded automatically
     pub current_explanation: Option<String>,
     pub mistakes_shown: Vec<String>,
     pub last_game_state: Option<GameState>,
     pub last_current_player: Option<u32>,
     pub messages_shown_this_state: std::collections::HashSet<String>,
+    pub checkpoints: std::collections::VecDeque<String>, // Tutorial stops waiting to be dismissed, front one on screen
+    pub blinds_explained: bool,
 }
 
 impl Default for TeachingState {
     fn default() -> Self {
         Self {
-            tutorial_mode: true, // Enable by default for learning
+            coach_level: CoachLevel::Verbose, // Full coaching by default for learning
+            coach_persona: CoachPersona::Standard,
             show_hand_rankings: false,
             show_rule_popup: false,
+            reveal_folded_cards: false,
+            show_tells: false,
+            training_wheels: false,
             current_explanation: None,
             mistakes_shown: Vec::new(),
             last_game_state: None,
             last_current_player: None,
             messages_shown_this_state: std::collections::HashSet::new(),
+            checkpoints: std::collections::VecDeque::new(),
+            blinds_explained: false,
         }
     }
 }
 
+// Rough range an opponent is representing, ordered from weakest to strongest read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RangeEstimate {
+    Wide,   // Only checked so far
+    Medium, // Called a bet
+    Strong, // Raised
+}
+
+impl RangeEstimate {
+    pub fn from_action(action: &PlayerAction) -> Option<Self> {
+        match action {
+            PlayerAction::Fold => None,
+            PlayerAction::Check => Some(RangeEstimate::Wide),
+            PlayerAction::Call => Some(RangeEstimate::Medium),
+            PlayerAction::Raise(_) => Some(RangeEstimate::Strong),
+        }
+    }
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            RangeEstimate::Wide => "Range: wide (weak hands, draws, traps)",
+            RangeEstimate::Medium => "Range: medium (pairs, draws)",
+            RangeEstimate::Strong => "Range: likely strong (top pair+)",
+        }
+    }
+}
+
+// Resource holding the strongest range each AI has represented this hand
+#[derive(Resource, Default)]
+pub struct OpponentReads {
+    pub ranges: std::collections::HashMap<u32, RangeEstimate>,
+}
+
+// Resource for the "learn by doing" quiz: the human picks an action, then sees the recommended one
+#[derive(Resource, Default)]
+pub struct QuizMode {
+    pub enabled: bool,
+    pub correct: u32,
+    pub incorrect: u32,
+    pub last_recommendation: Option<PlayerAction>,
+    pub awaiting_answer: bool,
+}
+
+impl QuizMode {
+    // Grade the human's choice against the recommendation; returns true when they match
+    pub fn grade(&mut self, chosen: &PlayerAction, recommended: PlayerAction) -> bool {
+        self.awaiting_answer = false;
+        if std::mem::discriminant(chosen) == std::mem::discriminant(&recommended) {
+            self.correct += 1;
+            self.last_recommendation = None;
+            true
+        } else {
+            self.incorrect += 1;
+            self.last_recommendation = Some(recommended);
+            false
+        }
+    }
+    
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+        self.awaiting_answer = false;
+        info!("🧠 Quiz mode: {}", if self.enabled { "ON" } else { "OFF" });
+    }
+}
+
 // Types of explanations available
 #[derive(Debug, Clone)]
 pub enum ExplanationType {
@@ -54,30 +205,56 @@ pub enum ExplanationType {
     GamePhase(String),
     PlayerAction(String),
     Mistake(String),
+    Lesson(String),
+    Practice(String),
+    Recap(String),
+    Checkpoint(String),
 }
 
 impl TeachingState {
     pub fn show_explanation(&mut self, explanation: ExplanationType) {
+        // A checkpoint stays on screen until the learner dismisses it
+        if self.at_checkpoint() && !matches!(explanation, ExplanationType::Checkpoint(_)) {
+            return;
+        }
+        
+        let persona = self.coach_persona;
         match explanation {
             ExplanationType::HandRanking(msg) => {
-                self.current_explanation = Some(format!("🃏 Hand Ranking: {}", msg));
+                self.current_explanation = Some(format!("🃏 Hand Ranking: {}", persona.phrase(&msg)));
                 self.show_rule_popup = true;
             },
             ExplanationType::BettingRule(msg) => {
-                self.current_explanation = Some(format!("💰 Betting Rule: {}", msg));
+                self.current_explanation = Some(format!("💰 Betting Rule: {}", persona.phrase(&msg)));
                 self.show_rule_popup = true;
             },
             ExplanationType::GamePhase(msg) => {
-                self.current_explanation = Some(format!("🎮 Game Phase: {}", msg));
+                self.current_explanation = Some(format!("🎮 Game Phase: {}", persona.phrase(&msg)));
                 self.show_rule_popup = true;
             },
             ExplanationType::PlayerAction(msg) => {
-                self.current_explanation = Some(format!("🎯 Player Action: {}", msg));
+                self.current_explanation = Some(format!("🎯 Player Action: {}", persona.phrase(&msg)));
+                self.show_rule_popup = true;
+            },
+            ExplanationType::Lesson(msg) => {
+                self.current_explanation = Some(format!("📘 Lesson: {}", persona.phrase(&msg)));
+                self.show_rule_popup = true;
+            },
+            ExplanationType::Practice(msg) => {
+                self.current_explanation = Some(format!("🎯 Practice: {}", persona.phrase(&msg)));
+                self.show_rule_popup = true;
+            },
+            ExplanationType::Recap(msg) => {
+                self.current_explanation = Some(format!("📈 Hand Recap: {}", persona.phrase(&msg)));
+                self.show_rule_popup = true;
+            },
+            ExplanationType::Checkpoint(msg) => {
+                self.current_explanation = Some(format!("⏸️ Tutorial: {} (press ESC to continue)", persona.phrase(&msg)));
                 self.show_rule_popup = true;
             },
             ExplanationType::Mistake(msg) => {
                 if !self.mistakes_shown.contains(&msg) {
-                    self.current_explanation = Some(format!("⚠️ Learning Tip: {}", msg));
+                    self.current_explanation = Some(format!("⚠️ Learning Tip: {}", persona.phrase(&msg)));
                     self.show_rule_popup = true;
                     self.mistakes_shown.push(msg);
                 }
@@ -90,17 +267,61 @@ impl TeachingState {
     pub fn hide_explanation(&mut self) {
         self.show_rule_popup = false;
         self.current_explanation = None;
+        
+        // Dismissing a checkpoint moves on to the next queued one, if any
+        if self.checkpoints.pop_front().is_some() {
+            if let Some(next) = self.checkpoints.front().cloned() {
+                self.show_explanation(ExplanationType::Checkpoint(next));
+            }
+        }
+    }
+    
+    /// Pause play on a tutorial message; the game waits until every checkpoint is dismissed
+    pub fn queue_checkpoint(&mut self, message: String) {
+        self.checkpoints.push_back(message.clone());
+        if self.checkpoints.len() == 1 {
+            self.show_explanation(ExplanationType::Checkpoint(message));
+        }
     }
     
-    pub fn toggle_tutorial_mode(&mut self) {
-        self.tutorial_mode = !self.tutorial_mode;
-        info!("📖 Tutorial mode: {}", if self.tutorial_mode { "ON" } else { "OFF" });
+    pub fn at_checkpoint(&self) -> bool {
+        !self.checkpoints.is_empty()
+    }
+    
+    // Tutorial mode is on at any coach level other than Silent
+    pub fn tutorial_mode(&self) -> bool {
+        self.coach_level != CoachLevel::Silent
+    }
+    
+    pub fn cycle_coach_level(&mut self) {
+        self.coach_level = self.coach_level.next();
+        info!("📖 Coach level: {:?}", self.coach_level);
+    }
+    
+    pub fn cycle_coach_persona(&mut self) {
+        self.coach_persona = self.coach_persona.next();
+        info!("🗣️ Coach persona: {:?}", self.coach_persona);
     }
     
     pub fn toggle_hand_rankings(&mut self) {
         self.show_hand_rankings = !self.show_hand_rankings;
         info!("🃏 Hand rankings guide: {}", if self.show_hand_rankings { "SHOWN" } else { "HIDDEN" });
     }
+    
+    pub fn toggle_training_wheels(&mut self) {
+        self.training_wheels = !self.training_wheels;
+        info!("🚲 Training wheels (auto-fold trash hands): {}", if self.training_wheels { "ON" } else { "OFF" });
+    }
+    
+    pub fn toggle_reveal_folded_cards(&mut self) {
+        self.reveal_folded_cards = !self.reveal_folded_cards;
+        info!("🂡 Reveal folded AI cards: {}", if self.reveal_folded_cards { "ON" } else { "OFF" });
+    }
+    
+    pub fn toggle_tells(&mut self) {
+        self.show_tells = !self.show_tells;
+        info!("🤲 AI tells: {}", if self.show_tells { "ON" } else { "OFF" });
+    }
 }
 
 // System to setup teaching UI
@@ -113,7 +334,7 @@ pub fn setup_teaching_ui(mut commands: Commands) {
             style: Style {
                 position_type: PositionType::Absolute,
                 left: Val::Px(10.0),
-                bottom: Val::Px(10.0),
+                bottom: Val::Px(TEACHING_PANEL_BOTTOM),
                 width: Val::Px(300.0),
                 min_height: Val::Px(40.0),
                 max_height: Val::Px(100.0),
@@ -138,7 +359,8 @@ pub fn setup_teaching_ui(mut commands: Commands) {
                     },
                 ))
                 .insert(TeachingMessageDisplay);
-        });
+        })
+        .insert(TeachingPopup);
     
     // Hand analysis display (bottom right corner)
     commands
@@ -174,14 +396,23 @@ pub fn setup_teaching_ui(mut commands: Commands) {
         });
     
     info!("🎓 TEACHING CONTROLS:");
-    info!("   T - Toggle tutorial mode (explanations)");
+    info!("   T - Cycle coach level (Silent / Key moments / Verbose)");
     info!("   H - Toggle hand rankings guide");
     info!("   R - Show basic betting rules");
+    info!("   Q - Toggle quiz mode (pick the best action before it's revealed)");
+    info!("   F - Reveal folded AI hands (face-up, dimmed)");
+    info!("   L - AI tells: opponents give away strong hands by stacking chips");
+    info!("   W - Training wheels: auto-fold unplayable starting hands");
+    info!("   O - Toggle odds overlay (pot odds, equity and outs)");
+    info!("   K - Open the odds calculator (pick any hand and board to study)");
+    info!("   G - Hand ranking practice (arrow keys: ← Hand A, → Hand B, ↓ Tie)");
+    info!("   D - Cycle AI difficulty (takes effect next hand)");
     info!("   ESC - Hide current explanation");
     info!("📖 Tutorial mode is ON - you'll get explanations during play!");
     
     info!("🎮 PHASE 7 GAME CONTROLS:");
     info!("   SPACE - Pause/resume auto-advance");
+    info!("   N - Step to the next phase while auto-advance is paused");
     info!("   P - Pause/resume game");
     info!("   , (comma) - Slow down game speed");
     info!("   . (period) - Speed up game speed");
@@ -189,6 +420,8 @@ pub fn setup_teaching_ui(mut commands: Commands) {
     info!("   M - Mute/unmute audio");
     info!("   + - Increase volume");
     info!("   - - Decrease volume");
+    info!("   F3 - Toggle FPS/perf overlay");
+    info!("   C - Toggle high-contrast theme");
 }
 
 // System to update teaching message display
@@ -197,7 +430,7 @@ pub fn update_teaching_display(
     mut teaching_display_query: Query<&mut Text, With<TeachingMessageDisplay>>,
 ) {
     if let Ok(mut text) = teaching_display_query.get_single_mut() {
-        if teaching_state.tutorial_mode && teaching_state.show_rule_popup {
+        if teaching_state.tutorial_mode() && teaching_state.show_rule_popup {
             if let Some(ref explanation) = teaching_state.current_explanation {
                 text.sections[0].value = explanation.clone();
             }
@@ -207,19 +440,95 @@ pub fn update_teaching_display(
     }
 }
 
+/// Screen box a bottom-anchored panel can take up at this window size, with y measured up from the
+/// bottom edge; panels that grow with their text are sized at their max height
+pub fn bottom_panel_rect(style: &Style, window_size: Vec2) -> Rect {
+    let resolve = |val: Val, extent: f32| match val {
+        Val::Px(px) => px,
+        Val::Percent(percent) => extent * percent / 100.0,
+        _ => 0.0,
+    };
+    let height = match style.height {
+        Val::Auto => style.max_height,
+        height => height,
+    };
+    let min = Vec2::new(resolve(style.left, window_size.x), resolve(style.bottom, window_size.y));
+    let size = Vec2::new(resolve(style.width, window_size.x), resolve(height, window_size.y));
+    Rect::from_corners(min, min + size)
+}
+
+// System to keep the teaching panel off the betting controls on small screens: while they're up it
+// moves above them, or collapses if there's no room there, and it goes back to its corner afterwards
+pub fn arbitrate_teaching_panel_layout(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    betting_ui_query: Query<(&Style, &Visibility), With<BettingUI>>,
+    mut teaching_panel_query: Query<&mut Style, (With<TeachingPopup>, Without<BettingUI>)>,
+) {
+    let (Ok(window), Ok((controls_style, controls_visibility)), Ok(mut panel_style)) =
+        (windows.get_single(), betting_ui_query.get_single(), teaching_panel_query.get_single_mut())
+    else {
+        return;
+    };
+    let window_size = Vec2::new(window.width(), window.height());
+    
+    let mut bottom = TEACHING_PANEL_BOTTOM;
+    let mut display = Display::Flex;
+    if *controls_visibility == Visibility::Visible {
+        let controls = bottom_panel_rect(controls_style, window_size);
+        let panel = bottom_panel_rect(&panel_style, window_size);
+        let panel_at_home = Rect::new(panel.min.x, bottom, panel.max.x, bottom + panel.height());
+        if !panel_at_home.intersect(controls).is_empty() {
+            if controls.max.y + TEACHING_PANEL_GAP + panel.height() <= window_size.y {
+                bottom = controls.max.y + TEACHING_PANEL_GAP;
+            } else {
+                display = Display::None;
+            }
+        }
+    }
+    
+    // Only write when something moved so the UI isn't re-laid out every frame
+    if panel_style.bottom != Val::Px(bottom) {
+        panel_style.bottom = Val::Px(bottom);
+    }
+    if panel_style.display != display {
+        panel_style.display = display;
+    }
+}
+
 // System to handle teaching keyboard shortcuts
 pub fn handle_teaching_input(
     input: Res<ButtonInput<KeyCode>>,
     mut teaching_state: ResMut<TeachingState>,
+    mut quiz_mode: ResMut<QuizMode>,
 ) {
     if input.just_pressed(KeyCode::KeyT) {
-        teaching_state.toggle_tutorial_mode();
+        teaching_state.cycle_coach_level();
+    }
+    
+    if input.just_pressed(KeyCode::KeyV) {
+        teaching_state.cycle_coach_persona();
     }
     
     if input.just_pressed(KeyCode::KeyH) {
         teaching_state.toggle_hand_rankings();
     }
     
+    if input.just_pressed(KeyCode::KeyQ) {
+        quiz_mode.toggle();
+    }
+    
+    if input.just_pressed(KeyCode::KeyF) {
+        teaching_state.toggle_reveal_folded_cards();
+    }
+    
+    if input.just_pressed(KeyCode::KeyL) {
+        teaching_state.toggle_tells();
+    }
+    
+    if input.just_pressed(KeyCode::KeyW) {
+        teaching_state.toggle_training_wheels();
+    }
+    
     if input.just_pressed(KeyCode::Escape) {
         teaching_state.hide_explanation();
     }
@@ -238,7 +547,7 @@ pub fn provide_contextual_explanations(
     mut teaching_state: ResMut<TeachingState>,
     players: Query<&Player>,
 ) {
-    if !teaching_state.tutorial_mode {
+    if !teaching_state.tutorial_mode() {
         return;
     }
     
@@ -247,6 +556,10 @@ pub fn provide_contextual_explanations(
         return;
     }
     
+    if !teaching_state.coach_level.explains(current_state.get()) {
+        return;
+    }
+    
     match current_state.get() {
         GameState::Setup => {
             teaching_state.show_explanation(ExplanationType::GamePhase(
@@ -290,10 +603,464 @@ pub fn provide_contextual_explanations(
                     "Game Over - Only one player has chips remaining! They are the winner.".to_string()
                 ));
             }
+        },
+        GameState::PostGameChoice => {}, // The prompt explains the choices itself
+    }
+}
+
+// Recommended action for the current spot, based on hand strength and the price to continue
+pub fn suggest_action(
+    hole_cards: &[crate::cards::Card],
+    community_cards: &[crate::cards::Card],
+    call_amount: u32,
+    pot: u32,
+    eval_cache: &HandEvalCache,
+) -> PlayerAction {
+    match evaluate_hand_strength(hole_cards, community_cards, eval_cache) {
+        HandStrength::VeryStrong | HandStrength::Strong => PlayerAction::Raise((pot / 2).max(1)),
+        HandStrength::Medium => {
+            if call_amount == 0 {
+                PlayerAction::Check
+            } else if call_amount * 3 <= pot + call_amount {
+                // Worth continuing when the price is no more than a third of the final pot
+                PlayerAction::Call
+            } else {
+                PlayerAction::Fold
+            }
+        },
+        HandStrength::Weak => {
+            if call_amount == 0 {
+                PlayerAction::Check
+            } else {
+                PlayerAction::Fold
+            }
+        },
+    }
+}
+
+// Rough pre-flop hand groups used for beginner guidance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartingHandTier {
+    Premium,  // JJ+ and AK
+    Strong,   // Mid pairs, big aces and broadway cards
+    Playable, // Small pairs, suited aces and suited connectors
+    Marginal,
+    Fold,     // Unsuited, unconnected and low: e.g. 7-2 offsuit
+}
+
+pub fn starting_hand_tier(hole_cards: &[crate::cards::Card]) -> StartingHandTier {
+    use crate::cards::Rank;
+    
+    if hole_cards.len() != 2 {
+        return StartingHandTier::Marginal;
+    }
+    let high = hole_cards[0].rank.max(hole_cards[1].rank);
+    let low = hole_cards[0].rank.min(hole_cards[1].rank);
+    let suited = hole_cards[0].suit == hole_cards[1].suit;
+    let gap = rank_value(high) - rank_value(low);
+    
+    if high == low {
+        return match high {
+            Rank::Jack | Rank::Queen | Rank::King | Rank::Ace => StartingHandTier::Premium,
+            Rank::Eight | Rank::Nine | Rank::Ten => StartingHandTier::Strong,
+            _ => StartingHandTier::Playable,
+        };
+    }
+    
+    if high == Rank::Ace && low == Rank::King {
+        StartingHandTier::Premium
+    } else if high >= Rank::Queen && low >= Rank::Ten {
+        StartingHandTier::Strong
+    } else if suited && (high == Rank::Ace || gap <= 2) {
+        StartingHandTier::Playable
+    } else if !suited && high < Rank::Queen && gap > 3 {
+        StartingHandTier::Fold
+    } else {
+        StartingHandTier::Marginal
+    }
+}
+
+// System for training wheels: fold clearly unplayable hands pre-flop for the human and say why
+pub fn auto_fold_trash_hands(
+    mut teaching_state: ResMut<TeachingState>,
+    mut human_input: ResMut<HumanPlayerInput>,
+    current_state: Res<State<GameState>>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+) {
+    if !teaching_state.training_wheels || *current_state.get() != GameState::PreFlop || human_input.pending_action.is_some() {
+        return;
+    }
+    
+    let Some(human_player) = betting_round
+        .peek_next_player()
+        .and_then(|id| players.iter().find(|p| p.id == id && matches!(p.player_type, PlayerType::Human)))
+    else {
+        return;
+    };
+    
+    // Never fold when checking is free
+    let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+    if call_amount == 0 || starting_hand_tier(&human_player.hole_cards) != StartingHandTier::Fold {
+        return;
+    }
+    
+    let high = human_player.hole_cards[0].rank.max(human_player.hole_cards[1].rank);
+    let low = human_player.hole_cards[0].rank.min(human_player.hole_cards[1].rank);
+    human_input.pending_action = Some(PlayerAction::Fold);
+    teaching_state.show_explanation(ExplanationType::PlayerAction(format!(
+        "🚲 Training wheels folded your {}-{} offsuit: low, unconnected cards rarely win. Save your chips for better hands.",
+        crate::rendering::rank_symbol(high),
+        crate::rendering::rank_symbol(low)
+    )));
+}
+
+fn action_label(action: &PlayerAction) -> &'static str {
+    match action {
+        PlayerAction::Fold => "FOLD",
+        PlayerAction::Check => "CHECK",
+        PlayerAction::Call => "CALL",
+        PlayerAction::Raise(_) => "RAISE",
+    }
+}
+
+// System to quiz the human on their turn and grade their answer before it is applied
+pub fn run_quiz_mode(
+    mut quiz_mode: ResMut<QuizMode>,
+    mut teaching_state: ResMut<TeachingState>,
+    human_input: Res<HumanPlayerInput>,
+    betting_round: Res<BettingRound>,
+    game_data: Res<crate::game_state::GameData>,
+    players: Query<&Player>,
+) {
+    if !quiz_mode.enabled {
+        return;
+    }
+    
+    let Some(human_player) = betting_round
+        .peek_next_player()
+        .and_then(|id| players.iter().find(|p| p.id == id && matches!(p.player_type, PlayerType::Human)))
+    else {
+        return;
+    };
+    
+    match &human_input.pending_action {
+        None => {
+            if !quiz_mode.awaiting_answer {
+                quiz_mode.awaiting_answer = true;
+                teaching_state.show_explanation(ExplanationType::PlayerAction(
+                    "🧠 Quiz: What's the best action here? FOLD, CHECK/CALL or RAISE?".to_string()
+                ));
+            }
+        },
+        Some(chosen) if quiz_mode.awaiting_answer => {
+            let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+            let recommended = suggest_action(
+                &human_player.hole_cards,
+                &game_data.community_cards,
+                call_amount,
+                betting_round.pot,
+                &game_data.eval_cache,
+            );
+            let recommended_label = action_label(&recommended);
+            
+            let feedback = if quiz_mode.grade(chosen, recommended) {
+                format!("✅ Correct! {} was the best play here.", recommended_label)
+            } else {
+                format!("❌ You chose {}, but the recommended action was {}.", action_label(chosen), recommended_label)
+            };
+            teaching_state.show_explanation(ExplanationType::PlayerAction(format!(
+                "{} (Score: {}/{})",
+                feedback,
+                quiz_mode.correct,
+                quiz_mode.correct + quiz_mode.incorrect
+            )));
+        },
+        Some(_) => {},
+    }
+}
+
+// System to read each AI's actions into a range estimate; the strongest action this hand sticks
+pub fn track_opponent_ranges(
+    current_state: Res<State<GameState>>,
+    betting_round: Res<BettingRound>,
+    players: Query<&Player>,
+    mut opponent_reads: ResMut<OpponentReads>,
+) {
+    if current_state.is_changed() && *current_state.get() == GameState::Setup {
+        opponent_reads.ranges.clear();
+        return;
+    }
+    if !betting_round.is_changed() {
+        return;
+    }
+    
+    for (player_id, action) in &betting_round.action_history {
+        let is_ai = players
+            .iter()
+            .any(|p| p.id == *player_id && matches!(p.player_type, PlayerType::AI));
+        let Some(estimate) = RangeEstimate::from_action(action).filter(|_| is_ai) else {
+            continue;
+        };
+        
+        let range = opponent_reads.ranges.entry(*player_id).or_insert(estimate);
+        *range = (*range).max(estimate);
+    }
+}
+
+// System to show the range reads under each opponent while tutorial mode is on
+pub fn update_opponent_range_display(
+    teaching_state: Res<TeachingState>,
+    opponent_reads: Res<OpponentReads>,
+    players: Query<&Player>,
+    mut range_text_query: Query<(&crate::ui::OpponentRangeText, &mut Text)>,
+) {
+    for (range_text, mut text) in range_text_query.iter_mut() {
+        let folded = players.iter().any(|p| p.id == range_text.player_id && p.has_folded);
+        let label = match opponent_reads.ranges.get(&range_text.player_id) {
+            Some(range) if teaching_state.tutorial_mode() && !folded => range.label(),
+            _ => "",
+        };
+        if text.sections[0].value != label {
+            text.sections[0].value = label.to_string();
         }
     }
 }
 
+// System to show each AI's tell under their panel while the tell flag is on
+pub fn update_ai_tells(
+    teaching_state: Res<TeachingState>,
+    game_data: Res<crate::game_state::GameData>,
+    players: Query<&Player>,
+    mut tell_text_query: Query<(&crate::ui::TellText, &mut Text)>,
+) {
+    for (tell_text, mut text) in tell_text_query.iter_mut() {
+        let tell = players
+            .iter()
+            .find(|p| p.id == tell_text.player_id && !p.has_folded)
+            .filter(|_| teaching_state.show_tells)
+            .and_then(|player| crate::ai_player::ai_tell(&player.hole_cards, &game_data.community_cards, &game_data.eval_cache))
+            .unwrap_or("");
+        if text.sections[0].value != tell {
+            text.sections[0].value = tell.to_string();
+        }
+    }
+}
+
+// Board textures that make a bet look strong
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardThreat {
+    FlushPossible,    // Three or more of one suit
+    StraightPossible, // Three ranks that fit in one straight
+    Paired,
+    HighCards,        // An ace or king is out
+}
+
+impl BoardThreat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BoardThreat::FlushPossible => "flush possible",
+            BoardThreat::StraightPossible => "straight possible",
+            BoardThreat::Paired => "paired board",
+            BoardThreat::HighCards => "big cards out",
+        }
+    }
+}
+
+/// Scary features of the board that an opponent has to respect when facing a bet
+pub fn board_threats(community_cards: &[crate::cards::Card]) -> Vec<BoardThreat> {
+    let mut threats = Vec::new();
+    
+    let mut suit_counts = std::collections::HashMap::new();
+    for card in community_cards {
+        *suit_counts.entry(card.suit).or_insert(0) += 1;
+    }
+    if suit_counts.values().any(|&count| count >= 3) {
+        threats.push(BoardThreat::FlushPossible);
+    }
+    
+    // Ace plays both low (1) and high (14)
+    let mut values: Vec<u8> = community_cards.iter().map(|card| rank_value(card.rank)).collect();
+    if values.contains(&14) {
+        values.push(1);
+    }
+    values.sort();
+    values.dedup();
+    if (1..=10).any(|low| values.iter().filter(|&&value| value >= low && value < low + 5).count() >= 3) {
+        threats.push(BoardThreat::StraightPossible);
+    }
+    
+    if community_cards.iter().enumerate().any(|(i, card)| community_cards[..i].iter().any(|other| other.rank == card.rank)) {
+        threats.push(BoardThreat::Paired);
+    }
+    
+    if community_cards.iter().any(|card| card.rank >= crate::cards::Rank::King) {
+        threats.push(BoardThreat::HighCards);
+    }
+    
+    threats
+}
+
+/// A "consider a bluff" tip when a weak hand faces a lone passive opponent on a scary board
+pub fn bluff_tip(
+    hole_cards: &[crate::cards::Card],
+    community_cards: &[crate::cards::Card],
+    opponent_reads: &[Option<RangeEstimate>],
+    eval_cache: &HandEvalCache,
+) -> Option<String> {
+    // Fold equity needs a single opponent who has shown no strength
+    if community_cards.len() < 3 || opponent_reads.len() != 1 || opponent_reads[0] != Some(RangeEstimate::Wide) {
+        return None;
+    }
+    
+    // Only for hands that can't win by checking down: nothing beyond what the board shows
+    let hand_rank = eval_cache.evaluate(hole_cards, community_cards).rank;
+    let board_rank = eval_cache.evaluate(&[], community_cards).rank;
+    if hand_rank > crate::poker_rules::HandRank::HighCard && hand_rank > board_rank {
+        return None;
+    }
+    
+    let threats = board_threats(community_cards);
+    if threats.is_empty() {
+        return None;
+    }
+    
+    let labels: Vec<&str> = threats.iter().map(|threat| threat.label()).collect();
+    Some(format!(
+        "Consider a bluff here: your hand is weak, but the board is scary ({}) and your opponent has only checked. A bet can win the pot right now.",
+        labels.join(", ")
+    ))
+}
+
+/// An implied-odds tip for a draw whose immediate pot odds fall short, when the money still
+/// behind and an opponent likely to pay off can make up the difference on the next card
+pub fn implied_odds_note(
+    hole_cards: &[crate::cards::Card],
+    community_cards: &[crate::cards::Card],
+    call_amount: u32,
+    pot: u32,
+    effective_stack: u32,
+    opponent_read: Option<RangeEstimate>,
+) -> Option<String> {
+    if call_amount == 0 || !(3..5).contains(&community_cards.len()) {
+        return None;
+    }
+    
+    // Chance the next card completes the draw
+    let outs = crate::odds::count_outs(hole_cards, community_cards).len();
+    if outs == 0 {
+        return None;
+    }
+    let hit_chance = outs as f32 / (52 - 2 - community_cards.len()) as f32;
+    let required = crate::odds::pot_odds(call_amount, pot);
+    if hit_chance >= required {
+        return None; // The call already pays for itself
+    }
+    
+    // Extra to win once the draw comes in for the call to break even, against what the
+    // opponent might pay off: players who have called or raised put more in with a made hand
+    let needed_later = (call_amount as f32 / hit_chance - (pot + call_amount) as f32).ceil() as u32;
+    let payoff_share = match opponent_read {
+        Some(RangeEstimate::Strong) => 0.5,
+        Some(RangeEstimate::Medium) => 0.3,
+        _ => 0.15,
+    };
+    if (effective_stack.saturating_sub(call_amount) as f32 * payoff_share) < needed_later as f32 {
+        return None;
+    }
+    
+    Some(format!(
+        "Implied odds: with {} outs you hit {:.0}% of the time, short of the {:.0}% the pot offers - but winning about ${} more when you hit makes up the difference. With ${} behind, calling the draw is justified.",
+        outs,
+        hit_chance * 100.0,
+        required * 100.0,
+        needed_later,
+        effective_stack
+    ))
+}
+
+// System to point out bluffing and implied-odds spots when the human's turn starts
+pub fn suggest_spot_tips(
+    mut teaching_state: ResMut<TeachingState>,
+    current_state: Res<State<GameState>>,
+    game_data: Res<crate::game_state::GameData>,
+    betting_round: Res<BettingRound>,
+    opponent_reads: Res<OpponentReads>,
+    players: Query<&Player>,
+    mut human_was_to_act: Local<bool>,
+) {
+    if current_state.get().street_index().is_none_or(|street| street == 0) {
+        *human_was_to_act = false;
+        return;
+    }
+    
+    let Some(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)) else {
+        return;
+    };
+    let human_to_act = betting_round.peek_next_player() == Some(human_player.id) && !human_player.has_folded;
+    let turn_started = human_to_act && !*human_was_to_act;
+    *human_was_to_act = human_to_act;
+    if !turn_started || !teaching_state.tutorial_mode() {
+        return;
+    }
+    
+    let opponents: Vec<&Player> = players.iter().filter(|p| p.id != human_player.id && !p.has_folded).collect();
+    let reads: Vec<Option<RangeEstimate>> = opponents.iter().map(|p| opponent_reads.ranges.get(&p.id).copied()).collect();
+    if let Some(tip) = bluff_tip(&human_player.hole_cards, &game_data.community_cards, &reads, &game_data.eval_cache) {
+        teaching_state.show_explanation(ExplanationType::PlayerAction(tip));
+        return;
+    }
+    
+    let call_amount = betting_round.current_bet.saturating_sub(human_player.current_bet);
+    let effective_stack = opponents.iter().map(|p| p.chips).max().unwrap_or(0).min(human_player.chips);
+    let strongest_read = reads.iter().flatten().max().copied();
+    if let Some(tip) = implied_odds_note(
+        &human_player.hole_cards,
+        &game_data.community_cards,
+        call_amount,
+        betting_round.pot,
+        effective_stack,
+        strongest_read,
+    ) {
+        teaching_state.show_explanation(ExplanationType::PlayerAction(tip));
+    }
+}
+
+/// Walk-through of the blinds for the first hand, naming who posted each one
+pub fn blinds_walkthrough(game_position: &GamePosition, players: &[&Player]) -> String {
+    let name = |id: u32| match players.iter().find(|p| p.id == id).map(|p| &p.player_type) {
+        Some(PlayerType::Human) => "You".to_string(),
+        _ => format!("AI Player {}", id),
+    };
+    format!(
+        "Before any cards are seen, two players post forced bets called blinds so there's always something to play for. {} posted the small blind (${}, marked SB) and {} posted the big blind (${}, marked BB). Everyone else must call ${}, raise, or fold. The blinds move one seat left each hand, following the dealer button (D).",
+        name(game_position.get_small_blind_player()),
+        game_position.small_blind_amount,
+        name(game_position.get_big_blind_player()),
+        game_position.big_blind_amount,
+        game_position.big_blind_amount,
+    )
+}
+
+// System to pause the first hand once the blinds are posted and explain them
+pub fn explain_blinds_on_first_hand(
+    game_state: Res<State<GameState>>,
+    game_position: Res<GamePosition>,
+    players: Query<&Player>,
+    mut teaching_state: ResMut<TeachingState>,
+) {
+    if !game_state.is_changed() || *game_state.get() != GameState::PreFlop {
+        return;
+    }
+    if !teaching_state.tutorial_mode() || teaching_state.blinds_explained {
+        return;
+    }
+    
+    let players: Vec<&Player> = players.iter().collect();
+    teaching_state.queue_checkpoint(blinds_walkthrough(&game_position, &players));
+    teaching_state.blinds_explained = true;
+}
+
 // System to explain hand rankings when requested
 pub fn explain_hand_rankings(
     teaching_state: Res<TeachingState>,
@@ -318,10 +1085,11 @@ pub fn highlight_valid_actions(
     mut teaching_state: ResMut<TeachingState>,
     current_state: Res<State<GameState>>,
     game_data: Res<crate::game_state::GameData>,
+    betting_round: Res<BettingRound>,
     players: Query<&Player>,
     mut hand_analysis_query: Query<&mut Text, With<HandAnalysisDisplay>>,
 ) {
-    if !teaching_state.tutorial_mode {
+    if !teaching_state.tutorial_mode() {
         // Clear display if tutorial mode is off
         if let Ok(mut text) = hand_analysis_query.get_single_mut() {
             text.sections[0].value = "".to_string();
@@ -329,6 +1097,11 @@ pub fn highlight_valid_actions(
         return;
     }
     
+    // Find human player
+    let Some(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)) else {
+        return; // No human at the table (e.g. an AI-only simulation)
+    };
+    
     // Check if state has changed or current player has changed
     let current_game_state = current_state.get().clone();
     let state_changed = teaching_state.last_game_state != Some(current_game_state);
@@ -347,33 +1120,35 @@ pub fn highlight_valid_actions(
         teaching_state.messages_shown_this_state.clear();
     }
     
-    // Find human player
-    if let Ok(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)).ok_or("No human player") {
-        match current_state.get() {
-            GameState::PreFlop | GameState::Flop | GameState::Turn | GameState::River => {
-                if !human_player.has_folded && human_player.chips > 0 && game_data.current_player == 0 {
-                    let message = "💡 Your Turn!\nOptions:\n• FOLD - Quit this hand\n• CHECK/CALL - Match current bet\n• RAISE - Increase the bet".to_string();
-                    
-                    if !teaching_state.messages_shown_this_state.contains(&message) {
-                        // Update UI display
-                        if let Ok(mut text) = hand_analysis_query.get_single_mut() {
-                            text.sections[0].value = message.clone();
-                        }
-                        
-                        // Keep log for reference
-                        info!("💡 Your options: FOLD (quit hand), CHECK/CALL (match bet), RAISE (increase bet)");
-                        teaching_state.messages_shown_this_state.insert(message);
+    match current_state.get() {
+        state if state.street_index().is_some() => {
+            if !human_player.has_folded && human_player.chips > 0 && game_data.current_player == human_player.id {
+                let check_is_free = betting_round.current_bet <= human_player.current_bet;
+                let message = if check_is_free {
+                    "💡 Your Turn!\nChecking is free - there's no bet to call, so CHECK rather than FOLD.\n• CHECK - See the next card for nothing\n• RAISE - Bet to build the pot".to_string()
+                } else {
+                    "💡 Your Turn!\nOptions:\n• FOLD - Quit this hand\n• CHECK/CALL - Match current bet\n• RAISE - Increase the bet".to_string()
+                };
+                
+                if !teaching_state.messages_shown_this_state.contains(&message) {
+                    // Update UI display
+                    if let Ok(mut text) = hand_analysis_query.get_single_mut() {
+                        text.sections[0].value = message.clone();
                     }
-                } else if let Ok(mut text) = hand_analysis_query.get_single_mut() {
-                    // Clear display when it's not human player's turn
-                    text.sections[0].value = "".to_string();
-                }
-            },
-            _ => {
-                // Clear display for non-betting phases
-                if let Ok(mut text) = hand_analysis_query.get_single_mut() {
-                    text.sections[0].value = "".to_string();
+                    
+                    // Keep log for reference
+                    info!("💡 Your options: FOLD (quit hand), CHECK/CALL (match bet), RAISE (increase bet)");
+                    teaching_state.messages_shown_this_state.insert(message);
                 }
+            } else if let Ok(mut text) = hand_analysis_query.get_single_mut() {
+                // Clear display when it's not human player's turn
+                text.sections[0].value = "".to_string();
+            }
+        },
+        _ => {
+            // Clear display for non-betting phases
+            if let Ok(mut text) = hand_analysis_query.get_single_mut() {
+                text.sections[0].value = "".to_string();
             }
         }
     }
@@ -388,39 +1163,52 @@ pub fn provide_hand_analysis(
     players: Query<&Player>,
     mut hand_analysis_query: Query<&mut Text, With<HandAnalysisDisplay>>,
 ) {
-    if !teaching_state.tutorial_mode {
+    if !teaching_state.tutorial_mode() {
         return;
     }
     
-    // Only update when state changes
-    if !current_state.is_changed() {
+    // Only update when state changes or new board cards are dealt
+    if !current_state.is_changed() && !game_data.is_changed() {
         return;
     }
     
     // Find human player
-    if let Ok(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)).ok_or("No human player") {
-        match current_state.get() {
-            GameState::PreFlop => {
-                if !human_player.hole_cards.is_empty() {
-                    let analysis = analyze_starting_hand_ui(&human_player.hole_cards, &betting_round);
-                    if let Ok(mut text) = hand_analysis_query.get_single_mut() {
-                        text.sections[0].value = analysis;
-                    }
-                }
-            },
-            GameState::Flop | GameState::Turn | GameState::River => {
-                // For now, clear the hand analysis during later phases
-                // We could add more detailed analysis here later
+    let Some(human_player) = players.iter().find(|p| matches!(p.player_type, PlayerType::Human)) else {
+        return; // No human at the table (e.g. an AI-only simulation)
+    };
+    
+    match current_state.get() {
+        GameState::PreFlop => {
+            if !human_player.hole_cards.is_empty() {
+                let analysis = analyze_starting_hand_ui(&human_player.hole_cards, &betting_round);
                 if let Ok(mut text) = hand_analysis_query.get_single_mut() {
-                    text.sections[0].value = "📊 Community cards revealed!\nAnalyze how they improve\nyour hand strength.".to_string();
+                    text.sections[0].value = analysis;
                 }
-            },
-            _ => {
-                // Clear analysis display for other phases
-                if let Ok(mut text) = hand_analysis_query.get_single_mut() {
-                    text.sections[0].value = "".to_string();
+            }
+        },
+        GameState::Flop | GameState::Turn | GameState::River => {
+            // For now, clear the hand analysis during later phases
+            // We could add more detailed analysis here later
+            let mut analysis = "📊 Community cards revealed!\nAnalyze how they improve\nyour hand strength.".to_string();
+            
+            // Point out straight draws, including wheel and Broadway draws where the ace plays both ways
+            if game_data.community_cards.len() < 5 {
+                let draw_ranks = crate::odds::straight_draw_ranks(&human_player.hole_cards, &game_data.community_cards);
+                if !draw_ranks.is_empty() {
+                    let needed: Vec<&str> = draw_ranks.iter().map(|&rank| get_rank_name(rank)).collect();
+                    analysis.push_str(&format!("\n🎯 Straight draw: needs a {}", needed.join(" or ")));
                 }
             }
+            
+            if let Ok(mut text) = hand_analysis_query.get_single_mut() {
+                text.sections[0].value = analysis;
+            }
+        },
+        _ => {
+            // Clear analysis display for other phases
+            if let Ok(mut text) = hand_analysis_query.get_single_mut() {
+                text.sections[0].value = "".to_string();
+            }
         }
     }
 }
@@ -579,3 +1367,346 @@ fn analyze_river_situation(hole_cards: &[crate::cards::Card], community_cards: &
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{Card, Suit, Rank};
+    
+    #[test]
+    fn test_quiz_grades_correct_and_incorrect_answers() {
+        let mut quiz_mode = QuizMode { enabled: true, ..default() };
+        
+        assert!(quiz_mode.grade(&PlayerAction::Fold, PlayerAction::Fold));
+        assert_eq!(quiz_mode.correct, 1);
+        assert_eq!(quiz_mode.last_recommendation, None);
+        
+        assert!(!quiz_mode.grade(&PlayerAction::Call, PlayerAction::Raise(50)));
+        assert_eq!(quiz_mode.incorrect, 1);
+        assert_eq!(quiz_mode.last_recommendation, Some(PlayerAction::Raise(50)));
+    }
+    
+    #[test]
+    fn test_suggest_action_raises_aces_and_folds_trash_to_a_bet() {
+        let aces = vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Spades, Rank::Ace)];
+        let trash = vec![Card::new(Suit::Hearts, Rank::Seven), Card::new(Suit::Spades, Rank::Two)];
+        
+        let eval_cache = HandEvalCache::default();
+        
+        assert!(matches!(suggest_action(&aces, &[], 20, 30, &eval_cache), PlayerAction::Raise(_)));
+        assert_eq!(suggest_action(&trash, &[], 20, 30, &eval_cache), PlayerAction::Fold);
+        assert_eq!(suggest_action(&trash, &[], 0, 30, &eval_cache), PlayerAction::Check);
+        
+        // Post-flop advice reuses the hand's cached evaluation
+        let flop = [
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Four),
+        ];
+        suggest_action(&aces, &flop, 20, 30, &eval_cache);
+        suggest_action(&aces, &flop, 0, 30, &eval_cache);
+        assert_eq!(eval_cache.len(), 1);
+    }
+    
+    #[test]
+    fn test_ai_raise_reads_as_strong_range() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<BettingRound>()
+            .init_resource::<OpponentReads>()
+            .add_systems(Update, track_opponent_ranges);
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Flop);
+        app.update();
+        
+        // The AI holds pocket kings and raises; the human just calls
+        let mut ai = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        ai.hole_cards = vec![
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Hearts, Rank::King),
+        ];
+        app.world_mut().spawn(ai);
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().resource_mut::<BettingRound>().action_history = vec![
+            (1, PlayerAction::Check),
+            (0, PlayerAction::Check),
+            (1, PlayerAction::Raise(80)),
+            (0, PlayerAction::Call),
+        ];
+        app.update();
+        
+        let reads = app.world().resource::<OpponentReads>();
+        assert_eq!(reads.ranges.get(&1), Some(&RangeEstimate::Strong));
+        assert!(reads.ranges.get(&1).unwrap().label().contains("strong"));
+        // Only AI opponents are read
+        assert!(!reads.ranges.contains_key(&0));
+        
+        // A new street's checks don't weaken the read
+        app.world_mut().resource_mut::<BettingRound>().action_history = vec![(1, PlayerAction::Check)];
+        app.update();
+        assert_eq!(app.world().resource::<OpponentReads>().ranges.get(&1), Some(&RangeEstimate::Strong));
+    }
+    
+    fn explanations_per_transition(coach_level: CoachLevel) -> Vec<Option<String>> {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .init_state::<GameState>()
+            .insert_resource(TeachingState { coach_level, ..default() })
+            .add_systems(Update, provide_contextual_explanations);
+        
+        let mut explanations = Vec::new();
+        for state in [GameState::Dealing, GameState::PreFlop, GameState::Flop, GameState::Turn, GameState::River, GameState::Showdown] {
+            app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+            app.update();
+            explanations.push(app.world_mut().resource_mut::<TeachingState>().current_explanation.take());
+        }
+        explanations
+    }
+    
+    #[test]
+    fn test_blinds_checkpoint_pauses_first_hand_only() {
+        use crate::test_support::{build_test_app, spawn_simple_ai_players};
+        
+        let mut app = build_test_app();
+        app.init_resource::<TeachingState>()
+            .add_systems(Update, explain_blinds_on_first_hand.before(crate::betting::ai_player_system));
+        spawn_simple_ai_players(&mut app, 3);
+        
+        let state = |app: &App| *app.world().resource::<State<GameState>>().get();
+        for _ in 0..10 {
+            app.update();
+            if state(&app) == GameState::PreFlop {
+                break;
+            }
+        }
+        let teaching_state = app.world().resource::<TeachingState>();
+        assert_eq!(teaching_state.checkpoints.len(), 1);
+        assert!(teaching_state.checkpoints[0].contains("AI Player 1 posted the small blind ($10, marked SB)"));
+        assert!(teaching_state.current_explanation.as_ref().unwrap().starts_with("⏸️ Tutorial:"));
+        
+        // Nothing moves until the checkpoint is dismissed
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(state(&app), GameState::PreFlop);
+        assert!(app.world().resource::<BettingRound>().action_history.is_empty());
+        app.world_mut().resource_mut::<TeachingState>().hide_explanation();
+        
+        // The second hand starts without a checkpoint
+        let mut hands_started = 1;
+        for _ in 0..200 {
+            let was_preflop = state(&app) == GameState::PreFlop;
+            app.update();
+            if !was_preflop && state(&app) == GameState::PreFlop {
+                hands_started += 1;
+                break;
+            }
+        }
+        assert_eq!(hands_started, 2, "a second hand should reach pre-flop");
+        assert!(!app.world().resource::<TeachingState>().at_checkpoint());
+    }
+    
+    #[test]
+    fn test_tells_show_only_for_strong_ai_hands() {
+        use crate::game_state::GameData;
+        use crate::ui::TellText;
+        
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TeachingState { show_tells: true, ..default() })
+            .init_resource::<GameData>()
+            .add_systems(Update, update_ai_tells);
+        let mut aces = Player::new(1, PlayerType::AI, 1000, Vec3::ZERO);
+        aces.hole_cards = vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::Ace)];
+        let mut trash = Player::new(2, PlayerType::AI, 1000, Vec3::ZERO);
+        trash.hole_cards = vec![Card::new(Suit::Clubs, Rank::Seven), Card::new(Suit::Diamonds, Rank::Two)];
+        app.world_mut().spawn_batch([aces, trash]);
+        let strong_tell = app
+            .world_mut()
+            .spawn((TextBundle::from_section("", TextStyle::default()), TellText { player_id: 1 }))
+            .id();
+        let weak_tell = app
+            .world_mut()
+            .spawn((TextBundle::from_section("", TextStyle::default()), TellText { player_id: 2 }))
+            .id();
+        
+        let tell = |app: &App, entity: Entity| app.world().get::<Text>(entity).unwrap().sections[0].value.clone();
+        app.update();
+        assert_eq!(tell(&app, strong_tell), "🤲 Stacking chips");
+        assert_eq!(tell(&app, weak_tell), "");
+        
+        // With the flag off nobody gives anything away
+        app.world_mut().resource_mut::<TeachingState>().toggle_tells();
+        app.update();
+        assert_eq!(tell(&app, strong_tell), "");
+    }
+    
+    #[test]
+    fn test_coach_level_gates_phase_explanations() {
+        let silent = explanations_per_transition(CoachLevel::Silent);
+        assert!(silent.iter().all(|explanation| explanation.is_none()));
+        
+        let verbose = explanations_per_transition(CoachLevel::Verbose);
+        assert!(verbose.iter().all(|explanation| explanation.is_some()));
+        
+        let key_moments = explanations_per_transition(CoachLevel::KeyMoments);
+        let fired: Vec<bool> = key_moments.iter().map(|explanation| explanation.is_some()).collect();
+        assert_eq!(fired, vec![false, true, false, false, false, true]);
+        
+        assert!(!TeachingState { coach_level: CoachLevel::Silent, ..default() }.tutorial_mode());
+        assert!(TeachingState { coach_level: CoachLevel::KeyMoments, ..default() }.tutorial_mode());
+    }
+    
+    #[test]
+    fn test_teaching_panel_never_covers_betting_controls_on_small_screens() {
+        use bevy::window::WindowResolution;
+        use crate::betting_ui::setup_betting_ui;
+        use crate::theme::Palette;
+        
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Palette>()
+            .add_systems(Startup, (setup_betting_ui, setup_teaching_ui))
+            .add_systems(Update, arbitrate_teaching_panel_layout);
+        // iPhone SE, portrait
+        let window = app.world_mut().spawn((Window { resolution: WindowResolution::new(375.0, 667.0), ..default() }, PrimaryWindow)).id();
+        app.update();
+        
+        let rects = |app: &mut App| {
+            let window = app.world_mut().query::<&Window>().single(app.world());
+            let window_size = Vec2::new(window.width(), window.height());
+            let controls = app.world_mut().query_filtered::<&Style, With<BettingUI>>().single(app.world()).clone();
+            let panel = app.world_mut().query_filtered::<&Style, With<TeachingPopup>>().single(app.world()).clone();
+            (bottom_panel_rect(&controls, window_size), bottom_panel_rect(&panel, window_size), panel.display)
+        };
+        
+        // A long tip lets the panel grow tall enough to reach the controls
+        let mut panel = app.world_mut().query_filtered::<&mut Style, With<TeachingPopup>>();
+        panel.single_mut(app.world_mut()).max_height = Val::Px(220.0);
+        let (controls, home, _) = rects(&mut app);
+        assert!(!home.intersect(controls).is_empty(), "the test should start from an overlap");
+        
+        let mut controls_visibility = app.world_mut().query_filtered::<&mut Visibility, With<BettingUI>>();
+        *controls_visibility.single_mut(app.world_mut()) = Visibility::Visible;
+        app.update();
+        let (controls, panel, display) = rects(&mut app);
+        assert_eq!(display, Display::Flex);
+        assert!(panel.intersect(controls).is_empty(), "{:?} covers {:?}", panel, controls);
+        
+        // Landscape leaves no room above the controls, so the panel collapses instead
+        app.world_mut().get_mut::<Window>(window).unwrap().resolution = WindowResolution::new(667.0, 375.0);
+        app.update();
+        assert_eq!(rects(&mut app).2, Display::None);
+        
+        // Once the controls hide, the panel is back in its corner
+        *controls_visibility.single_mut(app.world_mut()) = Visibility::Hidden;
+        app.update();
+        let (_, panel, display) = rects(&mut app);
+        assert_eq!(display, Display::Flex);
+        assert_eq!(panel.min.y, TEACHING_PANEL_BOTTOM);
+    }
+    
+    #[test]
+    fn test_coach_persona_rewords_the_same_tip() {
+        let tip = "Flop - 3 community cards revealed! Now you can make poker hands with 5 cards total.";
+        let explain = |coach_persona: CoachPersona| {
+            let mut teaching_state = TeachingState { coach_persona, ..default() };
+            teaching_state.show_explanation(ExplanationType::GamePhase(tip.to_string()));
+            teaching_state.current_explanation.unwrap()
+        };
+        
+        assert_eq!(explain(CoachPersona::Standard), format!("🎮 Game Phase: {}", tip));
+        assert_eq!(explain(CoachPersona::Concise), "🎮 Game Phase: Flop - 3 community cards revealed!");
+        let encouraging = explain(CoachPersona::Encouraging);
+        assert!(encouraging.contains(tip) && encouraging.contains("getting the hang of this"), "{}", encouraging);
+        assert_ne!(encouraging, explain(CoachPersona::Strict));
+        
+        // A single sentence has nothing to trim
+        assert_eq!(CoachPersona::Concise.phrase("Fold weak hands."), "Fold weak hands.");
+    }
+    
+    #[test]
+    fn test_bluff_tip_on_scary_board_against_passive_opponent() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .insert_state(GameState::Flop)
+            .init_resource::<TeachingState>()
+            .init_resource::<crate::game_state::GameData>()
+            .init_resource::<BettingRound>()
+            .init_resource::<OpponentReads>()
+            .add_systems(Update, suggest_spot_tips);
+        
+        // Seven-deuce on a three-heart, ace-high flop; the lone AI checked
+        let mut human = Player::new(0, PlayerType::Human, 1000, Vec3::ZERO);
+        human.hole_cards = vec![Card::new(Suit::Clubs, Rank::Seven), Card::new(Suit::Diamonds, Rank::Two)];
+        app.world_mut().spawn(human);
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        app.world_mut().resource_mut::<crate::game_state::GameData>().community_cards = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Nine),
+        ];
+        app.world_mut().resource_mut::<OpponentReads>().ranges.insert(1, RangeEstimate::Wide);
+        app.world_mut().resource_mut::<BettingRound>().players_to_act = vec![0];
+        app.update();
+        
+        let explanation = app.world().resource::<TeachingState>().current_explanation.clone().unwrap();
+        assert!(explanation.contains("Consider a bluff here"), "{}", explanation);
+        assert!(explanation.contains("flush possible"));
+        
+        // No bluff tip once the opponent has shown strength
+        let strong = bluff_tip(
+            &[Card::new(Suit::Clubs, Rank::Seven), Card::new(Suit::Diamonds, Rank::Two)],
+            &[Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Hearts, Rank::Queen), Card::new(Suit::Hearts, Rank::Nine)],
+            &[Some(RangeEstimate::Strong)],
+            &HandEvalCache::default(),
+        );
+        assert!(strong.is_none());
+    }
+    
+    #[test]
+    fn test_deep_stacked_flush_draw_gets_implied_odds_tip() {
+        let hole = [Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Hearts, Rank::Four)];
+        let flop = [
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+        
+        // $30 into $100 needs 23%; nine outs hit the turn about 19% of the time
+        let tip = implied_odds_note(&hole, &flop, 30, 100, 1000, Some(RangeEstimate::Medium)).unwrap();
+        assert!(tip.starts_with("Implied odds: with 9 outs"), "{}", tip);
+        
+        // Short stacks leave nothing to win later
+        assert!(implied_odds_note(&hole, &flop, 30, 100, 60, Some(RangeEstimate::Medium)).is_none());
+        // A good price needs no implied odds
+        assert!(implied_odds_note(&hole, &flop, 10, 100, 1000, Some(RangeEstimate::Medium)).is_none());
+    }
+    
+    #[test]
+    fn test_training_wheels_fold_seven_deuce_but_not_aces() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .insert_state(GameState::PreFlop)
+            .insert_resource(TeachingState { training_wheels: true, ..default() })
+            .init_resource::<HumanPlayerInput>()
+            .insert_resource(BettingRound { current_bet: 20, players_to_act: vec![0], ..default() })
+            .add_systems(Update, auto_fold_trash_hands);
+        let mut human = Player::new(0, PlayerType::Human, 1000, Vec3::ZERO);
+        human.hole_cards = vec![Card::new(Suit::Hearts, Rank::Seven), Card::new(Suit::Spades, Rank::Two)];
+        let human = app.world_mut().spawn(human).id();
+        
+        app.update();
+        assert_eq!(app.world().resource::<HumanPlayerInput>().pending_action, Some(PlayerAction::Fold));
+        let explanation = app.world().resource::<TeachingState>().current_explanation.clone().unwrap();
+        assert!(explanation.contains("Training wheels folded your 7-2 offsuit"), "{}", explanation);
+        
+        // A premium hand is left for the human to play
+        app.world_mut().resource_mut::<HumanPlayerInput>().pending_action = None;
+        app.world_mut().get_mut::<Player>(human).unwrap().hole_cards =
+            vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Spades, Rank::Ace)];
+        app.update();
+        assert!(app.world().resource::<HumanPlayerInput>().pending_action.is_none());
+        assert_eq!(starting_hand_tier(&[Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Spades, Rank::Ace)]), StartingHandTier::Premium);
+    }
+}
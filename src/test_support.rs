@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use std::time::Duration;
+use crate::cards::Deck;
+use crate::player::{Player, PlayerType};
+use crate::game_state::{GameState, GameData, GameEvent, GamePosition};
+use crate::game_config::GameConfig;
+use crate::game_controller::{self, GameController};
+use crate::betting::{self, BettingRound};
+use crate::betting_ui::HumanPlayerInput;
+use crate::game_speed::{self, GameClock};
+
+// Build a headless app running the core game loop. Every update advances the game
+// clock by a few seconds so the controller's state timers finish on each frame.
+pub fn build_test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .insert_resource(GameClock { manual_step: Some(Duration::from_secs(3)), ..default() })
+        .init_state::<GameState>()
+        .init_resource::<Deck>()
+        .init_resource::<GameData>()
+        .init_resource::<GameConfig>()
+        .init_resource::<GamePosition>()
+        .init_resource::<GameController>()
+        .init_resource::<BettingRound>()
+        .init_resource::<HumanPlayerInput>()
+        .add_event::<GameEvent>()
+        .add_systems(First, game_speed::advance_game_clock)
+        .add_systems(
+            Update,
+            (
+                game_controller::game_state_controller,
+                betting::ai_player_system,
+                betting::check_betting_round_complete,
+            ),
+        );
+    app
+}
+
+// Spawn AI players without an AIPlayerComponent so they use the deterministic simple AI
+pub fn spawn_simple_ai_players(app: &mut App, count: u32) {
+    for id in 0..count {
+        app.world_mut().spawn(Player::new(id, PlayerType::AI, 1000, Vec3::ZERO));
+    }
+}
+
+// Deal two hole cards to every player from a freshly shuffled deck
+pub fn deal_hole_cards(app: &mut App) {
+    let world = app.world_mut();
+    let mut players = world.query::<&mut Player>();
+    let player_count = players.iter(world).count();
+    
+    let mut dealt = Vec::new();
+    {
+        let mut deck = world.resource_mut::<Deck>();
+        deck.reset();
+        for _ in 0..player_count {
+            dealt.push(vec![deck.deal().unwrap(), deck.deal().unwrap()]);
+        }
+    }
+    
+    for (mut player, cards) in players.iter_mut(world).zip(dealt) {
+        player.hole_cards = cards;
+    }
+}
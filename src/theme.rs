@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+use crate::betting_ui::{BettingButton, BettingButtonAction, BettingUI};
+use crate::ui::GameInfoUI;
+
+// Resource holding every UI color, so a theme can be swapped at runtime
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub high_contrast: bool,
+    pub panel_background: Color,
+    pub text: Color,
+    pub button_normal: Color,
+    pub button_hover: Color,
+    pub button_pressed: Color,
+    pub button_border: Color,
+    pub fold_button: Color,
+    pub call_button: Color,
+    pub raise_button: Color,
+    pub human_panel: Color,
+    pub ai_panel: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl Palette {
+    pub fn standard() -> Self {
+        Self {
+            high_contrast: false,
+            panel_background: Color::srgba(0.1, 0.1, 0.1, 0.8),
+            text: Color::srgb(0.9, 0.9, 0.9),
+            button_normal: Color::srgb(0.3, 0.3, 0.3),
+            button_hover: Color::srgb(0.4, 0.4, 0.4),
+            button_pressed: Color::srgb(0.2, 0.2, 0.2),
+            button_border: Color::NONE,
+            fold_button: Color::srgb(0.7, 0.2, 0.2),
+            call_button: Color::srgb(0.2, 0.6, 0.2),
+            raise_button: Color::srgb(0.2, 0.4, 0.7),
+            human_panel: Color::srgb(0.2, 0.6, 0.2),
+            ai_panel: Color::srgb(0.6, 0.6, 0.2),
+        }
+    }
+    
+    /// Opaque dark surfaces with white text and borders; every fill keeps at least
+    /// the 4.5:1 contrast against white text that WCAG AA asks for
+    pub fn high_contrast() -> Self {
+        Self {
+            high_contrast: true,
+            panel_background: Color::BLACK,
+            text: Color::WHITE,
+            button_normal: Color::srgb(0.15, 0.15, 0.15),
+            button_hover: Color::srgb(0.3, 0.3, 0.3),
+            button_pressed: Color::srgb(0.45, 0.45, 0.45),
+            button_border: Color::WHITE,
+            fold_button: Color::srgb(0.6, 0.0, 0.0),
+            call_button: Color::srgb(0.0, 0.4, 0.0),
+            raise_button: Color::srgb(0.0, 0.2, 0.6),
+            human_panel: Color::srgb(0.0, 0.35, 0.0),
+            ai_panel: Color::srgb(0.35, 0.25, 0.0),
+        }
+    }
+    
+    pub fn toggle(&mut self) {
+        *self = if self.high_contrast { Self::standard() } else { Self::high_contrast() };
+    }
+    
+    pub fn button_color(&self, action: &BettingButtonAction) -> Color {
+        match action {
+            BettingButtonAction::Fold => self.fold_button,
+            BettingButtonAction::Check | BettingButtonAction::Call => self.call_button,
+            BettingButtonAction::Raise => self.raise_button,
+        }
+    }
+}
+
+// Plugin for switchable UI themes
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Palette>()
+            .add_systems(Update, (handle_theme_input, apply_palette).chain());
+    }
+}
+
+// System to toggle the high-contrast theme with C
+fn handle_theme_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut palette: ResMut<Palette>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyC) {
+        palette.toggle();
+        info!("🎨 High-contrast theme: {}", if palette.high_contrast { "ON" } else { "OFF" });
+    }
+}
+
+// Panels recolored on a theme switch (kept apart from the buttons so the queries don't overlap)
+type ThemedPanel = (Or<(With<BettingUI>, With<GameInfoUI>)>, Without<Button>);
+
+// System to recolor the long-lived UI when the palette changes; player panels are
+// rebuilt and recolored from the palette every frame by the UI systems
+fn apply_palette(
+    palette: Res<Palette>,
+    mut buttons: Query<(&mut BackgroundColor, &mut BorderColor, Option<&BettingButton>, &Children), With<Button>>,
+    mut panels: Query<&mut BackgroundColor, ThemedPanel>,
+    mut texts: Query<&mut Text>,
+) {
+    if !palette.is_changed() {
+        return;
+    }
+    
+    for (mut background, mut border, betting_button, children) in buttons.iter_mut() {
+        *background = betting_button
+            .map_or(palette.button_normal, |button| palette.button_color(&button.action))
+            .into();
+        *border = palette.button_border.into();
+        for &child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                for section in text.sections.iter_mut() {
+                    section.style.color = palette.text;
+                }
+            }
+        }
+    }
+    
+    for mut background in panels.iter_mut() {
+        *background = palette.panel_background.into();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_high_contrast_recolors_betting_buttons() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, ThemePlugin))
+            .init_resource::<ButtonInput<KeyCode>>();
+        let fold = app
+            .world_mut()
+            .spawn((ButtonBundle::default(), BettingButton { action: BettingButtonAction::Fold }))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section("FOLD", TextStyle::default()));
+            })
+            .id();
+        let raise = app
+            .world_mut()
+            .spawn((ButtonBundle::default(), BettingButton { action: BettingButtonAction::Raise }))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section("RAISE", TextStyle::default()));
+            })
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<BackgroundColor>(fold).unwrap().0, Palette::standard().fold_button);
+        
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyC);
+        app.update();
+        
+        let high_contrast = Palette::high_contrast();
+        assert_eq!(app.world().get::<BackgroundColor>(fold).unwrap().0, high_contrast.fold_button);
+        assert_eq!(app.world().get::<BackgroundColor>(raise).unwrap().0, high_contrast.raise_button);
+        assert_eq!(app.world().get::<BorderColor>(fold).unwrap().0, Color::WHITE);
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use crate::game_state::GameState;
+use crate::player::Player;
+
+// Resource recording the order players ran out of chips, first bust first
+#[derive(Resource, Debug, Default)]
+pub struct EliminationOrder {
+    pub busted: Vec<u32>,
+}
+
+impl EliminationOrder {
+    pub fn record(&mut self, player_id: u32) {
+        if !self.busted.contains(&player_id) {
+            info!("💀 Player {} is out of chips (elimination #{})", player_id, self.busted.len() + 1);
+            self.busted.push(player_id);
+        }
+    }
+    
+    /// Finishing place of a busted player: the first one out of `total_players` finishes last
+    pub fn finishing_position(&self, player_id: u32, total_players: usize) -> Option<usize> {
+        self.busted
+            .iter()
+            .position(|&id| id == player_id)
+            .map(|bust_index| total_players - bust_index)
+    }
+}
+
+// "1st", "2nd", "3rd", "4th", ... for finishing places
+pub fn ordinal(place: usize) -> String {
+    let suffix = match (place % 10, place % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", place, suffix)
+}
+
+/// The player with the most chips, if one stack is strictly bigger than all the others
+pub fn chip_leader<'a>(players: impl Iterator<Item = &'a Player>) -> Option<u32> {
+    let mut stacks: Vec<(u32, u32)> = players.filter(|p| p.chips > 0).map(|p| (p.chips, p.id)).collect();
+    stacks.sort_by(|a, b| b.cmp(a));
+    match stacks.as_slice() {
+        [(top, leader), (second, _), ..] if top > second => Some(*leader),
+        [(_, leader)] => Some(*leader),
+        _ => None,
+    }
+}
+
+// System to note who busted at the end of each hand; a new game starts the order over
+pub fn track_eliminations(
+    current_state: Res<State<GameState>>,
+    players: Query<&Player>,
+    mut elimination_order: ResMut<EliminationOrder>,
+) {
+    if !current_state.is_changed() {
+        return;
+    }
+    
+    match current_state.get() {
+        GameState::GameOver => {
+            let mut busted: Vec<u32> = players.iter().filter(|p| p.chips == 0).map(|p| p.id).collect();
+            busted.sort();
+            for player_id in busted {
+                elimination_order.record(player_id);
+            }
+        },
+        GameState::Setup if players.iter().all(|p| p.chips > 0) && !elimination_order.busted.is_empty() => {
+            elimination_order.busted.clear();
+        },
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::player::PlayerType;
+    
+    #[test]
+    fn test_busted_players_get_finishing_places_and_survivor_leads() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<EliminationOrder>()
+            .add_systems(Update, track_eliminations);
+        for id in 0..3 {
+            app.world_mut().spawn(Player::new(id, PlayerType::AI, 1000, Vec3::ZERO));
+        }
+        let bust = |app: &mut App, player_id: u32| {
+            let world = app.world_mut();
+            let mut players = world.query::<&mut Player>();
+            for mut player in players.iter_mut(world) {
+                if player.id == player_id {
+                    player.chips = 0;
+                } else if player.chips > 0 {
+                    player.chips += 500;
+                }
+            }
+            world.resource_mut::<NextState<GameState>>().set(GameState::GameOver);
+            app.update();
+            app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Setup);
+            app.update();
+        };
+        
+        bust(&mut app, 2);
+        bust(&mut app, 1);
+        
+        let elimination_order = app.world().resource::<EliminationOrder>();
+        assert_eq!(elimination_order.busted, vec![2, 1]);
+        assert_eq!(elimination_order.finishing_position(2, 3), Some(3));
+        assert_eq!(elimination_order.finishing_position(1, 3), Some(2));
+        assert_eq!(elimination_order.finishing_position(0, 3), None);
+        assert_eq!(ordinal(2), "2nd");
+        
+        let mut players = app.world_mut().query::<&Player>();
+        assert_eq!(chip_leader(players.iter(app.world())), Some(0));
+    }
+}
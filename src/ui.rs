@@ -1,6 +1,8 @@
 use bevy::prelude::*;
-use crate::player::{Player, PlayerType};
-use crate::game_state::{GameData, GameState};
+use crate::player::{seat_positions, Player, PlayerType};
+use crate::game_state::{GameData, GameState, GamePosition};
+use crate::theme::Palette;
+use crate::tournament::chip_leader;
 
 // UI Components
 #[derive(Component)]
@@ -8,6 +10,26 @@ pub struct PlayerUI {
     pub player_id: u32,
 }
 
+// Text under an AI opponent showing the range their actions represent
+#[derive(Component)]
+pub struct OpponentRangeText {
+    pub player_id: u32,
+}
+
+// Text under an AI opponent showing their tell, when tells are on
+#[derive(Component)]
+pub struct TellText {
+    pub player_id: u32,
+}
+
+// Crown on the panel of the player with the biggest stack
+#[derive(Component)]
+pub struct ChipLeaderMarker;
+
+// Dealer button / blind chip shown on a player's panel
+#[derive(Component)]
+pub struct PositionMarker;
+
 #[derive(Component)]
 pub struct GameInfoUI;
 
@@ -17,13 +39,11 @@ pub struct PotDisplay;
 #[derive(Component)]
 pub struct GamePhaseDisplay;
 
-// Colors for UI
-const UI_BACKGROUND: Color = Color::srgba(0.1, 0.1, 0.1, 0.8);
-const UI_TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
-const HUMAN_PLAYER_COLOR: Color = Color::srgb(0.2, 0.6, 0.2);
-const AI_PLAYER_COLOR: Color = Color::srgb(0.6, 0.6, 0.2);
+// Colors for UI; the main colors come from the theme's Palette
+const POSITION_MARKER_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+const POSITION_MARKER_BACKGROUND: Color = Color::srgb(0.15, 0.15, 0.5);
 
-pub fn setup_ui(mut commands: Commands) {
+pub fn setup_ui(mut commands: Commands, palette: Res<Palette>) {
     // Game info panel (top center)
     commands
         .spawn(NodeBundle {
@@ -39,7 +59,7 @@ pub fn setup_ui(mut commands: Commands) {
                 flex_direction: FlexDirection::Column,
                 ..default()
             },
-            background_color: UI_BACKGROUND.into(),
+            background_color: palette.panel_background.into(),
             ..default()
         })
         .with_children(|parent| {
@@ -49,7 +69,7 @@ pub fn setup_ui(mut commands: Commands) {
                     "Pot: $0",
                     TextStyle {
                         font_size: 20.0,
-                        color: UI_TEXT_COLOR,
+                        color: palette.text,
                         ..default()
                     },
                 ))
@@ -61,7 +81,7 @@ pub fn setup_ui(mut commands: Commands) {
                     "Setup",
                     TextStyle {
                         font_size: 16.0,
-                        color: UI_TEXT_COLOR,
+                        color: palette.text,
                         ..default()
                     },
                 ))
@@ -70,14 +90,24 @@ pub fn setup_ui(mut commands: Commands) {
         .insert(GameInfoUI);
 }
 
+// Panel spot (left %, top px) for an AI seat: the far left and right seats at the edges,
+// the top seats 100px down and lower side seats further down
+fn ai_panel_placement(seat: Vec3) -> (f32, f32) {
+    let left_percent = (37.5 + seat.x / 300.0 * 32.5).clamp(0.0, 75.0);
+    let top_px = 100.0 + (100.0 - seat.y).max(0.0) * 1.2;
+    (left_percent, top_px)
+}
+
 pub fn setup_player_ui(
     mut commands: Commands,
     players: Query<&Player>,
     existing_ui: Query<Entity, With<PlayerUI>>,
     game_state: Res<State<GameState>>,
+    game_position: Res<GamePosition>,
+    palette: Res<Palette>,
 ) {
-    // Only update when game state changes or player data might have changed
-    if !game_state.is_changed() {
+    // Only update when game state changes, player data might have changed, the button moved, or the theme switched
+    if !game_state.is_changed() && !game_position.is_changed() && !palette.is_changed() {
         return;
     }
     
@@ -86,6 +116,9 @@ pub fn setup_player_ui(
         commands.entity(entity).despawn_recursive();
     }
     
+    let seats = seat_positions(game_position.total_players);
+    let leader = chip_leader(players.iter());
+    
     // Create UI for each player
     for player in players.iter() {
         let (ui_position, ui_color) = match player.player_type {
@@ -100,16 +133,12 @@ pub fn setup_player_ui(
                         height: Val::Px(60.0),
                         ..default()
                     },
-                    HUMAN_PLAYER_COLOR
+                    palette.human_panel
                 )
             },
             PlayerType::AI => {
-                // AI players at top sides
-                let (left_percent, top_px) = if player.id == 1 {
-                    (5.0, 100.0) // Top left
-                } else {
-                    (70.0, 100.0) // Top right
-                };
+                // AI players follow their seat around the table
+                let (left_percent, top_px) = ai_panel_placement(seats.get(player.id as usize).copied().unwrap_or(player.position));
                 
                 (
                     Style {
@@ -120,7 +149,7 @@ pub fn setup_player_ui(
                         height: Val::Px(60.0),
                         ..default()
                     },
-                    AI_PLAYER_COLOR
+                    palette.ai_panel
                 )
             }
         };
@@ -148,17 +177,57 @@ pub fn setup_player_ui(
                     player_name,
                     TextStyle {
                         font_size: 16.0,
-                        color: UI_TEXT_COLOR,
+                        color: palette.text,
                         ..default()
                     },
                 ));
                 
+                // Dealer button / blind marker
+                if let Some(marker) = position_marker(player.id, &game_position) {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                padding: UiRect::horizontal(Val::Px(6.0)),
+                                ..default()
+                            },
+                            background_color: POSITION_MARKER_BACKGROUND.into(),
+                            ..default()
+                        })
+                        .with_children(|chip| {
+                            chip.spawn((
+                                TextBundle::from_section(
+                                    marker,
+                                    TextStyle {
+                                        font_size: 12.0,
+                                        color: POSITION_MARKER_COLOR,
+                                        ..default()
+                                    },
+                                ),
+                                PositionMarker,
+                            ));
+                        });
+                }
+                
+                if leader == Some(player.id) {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "👑 Chip leader",
+                            TextStyle {
+                                font_size: 12.0,
+                                color: Color::srgb(1.0, 0.85, 0.2),
+                                ..default()
+                            },
+                        ),
+                        ChipLeaderMarker,
+                    ));
+                }
+                
                 // Chip count
                 parent.spawn(TextBundle::from_section(
                     format!("Chips: ${}", player.chips),
                     TextStyle {
                         font_size: 14.0,
-                        color: UI_TEXT_COLOR,
+                        color: palette.text,
                         ..default()
                     },
                 ));
@@ -175,6 +244,32 @@ pub fn setup_player_ui(
                     ));
                 }
                 
+                // Range read (filled in by the teaching system in tutorial mode)
+                if matches!(player.player_type, PlayerType::AI) {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 11.0,
+                                color: Color::srgb(0.7, 0.85, 1.0),
+                                ..default()
+                            },
+                        ),
+                        OpponentRangeText { player_id: player.id },
+                    ));
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 11.0,
+                                color: Color::srgb(1.0, 0.85, 0.6),
+                                ..default()
+                            },
+                        ),
+                        TellText { player_id: player.id },
+                    ));
+                }
+                
                 // Folded status
                 if player.has_folded {
                     parent.spawn(TextBundle::from_section(
@@ -191,6 +286,26 @@ pub fn setup_player_ui(
     }
 }
 
+/// Label for the dealer button and blinds on a seat ("D", "SB", "BB", or "D/SB" heads-up)
+pub fn position_marker(player_id: u32, game_position: &GamePosition) -> Option<String> {
+    let mut labels = Vec::new();
+    if player_id == game_position.dealer_button {
+        labels.push("D");
+    }
+    if player_id == game_position.get_small_blind_player() {
+        labels.push("SB");
+    }
+    if player_id == game_position.get_big_blind_player() {
+        labels.push("BB");
+    }
+    
+    if labels.is_empty() {
+        None
+    } else {
+        Some(labels.join("/"))
+    }
+}
+
 pub fn update_pot_display(
     mut pot_query: Query<&mut Text, With<PotDisplay>>,
     game_data: Res<GameData>,
@@ -205,17 +320,7 @@ pub fn update_game_phase_display(
     game_state: Res<State<GameState>>,
 ) {
     if let Ok(mut text) = phase_query.get_single_mut() {
-        let phase_text = match game_state.get() {
-            GameState::Setup => "Setup",
-            GameState::Dealing => "Dealing Cards",
-            GameState::PreFlop => "Pre-Flop",
-            GameState::Flop => "Flop",
-            GameState::Turn => "Turn",
-            GameState::River => "River", 
-            GameState::Showdown => "Showdown",
-            GameState::GameOver => "Game Over",
-        };
-        text.sections[0].value = phase_text.to_string();
+        text.sections[0].value = game_state.get().display_name().to_string();
     }
 }
 
@@ -224,14 +329,15 @@ pub fn update_player_ui(
     players: Query<&Player>,
     mut text_query: Query<&mut Text>,
     game_data: Res<GameData>,
+    palette: Res<Palette>,
 ) {
     for (player_ui, mut bg_color, children) in player_ui_query.iter_mut() {
         if let Some(player) = players.iter().find(|p| p.id == player_ui.player_id) {
             // Highlight current player
             let is_current_player = game_data.current_player == player.id;
             let base_color = match player.player_type {
-                PlayerType::Human => HUMAN_PLAYER_COLOR,
-                PlayerType::AI => AI_PLAYER_COLOR,
+                PlayerType::Human => palette.human_panel,
+                PlayerType::AI => palette.ai_panel,
             };
             
             let alpha = if is_current_player { 1.0 } else { 0.6 };
@@ -251,3 +357,86 @@ pub fn update_player_ui(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    
+    fn markers(app: &mut App) -> Vec<(u32, String)> {
+        // Marker text sits inside a chip node under the player's panel
+        let mut query = app.world_mut().query_filtered::<(&Parent, &Text), With<PositionMarker>>();
+        let mut markers: Vec<(u32, String)> = query
+            .iter(app.world())
+            .map(|(chip, text)| {
+                let panel = app.world().get::<Parent>(chip.get()).unwrap().get();
+                let player_id = app.world().get::<PlayerUI>(panel).unwrap().player_id;
+                (player_id, text.sections[0].value.clone())
+            })
+            .collect();
+        markers.sort();
+        markers
+    }
+    
+    #[test]
+    fn test_position_markers_follow_dealer_button() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<GamePosition>()
+            .init_resource::<Palette>()
+            .add_systems(Update, setup_player_ui);
+        app.world_mut().spawn(Player::new(0, PlayerType::Human, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(1, PlayerType::AI, 1000, Vec3::ZERO));
+        app.world_mut().spawn(Player::new(2, PlayerType::AI, 1000, Vec3::ZERO));
+        
+        app.update();
+        assert_eq!(markers(&mut app), vec![
+            (0, "D".to_string()),
+            (1, "SB".to_string()),
+            (2, "BB".to_string()),
+        ]);
+        
+        app.world_mut().resource_mut::<GamePosition>().advance_dealer_button();
+        app.update();
+        assert_eq!(markers(&mut app), vec![
+            (0, "BB".to_string()),
+            (1, "D".to_string()),
+            (2, "SB".to_string()),
+        ]);
+    }
+    
+    #[test]
+    fn test_six_player_table_gets_a_panel_spot_per_seat() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .insert_resource(GamePosition { total_players: 6, ..default() })
+            .init_resource::<Palette>()
+            .add_systems(Update, setup_player_ui);
+        for (id, position) in (0..6).zip(seat_positions(6)) {
+            let player_type = if id == 0 { PlayerType::Human } else { PlayerType::AI };
+            app.world_mut().spawn(Player::new(id, player_type, 1000, position));
+        }
+        app.update();
+        
+        let mut panels = app.world_mut().query::<(&PlayerUI, &Style)>();
+        let spots: Vec<(Val, Val, Val)> = panels.iter(app.world()).map(|(_, style)| (style.left, style.top, style.bottom)).collect();
+        assert_eq!(spots.len(), 6);
+        for (i, spot) in spots.iter().enumerate() {
+            assert!(!spots[..i].contains(spot), "two panels share {:?}", spot);
+        }
+        
+        // Three-handed keeps the original top-left and top-right spots
+        let three_handed = seat_positions(3);
+        assert_eq!(ai_panel_placement(three_handed[1]), (5.0, 100.0));
+        assert_eq!(ai_panel_placement(three_handed[2]), (70.0, 100.0));
+    }
+    
+    #[test]
+    fn test_heads_up_dealer_marker_includes_small_blind() {
+        let game_position = GamePosition { total_players: 2, ..default() };
+        assert_eq!(position_marker(0, &game_position), Some("D/SB".to_string()));
+        assert_eq!(position_marker(1, &game_position), Some("BB".to_string()));
+    }
+}